@@ -0,0 +1,149 @@
+//! Admin DTOs
+
+use chrono::{DateTime, Utc};
+use domain::{audit::AuditEvent, member::Member};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use utoipa::{IntoParams, ToSchema};
+
+use super::common::{default_page, default_page_size};
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct MemberListQuery {
+    /// 搜索关键词，匹配邮箱和用户名
+    #[serde(default)]
+    pub keyword: String,
+    /// 按状态过滤：active、inactive、banned
+    pub status: Option<String>,
+    /// 按角色过滤：regular、decider、admin
+    pub role: Option<String>,
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ChangeMemberStatusRequest {
+    /// 目标状态：active、inactive、banned
+    #[schema(example = "banned")]
+    pub status: String,
+    /// 变更原因（必填）
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ChangeMemberRoleRequest {
+    /// 目标角色：regular、admin（决策者通过分配职业设置）
+    #[schema(example = "admin")]
+    pub role: String,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AssignDeciderRequest {
+    /// 管理的职业编码
+    #[schema(example = json!(["cooking", "plumbing"]))]
+    pub professions: Vec<String>,
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize, Serialize, ToSchema)]
+pub struct RevokeDeciderRequest {
+    pub reason: Option<String>,
+}
+
+/// 管理后台的会员视图
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AdminMemberDto {
+    pub id: String,
+    pub email: String,
+    pub username: String,
+    pub status: String,
+    pub role: String,
+    /// 决策者管理的职业
+    pub managed_professions: Vec<String>,
+    #[schema(value_type = String, example = "62.50")]
+    pub reputation: rust_decimal::Decimal,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&Member> for AdminMemberDto {
+    fn from(member: &Member) -> Self {
+        Self {
+            id: member.id.to_string(),
+            email: member.email.value().to_string(),
+            username: member.username.value().to_string(),
+            status: member.status.to_string(),
+            role: member.role.to_string(),
+            managed_professions: member
+                .managed_professions
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            reputation: member.reputation,
+            created_at: member.created_at.to_rfc3339(),
+            updated_at: member.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditEventListQuery {
+    /// 按操作者过滤
+    pub actor_id: Option<String>,
+    /// 按操作过滤，如 member_status_changed、isu_balance_adjusted、tool_deleted、dispute_resolved
+    pub action: Option<String>,
+    /// 按对象类型过滤：member、isu_account、tool、dispute
+    pub target_type: Option<String>,
+    /// 按对象ID过滤
+    pub target_id: Option<String>,
+    /// 起始时间（RFC 3339，含）
+    pub from: Option<DateTime<Utc>>,
+    /// 截止时间（RFC 3339，不含）
+    pub to: Option<DateTime<Utc>>,
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct AuditEventDto {
+    pub id: String,
+    pub actor_id: String,
+    pub action: String,
+    pub target_type: String,
+    pub target_id: String,
+    /// 操作前快照
+    #[schema(value_type = Option<Object>)]
+    pub before: Option<Value>,
+    /// 操作后快照
+    #[schema(value_type = Option<Object>)]
+    pub after: Option<Value>,
+    pub reason: Option<String>,
+    pub request_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: String,
+}
+
+impl From<&AuditEvent> for AuditEventDto {
+    fn from(event: &AuditEvent) -> Self {
+        Self {
+            id: event.id.to_string(),
+            actor_id: event.actor_id.to_string(),
+            action: event.action.to_string(),
+            target_type: event.target_type.to_string(),
+            target_id: event.target_id.clone(),
+            before: event.before.clone(),
+            after: event.after.clone(),
+            reason: event.reason.clone(),
+            request_id: event.request_id.clone(),
+            ip_address: event.ip_address.clone(),
+            created_at: event.created_at.to_rfc3339(),
+        }
+    }
+}
@@ -19,6 +19,14 @@ impl<T> ApiResponse<T> {
         }
     }
 
+    pub fn success_with_message(data: T, message: impl Into<String>) -> Self {
+        Self {
+            code: 200,
+            data: Some(data),
+            message: Some(message.into()),
+        }
+    }
+
     pub fn error(code: u16, message: String) -> Self {
         Self {
             code,
@@ -36,11 +44,11 @@ pub struct PaginationQuery {
     pub page_size: i64,
 }
 
-fn default_page() -> i64 {
+pub(crate) fn default_page() -> i64 {
     1
 }
 
-fn default_page_size() -> i64 {
+pub(crate) fn default_page_size() -> i64 {
     20
 }
 
@@ -0,0 +1,128 @@
+//! Dispute DTOs
+
+use app::dispute::DisputeDetails;
+use domain::dispute::{Dispute, DisputeEvidence, DisputeRuling};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct SubmitEvidenceRequest {
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AssignDisputeRequest {
+    /// 被分配的决策者，为空表示操作者认领
+    pub decider_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct ResolveDisputeRequest {
+    /// full_refund、split 或 release_to_seller
+    #[schema(example = "split")]
+    pub outcome: String,
+    /// 拆分裁决时退还买家的金额，其余放款给卖家
+    #[schema(value_type = Option<String>, example = "1.50")]
+    pub buyer_refund: Option<Decimal>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DisputeRulingDto {
+    #[schema(example = "split")]
+    pub outcome: String,
+    #[schema(value_type = String, example = "1.50")]
+    pub buyer_refund: Decimal,
+    #[schema(value_type = String, example = "1.00")]
+    pub seller_payout: Decimal,
+    pub note: Option<String>,
+    pub decided_by: String,
+    pub decided_at: String,
+    /// 退还买家的ISU流水
+    pub refund_isu_transaction_id: Option<String>,
+    /// 放款给卖家的ISU流水
+    pub payout_isu_transaction_id: Option<String>,
+}
+
+impl From<&DisputeRuling> for DisputeRulingDto {
+    fn from(ruling: &DisputeRuling) -> Self {
+        Self {
+            outcome: ruling.outcome.to_string(),
+            buyer_refund: ruling.buyer_refund.value(),
+            seller_payout: ruling.seller_payout.value(),
+            note: ruling.note.clone(),
+            decided_by: ruling.decided_by.to_string(),
+            decided_at: ruling.decided_at.to_rfc3339(),
+            refund_isu_transaction_id: ruling.refund_isu_transaction_id.clone(),
+            payout_isu_transaction_id: ruling.payout_isu_transaction_id.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DisputeDto {
+    pub id: String,
+    pub transaction_id: String,
+    pub opened_by: String,
+    pub reason: String,
+    /// 服务所属职业，工具租用为空
+    pub profession_type: Option<String>,
+    pub decider_id: Option<String>,
+    #[schema(example = "open")]
+    pub status: String,
+    pub ruling: Option<DisputeRulingDto>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&Dispute> for DisputeDto {
+    fn from(dispute: &Dispute) -> Self {
+        Self {
+            id: dispute.id.to_string(),
+            transaction_id: dispute.transaction_id.to_string(),
+            opened_by: dispute.opened_by.to_string(),
+            reason: dispute.reason.clone(),
+            profession_type: dispute.profession_type.as_ref().map(|p| p.as_str().to_string()),
+            decider_id: dispute.decider_id.map(|id| id.to_string()),
+            status: dispute.status.to_string(),
+            ruling: dispute.ruling.as_ref().map(DisputeRulingDto::from),
+            created_at: dispute.created_at.to_rfc3339(),
+            updated_at: dispute.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DisputeEvidenceDto {
+    pub id: String,
+    pub author_id: String,
+    pub message: String,
+    pub created_at: String,
+}
+
+impl From<&DisputeEvidence> for DisputeEvidenceDto {
+    fn from(evidence: &DisputeEvidence) -> Self {
+        Self {
+            id: evidence.id.to_string(),
+            author_id: evidence.author_id.to_string(),
+            message: evidence.message.clone(),
+            created_at: evidence.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DisputeDetailsDto {
+    pub dispute: DisputeDto,
+    pub evidence: Vec<DisputeEvidenceDto>,
+}
+
+impl From<&DisputeDetails> for DisputeDetailsDto {
+    fn from(details: &DisputeDetails) -> Self {
+        Self {
+            dispute: DisputeDto::from(&details.dispute),
+            evidence: details.evidence.iter().map(DisputeEvidenceDto::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+//! ISU DTOs
+
+use domain::isu::{ISUAccount, ISUAccountId, ISUCirculation, ISUTransaction};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::common::default_page_size;
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ISUAccountDto {
+    pub account_id: String,
+    /// 可用余额
+    #[schema(value_type = String, example = "100.00")]
+    pub balance: Decimal,
+    /// 托管中（已冻结、尚未结算）的余额
+    #[schema(value_type = String, example = "20.00")]
+    pub held_balance: Decimal,
+    pub updated_at: String,
+}
+
+impl From<&ISUAccount> for ISUAccountDto {
+    fn from(account: &ISUAccount) -> Self {
+        Self {
+            account_id: account.id.to_string(),
+            balance: account.balance.value(),
+            held_balance: account.held_balance.value(),
+            updated_at: account.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct StatementQuery {
+    /// 逗号分隔的流水类型，如 service_payment,admin_adjustment
+    pub types: Option<String>,
+    /// 上一页返回的 next_cursor
+    pub cursor: Option<String>,
+    #[serde(default = "default_page_size")]
+    pub limit: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StatementEntryDto {
+    pub id: String,
+    /// in 表示转入，out 表示转出
+    #[schema(example = "in")]
+    pub direction: String,
+    pub counterparty_account_id: String,
+    #[schema(value_type = String, example = "2.50")]
+    pub amount: Decimal,
+    pub transaction_type: String,
+    pub description: Option<String>,
+    pub created_at: String,
+}
+
+impl StatementEntryDto {
+    pub fn new(entry: &ISUTransaction, account_id: &ISUAccountId) -> Self {
+        let (direction, counterparty) = if &entry.to_account_id == account_id {
+            ("in", entry.from_account_id)
+        } else {
+            ("out", entry.to_account_id)
+        };
+
+        Self {
+            id: entry.id.clone(),
+            direction: direction.to_string(),
+            counterparty_account_id: counterparty.to_string(),
+            amount: entry.amount.value(),
+            transaction_type: entry.transaction_type.to_string(),
+            description: entry.description.clone(),
+            created_at: entry.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StatementResponse {
+    pub account: ISUAccountDto,
+    pub entries: Vec<StatementEntryDto>,
+    pub next_cursor: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct AdjustBalanceRequest {
+    pub member_id: String,
+    /// 正数为发放，负数为扣减，最多两位小数
+    #[schema(value_type = String, example = "-10.00")]
+    pub amount: Decimal,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ISUTransactionDto {
+    pub id: String,
+    pub from_account_id: String,
+    pub to_account_id: String,
+    #[schema(value_type = String, example = "10.00")]
+    pub amount: Decimal,
+    pub transaction_type: String,
+    pub description: Option<String>,
+    pub created_at: String,
+}
+
+impl From<&ISUTransaction> for ISUTransactionDto {
+    fn from(entry: &ISUTransaction) -> Self {
+        Self {
+            id: entry.id.clone(),
+            from_account_id: entry.from_account_id.to_string(),
+            to_account_id: entry.to_account_id.to_string(),
+            amount: entry.amount.value(),
+            transaction_type: entry.transaction_type.to_string(),
+            description: entry.description.clone(),
+            created_at: entry.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CirculationDto {
+    #[schema(value_type = String)]
+    pub total_supply: Decimal,
+    #[schema(value_type = String)]
+    pub treasury_balance: Decimal,
+    #[schema(value_type = String)]
+    pub circulating: Decimal,
+    #[schema(value_type = String)]
+    pub net_issuance: Decimal,
+    #[schema(value_type = String)]
+    pub escrow_balance: Decimal,
+    #[schema(value_type = String)]
+    pub held_total: Decimal,
+    /// 流通量是否与净发行量、创世供应量一致，托管账户余额是否与托管中余额一致
+    pub balanced: bool,
+}
+
+impl From<&ISUCirculation> for CirculationDto {
+    fn from(circulation: &ISUCirculation) -> Self {
+        Self {
+            total_supply: circulation.total_supply,
+            treasury_balance: circulation.treasury_balance,
+            circulating: circulation.circulating,
+            net_issuance: circulation.net_issuance(),
+            escrow_balance: circulation.escrow_balance,
+            held_total: circulation.held_total,
+            balanced: circulation.is_balanced(),
+        }
+    }
+}
@@ -1,9 +1,12 @@
 //! Member DTOs
 
 use domain::member::Member;
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
+use super::review::RatingSummaryDto;
+
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct RegisterRequest {
     pub email: String,
@@ -17,9 +20,13 @@ pub struct LoginRequest {
     pub password: String,
 }
 
+/// 登录或续期返回的会话（刷新令牌通过 HttpOnly Cookie 下发，不出现在响应体中）
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct LoginResponse {
+    /// 访问令牌
     pub token: String,
+    /// 访问令牌有效期（秒）
+    pub expires_in: i64,
     pub member: MemberDto,
 }
 
@@ -29,6 +36,11 @@ pub struct MemberDto {
     pub email: String,
     pub username: String,
     pub status: String,
+    /// 收到的评价汇总
+    pub rating: RatingSummaryDto,
+    /// 信誉分（0-100）
+    #[schema(value_type = String, example = "62.50")]
+    pub reputation: Decimal,
     pub created_at: String,
 }
 
@@ -39,6 +51,8 @@ impl From<&Member> for MemberDto {
             email: member.email.value().to_string(),
             username: member.username.value().to_string(),
             status: member.status.to_string(),
+            rating: RatingSummaryDto::from(&member.rating),
+            reputation: member.reputation,
             created_at: member.created_at.to_rfc3339(),
         }
     }
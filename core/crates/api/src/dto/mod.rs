@@ -1,7 +1,12 @@
 //! 数据传输对象
 
+pub mod admin;
 pub mod common;
+pub mod dispute;
+pub mod isu;
 pub mod member;
+pub mod profession;
+pub mod review;
+pub mod service;
 pub mod tool;
-
-// pub mod transaction;
+pub mod transaction;
@@ -0,0 +1,154 @@
+//! Profession catalogue DTOs
+
+use app::profession::UpdateProfessionRateOutput;
+use domain::profession::{LocalizedNames, Profession, ProfessionCategory};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ProfessionListQuery {
+    /// 显示名称使用的语言，如 en、zh-tw；缺少翻译时返回默认名称
+    pub locale: Option<String>,
+    /// 是否包含已停用的职业
+    #[serde(default)]
+    pub include_inactive: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CategoryListQuery {
+    /// 显示名称使用的语言，如 en、zh-tw；缺少翻译时返回默认名称
+    pub locale: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CreateProfessionRequest {
+    #[schema(example = "locksmith")]
+    pub code: String,
+    #[schema(example = "开锁换锁")]
+    pub display_name: String,
+    #[serde(default)]
+    #[schema(value_type = Object, example = json!({"en": "Locksmith"}))]
+    pub localized_names: LocalizedNames,
+    #[schema(example = "repair")]
+    pub category_code: String,
+    #[schema(value_type = String, example = "1.5")]
+    pub default_rate: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct UpdateProfessionRequest {
+    pub display_name: Option<String>,
+    /// 提供时整体替换多语言名称
+    #[schema(value_type = Option<Object>, example = json!({"en": "Locksmith"}))]
+    pub localized_names: Option<LocalizedNames>,
+    pub category_code: Option<String>,
+    #[schema(value_type = Option<String>, example = "2")]
+    pub default_rate: Option<Decimal>,
+    pub is_active: Option<bool>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CreateCategoryRequest {
+    #[schema(example = "security")]
+    pub code: String,
+    #[schema(example = "安防服务")]
+    pub display_name: String,
+    #[serde(default)]
+    #[schema(value_type = Object, example = json!({"en": "Security"}))]
+    pub localized_names: LocalizedNames,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct UpdateCategoryRequest {
+    pub display_name: Option<String>,
+    /// 提供时整体替换多语言名称
+    #[schema(value_type = Option<Object>, example = json!({"en": "Security"}))]
+    pub localized_names: Option<LocalizedNames>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct UpdateProfessionRateRequest {
+    #[schema(value_type = String, example = "1.5")]
+    pub new_rate: Decimal,
+    /// 调整原因（写入费率变更历史）
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProfessionRateDto {
+    pub code: String,
+    #[schema(value_type = String, example = "1.00")]
+    pub old_rate: Decimal,
+    #[schema(value_type = String, example = "1.50")]
+    pub new_rate: Decimal,
+    pub message: String,
+}
+
+impl From<&UpdateProfessionRateOutput> for ProfessionRateDto {
+    fn from(output: &UpdateProfessionRateOutput) -> Self {
+        Self {
+            code: output.profession_type.to_string(),
+            old_rate: output.old_rate.value(),
+            new_rate: output.new_rate.value(),
+            message: output.message.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ProfessionDto {
+    pub code: String,
+    /// 按请求语言解析后的显示名称
+    pub display_name: String,
+    /// 默认显示名称
+    pub default_name: String,
+    #[schema(value_type = Object)]
+    pub localized_names: LocalizedNames,
+    pub category_code: String,
+    #[schema(value_type = String, example = "1.00")]
+    pub default_rate: Decimal,
+    pub is_active: bool,
+    pub updated_at: String,
+}
+
+impl ProfessionDto {
+    pub fn new(profession: &Profession, locale: Option<&str>) -> Self {
+        Self {
+            code: profession.code.to_string(),
+            display_name: profession.display_name_for(locale).to_string(),
+            default_name: profession.display_name.clone(),
+            localized_names: profession.localized_names.clone(),
+            category_code: profession.category_code.clone(),
+            default_rate: profession.default_rate.value(),
+            is_active: profession.is_active,
+            updated_at: profession.updated_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CategoryDto {
+    pub code: String,
+    /// 按请求语言解析后的显示名称
+    pub display_name: String,
+    /// 默认显示名称
+    pub default_name: String,
+    #[schema(value_type = Object)]
+    pub localized_names: LocalizedNames,
+    pub updated_at: String,
+}
+
+impl CategoryDto {
+    pub fn new(category: &ProfessionCategory, locale: Option<&str>) -> Self {
+        Self {
+            code: category.code.clone(),
+            display_name: category.display_name_for(locale).to_string(),
+            default_name: category.display_name.clone(),
+            localized_names: category.localized_names.clone(),
+            updated_at: category.updated_at.to_rfc3339(),
+        }
+    }
+}
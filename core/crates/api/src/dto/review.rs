@@ -0,0 +1,89 @@
+//! Review DTOs
+
+use app::review::MemberReviews;
+use domain::review::{RatingSummary, Review};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use super::common::PaginatedResponse;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct SubmitReviewRequest {
+    pub transaction_id: String,
+    /// 1-5 星
+    #[schema(example = 5)]
+    pub rating: u8,
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RatingSummaryDto {
+    /// 平均评分，没有评价时为空
+    #[schema(value_type = Option<String>, example = "4.50")]
+    pub average: Option<Decimal>,
+    pub count: u32,
+}
+
+impl From<&RatingSummary> for RatingSummaryDto {
+    fn from(summary: &RatingSummary) -> Self {
+        Self {
+            average: summary.average(),
+            count: summary.count,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReviewDto {
+    pub id: String,
+    pub transaction_id: String,
+    pub reviewer_id: String,
+    pub reviewee_id: String,
+    /// 买家评价服务时关联的服务
+    pub service_id: Option<String>,
+    #[schema(example = 5)]
+    pub rating: u8,
+    pub comment: Option<String>,
+    pub created_at: String,
+}
+
+impl From<&Review> for ReviewDto {
+    fn from(review: &Review) -> Self {
+        Self {
+            id: review.id.to_string(),
+            transaction_id: review.transaction_id.to_string(),
+            reviewer_id: review.reviewer_id.to_string(),
+            reviewee_id: review.reviewee_id.to_string(),
+            service_id: review.service_id.map(|id| id.to_string()),
+            rating: review.rating.value(),
+            comment: review.comment.clone(),
+            created_at: review.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct MemberReviewsDto {
+    pub member_id: String,
+    pub username: String,
+    pub rating: RatingSummaryDto,
+    pub reviews: PaginatedResponse<ReviewDto>,
+}
+
+impl MemberReviewsDto {
+    pub fn new(member_reviews: &MemberReviews, page: i64, page_size: i64) -> Self {
+        let member = &member_reviews.member;
+        Self {
+            member_id: member.id.to_string(),
+            username: member.username.value().to_string(),
+            rating: RatingSummaryDto::from(&member.rating),
+            reviews: PaginatedResponse::new(
+                member_reviews.reviews.iter().map(ReviewDto::from).collect(),
+                i64::from(member.rating.count),
+                page,
+                page_size,
+            ),
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Service DTOs
+
+use domain::service::Service;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::common::{default_page, default_page_size};
+use super::review::RatingSummaryDto;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct PublishServiceRequest {
+    #[schema(example = "cleaning")]
+    pub profession_type: String,
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+    #[schema(value_type = String, example = "2.5")]
+    pub estimated_hours: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct UpdateServiceRequest {
+    pub title: Option<String>,
+    pub description: Option<String>,
+    #[schema(value_type = Option<String>, example = "3")]
+    pub estimated_hours: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListServicesQuery {
+    /// 按职业类型过滤
+    pub profession_type: Option<String>,
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchServicesQuery {
+    /// 搜索关键词，匹配标题和描述
+    #[serde(default)]
+    pub keyword: String,
+    /// 按职业类型过滤
+    pub profession_type: Option<String>,
+    /// 服务提供者的最低信誉分（0-100）
+    #[param(value_type = Option<String>, example = "60")]
+    #[schema(value_type = Option<String>, example = "60")]
+    pub min_reputation: Option<Decimal>,
+    /// 排序方式：relevance（默认，按相关度）或 reputation（按提供者信誉分）
+    pub sort: Option<String>,
+    #[serde(default = "default_page")]
+    pub page: i64,
+    #[serde(default = "default_page_size")]
+    pub page_size: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ServiceDto {
+    pub id: String,
+    pub provider_id: String,
+    pub profession_type: String,
+    pub title: String,
+    pub description: String,
+    #[schema(value_type = String, example = "2.5")]
+    pub estimated_hours: Decimal,
+    /// 发布时的费率快照（ISU/小时）
+    #[schema(value_type = String, example = "1.0")]
+    pub isu_rate: Decimal,
+    #[schema(value_type = String, example = "2.5")]
+    pub total_isu: Decimal,
+    pub status: String,
+    /// 买家评价汇总
+    pub rating: RatingSummaryDto,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+impl From<&Service> for ServiceDto {
+    fn from(service: &Service) -> Self {
+        Self {
+            id: service.id.to_string(),
+            provider_id: service.provider_id.to_string(),
+            profession_type: service.profession_type.to_string(),
+            title: service.title.clone(),
+            description: service.description.clone(),
+            estimated_hours: service.estimated_hours,
+            isu_rate: service.isu_rate.value(),
+            total_isu: service.total_isu.value(),
+            status: service.status.to_string(),
+            rating: RatingSummaryDto::from(&service.rating),
+            created_at: service.created_at.to_rfc3339(),
+            updated_at: service.updated_at.to_rfc3339(),
+        }
+    }
+}
@@ -1,8 +1,10 @@
 //! Tool DTOs
 
-use domain::tool::{Currency, Money, Tool};
+use chrono::{DateTime, Utc};
+use domain::tool::{Currency, Money, ReservationWindow, Tool, ToolAvailability, ToolReservation};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use utoipa::ToSchema;
+use utoipa::{IntoParams, ToSchema};
 
 #[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
 pub struct CreateToolRequest {
@@ -12,6 +14,12 @@ pub struct CreateToolRequest {
     pub price_amount: i64,
     #[serde(default = "default_currency")]
     pub price_currency: String,
+    /// 每日租金（ISU），不设置表示不出租
+    #[schema(value_type = Option<String>, example = "5.00")]
+    pub daily_rate: Option<Decimal>,
+    /// 可退还押金（ISU）
+    #[schema(value_type = Option<String>, example = "50.00")]
+    pub deposit: Option<Decimal>,
 }
 
 fn default_currency() -> String {
@@ -25,6 +33,10 @@ pub struct UpdateToolRequest {
     pub category: Option<String>,
     pub price_amount: Option<i64>,
     pub price_currency: Option<String>,
+    #[schema(value_type = Option<String>, example = "5.00")]
+    pub daily_rate: Option<Decimal>,
+    #[schema(value_type = Option<String>, example = "50.00")]
+    pub deposit: Option<Decimal>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
@@ -35,6 +47,10 @@ pub struct ToolDto {
     pub description: Option<String>,
     pub category: String,
     pub price: MoneyDto,
+    #[schema(value_type = Option<String>)]
+    pub daily_rate: Option<Decimal>,
+    #[schema(value_type = String)]
+    pub deposit: Decimal,
     pub status: String,
     pub created_at: String,
     pub updated_at: String,
@@ -55,6 +71,8 @@ impl From<&Tool> for ToolDto {
             description: tool.description.clone(),
             category: tool.category.clone(),
             price: MoneyDto::from(&tool.price),
+            daily_rate: tool.daily_rate.map(|rate| rate.value()),
+            deposit: tool.deposit.value(),
             status: tool.status.to_string(),
             created_at: tool.created_at.to_rfc3339(),
             updated_at: tool.updated_at.to_rfc3339(),
@@ -82,3 +100,82 @@ pub struct ToolListResponse {
     pub page: i64,
     pub page_size: i64,
 }
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AvailabilityQuery {
+    /// 查询开始时间（RFC 3339），默认当前时间
+    pub from: Option<DateTime<Utc>>,
+    /// 查询结束时间（RFC 3339），默认开始时间后 30 天
+    pub to: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct BlockToolDatesRequest {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TimeWindowDto {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl From<&ReservationWindow> for TimeWindowDto {
+    fn from(window: &ReservationWindow) -> Self {
+        Self {
+            starts_at: window.starts_at,
+            ends_at: window.ends_at,
+        }
+    }
+}
+
+/// 预订（不包含租用者信息）
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ToolReservationDto {
+    pub id: String,
+    #[schema(example = "block")]
+    pub kind: String,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub note: Option<String>,
+}
+
+impl From<&ToolReservation> for ToolReservationDto {
+    fn from(reservation: &ToolReservation) -> Self {
+        Self {
+            id: reservation.id.to_string(),
+            kind: reservation.kind.to_string(),
+            starts_at: reservation.window.starts_at,
+            ends_at: reservation.window.ends_at,
+            note: reservation.note.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ToolAvailabilityDto {
+    pub tool_id: String,
+    pub from: DateTime<Utc>,
+    pub to: DateTime<Utc>,
+    pub reservations: Vec<ToolReservationDto>,
+    pub free: Vec<TimeWindowDto>,
+}
+
+impl From<&ToolAvailability> for ToolAvailabilityDto {
+    fn from(availability: &ToolAvailability) -> Self {
+        Self {
+            tool_id: availability.tool_id.to_string(),
+            from: availability.window.starts_at,
+            to: availability.window.ends_at,
+            reservations: availability
+                .reservations
+                .iter()
+                .map(ToolReservationDto::from)
+                .collect(),
+            free: availability.free.iter().map(TimeWindowDto::from).collect(),
+        }
+    }
+}
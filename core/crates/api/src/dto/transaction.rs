@@ -0,0 +1,145 @@
+//! Transaction DTOs
+
+use chrono::NaiveDate;
+use domain::tool::ToolRental;
+use domain::transaction::{Transaction, TransactionItemType};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use super::dispute::DisputeDto;
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct CreateTransactionRequest {
+    pub service_id: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct RentToolRequest {
+    pub tool_id: String,
+    /// 租用开始日期（含）
+    #[schema(value_type = String, format = Date, example = "2025-01-10")]
+    pub start_date: NaiveDate,
+    /// 租用结束日期（含）
+    #[schema(value_type = String, format = Date, example = "2025-01-12")]
+    pub end_date: NaiveDate,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema)]
+pub struct DisputeTransactionRequest {
+    /// 争议理由
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TransactionListQuery {
+    /// 按交易状态过滤，如 pending、in_progress
+    pub status: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct TransactionDto {
+    pub id: String,
+    pub buyer_id: String,
+    pub seller_id: String,
+    #[schema(example = "service")]
+    pub item_type: String,
+    pub item_id: String,
+    #[schema(value_type = String, example = "2.5")]
+    pub isu_amount: Decimal,
+    /// 托管中的交易款项
+    #[schema(value_type = String, example = "2.5")]
+    pub escrowed_amount: Decimal,
+    pub status: String,
+    pub description: Option<String>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub confirmed_at: Option<String>,
+    pub started_at: Option<String>,
+    pub completed_at: Option<String>,
+    /// 取消交易的参与者
+    pub cancelled_by: Option<String>,
+}
+
+impl From<&Transaction> for TransactionDto {
+    fn from(transaction: &Transaction) -> Self {
+        let (item_type, item_id) = match &transaction.item_type {
+            TransactionItemType::Service(service_id) => ("service", service_id.to_string()),
+            TransactionItemType::Tool(tool_id) => ("tool", tool_id.to_string()),
+        };
+
+        Self {
+            id: transaction.id.to_string(),
+            buyer_id: transaction.buyer_id.to_string(),
+            seller_id: transaction.seller_id.to_string(),
+            item_type: item_type.to_string(),
+            item_id,
+            isu_amount: transaction.isu_amount.value(),
+            escrowed_amount: transaction.escrowed_amount.value(),
+            status: transaction.status.to_string(),
+            description: transaction.description.clone(),
+            created_at: transaction.created_at.to_rfc3339(),
+            updated_at: transaction.updated_at.to_rfc3339(),
+            confirmed_at: transaction.confirmed_at.map(|t| t.to_rfc3339()),
+            started_at: transaction.started_at.map(|t| t.to_rfc3339()),
+            completed_at: transaction.completed_at.map(|t| t.to_rfc3339()),
+            cancelled_by: transaction.cancelled_by.map(|id| id.to_string()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ToolRentalDto {
+    pub transaction_id: String,
+    pub tool_id: String,
+    pub renter_id: String,
+    #[schema(value_type = String, format = Date)]
+    pub start_date: NaiveDate,
+    #[schema(value_type = String, format = Date)]
+    pub end_date: NaiveDate,
+    pub days: i64,
+    #[schema(value_type = String, example = "5.00")]
+    pub daily_rate: Decimal,
+    #[schema(value_type = String, example = "15.00")]
+    pub rental_fee: Decimal,
+    #[schema(value_type = String, example = "50.00")]
+    pub deposit: Decimal,
+    #[schema(example = "held")]
+    pub deposit_status: String,
+    pub returned_at: Option<String>,
+    pub created_at: String,
+}
+
+impl From<&ToolRental> for ToolRentalDto {
+    fn from(rental: &ToolRental) -> Self {
+        Self {
+            transaction_id: rental.transaction_id.to_string(),
+            tool_id: rental.tool_id.to_string(),
+            renter_id: rental.renter_id.to_string(),
+            start_date: rental.period.start_date,
+            end_date: rental.period.end_date,
+            days: rental.period.days(),
+            daily_rate: rental.daily_rate.value(),
+            rental_fee: rental.rental_fee.value(),
+            deposit: rental.deposit.value(),
+            deposit_status: rental.deposit_status.to_string(),
+            returned_at: rental.returned_at.map(|t| t.to_rfc3339()),
+            created_at: rental.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RentToolResponse {
+    pub transaction: TransactionDto,
+    pub rental: ToolRentalDto,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DisputeTransactionResponse {
+    pub transaction: TransactionDto,
+    pub dispute: DisputeDto,
+}
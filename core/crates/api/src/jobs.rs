@@ -0,0 +1,69 @@
+//! 后台任务调度
+//!
+//! 在 api 进程内定期运行交易过期和自动完成任务。任务逐条认领交易并跳过已锁定的行，
+//! 多个 api 实例同时运行时不会重复处理同一交易。
+
+use std::{sync::Arc, time::Duration};
+
+use app::transaction::{
+    auto_complete_transactions, expire_transactions, AutoCompleteTransactionsInput,
+    ExpireTransactionsInput,
+};
+use chrono::Utc;
+use domain::unit_of_work::UnitOfWorkFactory;
+use shared::config::JobsConfig;
+use tokio::{task::JoinHandle, time::MissedTickBehavior};
+use tracing::{error, info};
+
+/// 启动交易后台任务
+pub fn spawn_transaction_jobs(
+    uow_factory: Arc<dyn UnitOfWorkFactory>,
+    config: JobsConfig,
+) -> JoinHandle<()> {
+    tokio::spawn(async move {
+        info!(
+            interval_seconds = config.interval_seconds,
+            pending_ttl_hours = config.pending_ttl_hours,
+            auto_complete_after_hours = config.auto_complete_after_hours,
+            "交易后台任务已启动"
+        );
+
+        let mut interval = tokio::time::interval(Duration::from_secs(config.interval_seconds.max(1)));
+        // 上一轮运行超时时不补跑错过的轮次
+        interval.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            interval.tick().await;
+            run_transaction_jobs(uow_factory.as_ref(), &config).await;
+        }
+    })
+}
+
+/// 运行一轮交易后台任务，错误只记录日志，下一轮重试
+async fn run_transaction_jobs(uow_factory: &dyn UnitOfWorkFactory, config: &JobsConfig) {
+    let now = Utc::now();
+
+    if let Err(e) = expire_transactions(
+        uow_factory,
+        ExpireTransactionsInput {
+            cutoff: now - chrono::Duration::hours(config.pending_ttl_hours),
+            batch_size: config.batch_size,
+        },
+    )
+    .await
+    {
+        error!(error = %e, "交易过期任务运行失败");
+    }
+
+    if let Err(e) = auto_complete_transactions(
+        uow_factory,
+        AutoCompleteTransactionsInput {
+            cutoff: now - chrono::Duration::hours(config.auto_complete_after_hours),
+            batch_size: config.batch_size,
+        },
+    )
+    .await
+    {
+        error!(error = %e, "交易自动完成任务运行失败");
+    }
+}
@@ -1,6 +1,8 @@
 //! API 接口层
 
 pub mod dto;
+pub mod jobs;
+pub mod jwt_keys;
 pub mod middleware;
 pub mod openapi;
 pub mod routes;
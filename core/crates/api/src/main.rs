@@ -1,9 +1,13 @@
-use std::sync::Arc;
+use std::{net::SocketAddr, sync::Arc};
 
-use api::AppState;
+use api::{jwt_keys::JwtKeys, AppState};
 use infra::{
-    create_pool, init_tracing, Argon2PasswordHasher, PasswordHasher, 
-    PostgresMemberRepository, PostgresToolRepository,
+    create_pool, init_tracing, Argon2PasswordHasher, PasswordHasher, PostgresAuditEventRepository,
+    PostgresDisputeRepository,
+    PostgresISUAccountRepository,
+    PostgresMemberRepository, PostgresProfessionCatalogRepository,
+    PostgresProfessionStandardRepository, PostgresRefreshTokenRepository, PostgresReviewRepository, PostgresServiceRepository, PostgresToolRentalRepository, PostgresToolRepository,
+    PostgresToolReservationRepository, PostgresTransactionRepository, PostgresUnitOfWorkFactory,
 };
 use shared::AppConfig;
 
@@ -32,15 +36,59 @@ async fn main() -> anyhow::Result<()> {
         Arc::new(PostgresMemberRepository::new(pool.clone()));
     let tool_repo: Arc<dyn domain::tool::ToolRepository> =
         Arc::new(PostgresToolRepository::new(pool.clone()));
+    let tool_rental_repo: Arc<dyn domain::tool::ToolRentalRepository> =
+        Arc::new(PostgresToolRentalRepository::new(pool.clone()));
+    let tool_reservation_repo: Arc<dyn domain::tool::ToolReservationRepository> =
+        Arc::new(PostgresToolReservationRepository::new(pool.clone()));
+    let service_repo: Arc<dyn domain::service::ServiceRepository> =
+        Arc::new(PostgresServiceRepository::new(pool.clone()));
+    let transaction_repo: Arc<dyn domain::transaction::TransactionRepository> =
+        Arc::new(PostgresTransactionRepository::new(pool.clone()));
+    let dispute_repo: Arc<dyn domain::dispute::DisputeRepository> =
+        Arc::new(PostgresDisputeRepository::new(pool.clone()));
+    let review_repo: Arc<dyn domain::review::ReviewRepository> =
+        Arc::new(PostgresReviewRepository::new(pool.clone()));
+    let refresh_token_repo: Arc<dyn domain::auth::RefreshTokenRepository> =
+        Arc::new(PostgresRefreshTokenRepository::new(pool.clone()));
+    let audit_event_repo: Arc<dyn domain::audit::AuditEventRepository> =
+        Arc::new(PostgresAuditEventRepository::new(pool.clone()));
+    let isu_repo: Arc<dyn domain::isu::ISUAccountRepository> =
+        Arc::new(PostgresISUAccountRepository::new(pool.clone()));
+    let profession_repo: Arc<dyn domain::profession::ProfessionStandardRepository> =
+        Arc::new(PostgresProfessionStandardRepository::new(pool.clone()));
+    let profession_catalog_repo: Arc<dyn domain::profession::ProfessionCatalogRepository> =
+        Arc::new(PostgresProfessionCatalogRepository::new(pool.clone()));
+    let uow_factory: Arc<dyn domain::unit_of_work::UnitOfWorkFactory> =
+        Arc::new(PostgresUnitOfWorkFactory::new(pool.clone()));
     let password_hasher: Arc<dyn PasswordHasher> = Arc::new(Argon2PasswordHasher::new());
+    let jwt_keys = Arc::new(JwtKeys::load(&config.jwt, config.is_production())?);
+    tracing::info!(signing_kid = %config.jwt.signing_kid, "JWT keys loaded");
     
     let state = AppState {
         member_repo,
         tool_repo,
+        tool_rental_repo,
+        tool_reservation_repo,
+        service_repo,
+        transaction_repo,
+        dispute_repo,
+        review_repo,
+        refresh_token_repo,
+        audit_event_repo,
+        isu_repo,
+        profession_repo,
+        profession_catalog_repo,
+        uow_factory,
         password_hasher,
+        jwt_keys,
         config: Arc::new(config.clone()),
     };
 
+    // 启动后台任务
+    if config.jobs.enabled {
+        api::jobs::spawn_transaction_jobs(state.uow_factory.clone(), config.jobs.clone());
+    }
+
     // 构建路由
     let app = api::routes::app_routes(state);
 
@@ -49,7 +97,7 @@ async fn main() -> anyhow::Result<()> {
     let listener = tokio::net::TcpListener::bind(&addr).await?;
     tracing::info!("Server listening on {}", addr);
 
-    axum::serve(listener, app).await?;
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await?;
 
     Ok(())
 }
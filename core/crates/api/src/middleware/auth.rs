@@ -1,109 +1,258 @@
 //! JWT 认证中间件
+//!
+//! 令牌携带签发时的角色、状态、管理的职业和令牌版本。
+//! `auth_middleware` 每个请求都会按主键查询一次会员，核对状态和令牌版本：
+//! 这是有意为之，保证封禁、停用和权限变更立即生效，而不是等到访问令牌过期（不做缓存，缓存会重新引入这段延迟）。
+//! 核对通过后令牌中的声明与会员当前数据一致，处理器可通过 `RequireRole`、`RequireProfessionManager`
+//! 提取器直接基于声明做权限守卫，无需再次查询会员。
+
+use std::{collections::HashMap, marker::PhantomData};
 
 use axum::{
     async_trait,
-    extract::{FromRequestParts, Request, Extension},
-    http::{header::AUTHORIZATION, request::Parts, StatusCode},
+    extract::{FromRequestParts, Path, Request, State},
+    http::{header::AUTHORIZATION, request::Parts},
     middleware::Next,
     response::Response,
 };
-use jsonwebtoken::{decode, encode, DecodingKey, EncodingKey, Header, Validation};
+use jsonwebtoken::{decode, decode_header, encode, Header, Validation};
 use serde::{Deserialize, Serialize};
 use shared::AppError;
-use domain::member::MemberId;
+use domain::member::{Member, MemberId, MemberStatus, UserRole};
+
+use crate::{jwt_keys::JwtKeys, AppState};
+use domain::profession::ProfessionType;
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Claims {
     pub sub: String,
     pub exp: usize,
+    /// 签发时的角色
+    pub role: UserRole,
+    /// 签发时的会员状态
+    pub status: MemberStatus,
+    /// 决策者管理的职业
+    #[serde(default)]
+    pub managed_professions: Vec<ProfessionType>,
+    /// 令牌版本，对应会员的 token_version
+    pub ver: i32,
+}
+
+impl Claims {
+    /// 令牌所属会员ID
+    pub fn member_id(&self) -> Result<MemberId, AppError> {
+        shared::Id::from_string(&self.sub).map_err(|_| AppError::Unauthorized)
+    }
+
+    /// 检查是否是管理员
+    pub fn is_admin(&self) -> bool {
+        self.role == UserRole::Admin
+    }
+
+    /// 检查是否可以管理指定职业（与 `Member::can_manage_profession` 规则一致）
+    pub fn can_manage_profession(&self, profession: &ProfessionType) -> bool {
+        self.role.can_manage_profession(&self.managed_professions, profession)
+    }
 }
 
 /// 当前认证用户的ID
 #[derive(Debug, Clone)]
 pub struct CurrentUser(pub MemberId);
 
-pub fn generate_token(user_id: &str, secret: &str, expires_in: i64) -> Result<String, AppError> {
+/// 使用当前签名密钥签发访问令牌（令牌头携带 kid）
+pub fn generate_token(member: &Member, keys: &JwtKeys, expires_in: i64) -> Result<String, AppError> {
     let expiration = chrono::Utc::now()
         .checked_add_signed(chrono::Duration::seconds(expires_in))
         .ok_or_else(|| AppError::internal("token 过期时间计算失败"))?
         .timestamp() as usize;
 
     let claims = Claims {
-        sub: user_id.to_string(),
+        sub: member.id.to_string(),
         exp: expiration,
+        role: member.role,
+        status: member.status,
+        managed_professions: member.managed_professions.clone(),
+        ver: member.token_version,
     };
 
-    encode(
-        &Header::default(),
-        &claims,
-        &EncodingKey::from_secret(secret.as_bytes()),
-    )
-    .map_err(|e| AppError::internal(format!("token 生成失败: {}", e)))
+    let signing = keys.signing_key();
+    let mut header = Header::new(signing.algorithm);
+    header.kid = Some(signing.kid.clone());
+
+    encode(&header, &claims, &signing.key)
+        .map_err(|e| AppError::internal(format!("token 生成失败: {}", e)))
 }
 
-pub fn verify_token(token: &str, secret: &str) -> Result<Claims, AppError> {
-    decode::<Claims>(
-        token,
-        &DecodingKey::from_secret(secret.as_bytes()),
-        &Validation::default(),
-    )
-    .map(|data| data.claims)
-    .map_err(|_| AppError::Unauthorized)
+/// 按令牌头的 kid 选择验证密钥并校验令牌（只接受该密钥的算法）
+pub fn verify_token(token: &str, keys: &JwtKeys) -> Result<Claims, AppError> {
+    let kid = decode_header(token)
+        .ok()
+        .and_then(|header| header.kid)
+        .ok_or(AppError::Unauthorized)?;
+    let verification = keys.verification_key(&kid).ok_or(AppError::Unauthorized)?;
+
+    decode::<Claims>(token, &verification.key, &Validation::new(verification.algorithm))
+        .map(|data| data.claims)
+        .map_err(|_| AppError::Unauthorized)
 }
 
-/// CurrentUser extractor - 从Extensions中提取已验证的Claims
+/// Claims extractor - 从Extensions中提取已验证的Claims
 #[async_trait]
-impl<S> FromRequestParts<S> for CurrentUser
+impl<S> FromRequestParts<S> for Claims
 where
     S: Send + Sync,
 {
     type Rejection = AppError;
 
     async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
-        // 从中间件中提取已验证的Claims
-        let claims = parts
+        parts
             .extensions
             .get::<Claims>()
             .cloned()
-            .ok_or(AppError::Unauthorized)?;
-        
-        // 将user_id字符串转换为MemberId
-        let user_id = shared::Id::from_string(&claims.sub)
-            .map_err(|_| AppError::Unauthorized)?;
+            .ok_or(AppError::Unauthorized)
+    }
+}
+
+/// CurrentUser extractor - 从Extensions中提取已验证的Claims
+#[async_trait]
+impl<S> FromRequestParts<S> for CurrentUser
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        Ok(CurrentUser(claims.member_id()?))
+    }
+}
+
+/// 角色要求（`RequireRole` 的类型参数）
+pub trait RoleRequirement: Send + Sync + 'static {
+    /// 权限不足时的提示
+    const MESSAGE: &'static str;
+
+    fn allows(role: UserRole) -> bool;
+}
+
+/// 管理员
+pub struct Admin;
+
+impl RoleRequirement for Admin {
+    const MESSAGE: &'static str = "需要管理员权限";
+
+    fn allows(role: UserRole) -> bool {
+        role == UserRole::Admin
+    }
+}
 
-        Ok(CurrentUser(user_id))
+/// 决策者（管理员同样满足）
+pub struct Decider;
+
+impl RoleRequirement for Decider {
+    const MESSAGE: &'static str = "需要决策者或管理员权限";
+
+    fn allows(role: UserRole) -> bool {
+        matches!(role, UserRole::Decider | UserRole::Admin)
+    }
+}
+
+/// 角色守卫：令牌中的角色不满足要求时返回 403
+///
+/// ```ignore
+/// async fn handler(RequireRole(admin_id, ..): RequireRole<Admin>) { ... }
+/// ```
+pub struct RequireRole<R: RoleRequirement>(pub MemberId, pub PhantomData<R>);
+
+#[async_trait]
+impl<S, R> FromRequestParts<S> for RequireRole<R>
+where
+    S: Send + Sync,
+    R: RoleRequirement,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        if !R::allows(claims.role) {
+            return Err(AppError::forbidden(R::MESSAGE));
+        }
+
+        Ok(RequireRole(claims.member_id()?, PhantomData))
     }
 }
 
-/// Auth middleware - 验证JWT token並将Claims组件支Extensions
-pub async fn auth_middleware_with_secret(
+/// 职业管理守卫：路由路径参数 `code` 指定的职业必须由当前用户管理（管理员或该职业的决策者）
+pub struct RequireProfessionManager {
+    pub member_id: MemberId,
+    pub profession: ProfessionType,
+}
+
+#[async_trait]
+impl<S> FromRequestParts<S> for RequireProfessionManager
+where
+    S: Send + Sync,
+{
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        let claims = Claims::from_request_parts(parts, state).await?;
+
+        let Path(params) = Path::<HashMap<String, String>>::from_request_parts(parts, state)
+            .await
+            .map_err(|_| AppError::internal("路由缺少职业编码参数"))?;
+        let profession: ProfessionType = params
+            .get("code")
+            .ok_or_else(|| AppError::internal("路由缺少职业编码参数"))?
+            .parse()?;
+
+        if !claims.can_manage_profession(&profession) {
+            return Err(AppError::forbidden(format!("无权管理职业: {}", profession)));
+        }
+
+        Ok(RequireProfessionManager {
+            member_id: claims.member_id()?,
+            profession,
+        })
+    }
+}
+
+/// Auth middleware - 验证JWT token并将Claims存入Extensions
+///
+/// 令牌版本与会员当前版本不一致（角色、状态或管理的职业已变化）时返回 401，客户端需用刷新令牌续期；
+/// 会员已停用或封禁时返回 403，封禁立即生效。
+pub async fn auth_middleware(
+    State(state): State<AppState>,
     mut req: Request,
     next: Next,
-    secret: String,
-) -> Result<Response, StatusCode> {
-    // 提取Authorization header
-    let auth_header = req
+) -> Result<Response, AppError> {
+    // 提取Authorization header，期望格式: "Bearer <token>"
+    let claims = req
         .headers()
         .get(AUTHORIZATION)
         .and_then(|h| h.to_str().ok())
-        .map(|s| s.to_string());
-
-    if let Some(auth_header) = auth_header {
-        // 期望格式: "Bearer <token>"
-        if let Some(token) = auth_header.strip_prefix("Bearer ") {
-            // 验证token
-            if let Ok(claims) = verify_token(token, &secret) {
-                // 将Claims存入Extensions
-                req.extensions_mut().insert(claims);
-                return Ok(next.run(req).await);
-            }
-        }
+        .and_then(|h| h.strip_prefix("Bearer "))
+        .ok_or(AppError::Unauthorized)
+        .and_then(|token| verify_token(token, &state.jwt_keys))?;
+
+    // 核对会员当前的状态和令牌版本（每个请求一次主键查询，保证封禁和权限变更立即生效）
+    let member = state
+        .member_repo
+        .find_by_id(claims.member_id()?)
+        .await?
+        .ok_or(AppError::Unauthorized)?;
+
+    if !member.is_active() {
+        return Err(AppError::forbidden("账号未激活或已被封禁"));
     }
 
-    Err(StatusCode::UNAUTHORIZED)
-}
+    if member.token_version != claims.ver {
+        return Err(AppError::Unauthorized);
+    }
 
-/// Legacy auth middleware for backward compatibility
-pub async fn auth_middleware(req: Request, next: Next) -> Result<Response, StatusCode> {
-    auth_middleware_with_secret(req, next, "your-secret-key-change-in-production".to_string()).await
+    // 将Claims存入Extensions
+    req.extensions_mut().insert(claims);
+    Ok(next.run(req).await)
 }
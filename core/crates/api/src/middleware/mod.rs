@@ -1,6 +1,7 @@
 //! 中间件模块
 
 pub mod auth;
+pub mod request_context;
 
 // pub mod error;
 // pub mod trace;
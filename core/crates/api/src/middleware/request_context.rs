@@ -0,0 +1,152 @@
+//! 请求上下文
+//!
+//! 每个请求都带有 `x-request-id`（客户端未提供或格式无效时由服务端生成，并回写到响应头），
+//! 特权操作通过 `RequestContext` 提取器把请求ID和客户端IP带入审计事件。
+
+use std::{convert::Infallible, net::IpAddr, net::SocketAddr};
+
+use axum::{
+    async_trait,
+    extract::{ConnectInfo, FromRequestParts, Request},
+    http::request::Parts,
+};
+use domain::audit::AuditContext;
+
+use crate::AppState;
+
+/// 请求ID头
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// 请求ID最大长度（与 audit_events.request_id 列一致）
+const MAX_REQUEST_ID_LEN: usize = 100;
+
+/// 部署在反向代理之后时，由代理写入的客户端地址头
+const FORWARDED_FOR_HEADER: &str = "x-forwarded-for";
+
+/// 发起请求的上下文（请求ID、客户端IP）
+#[derive(Debug, Clone, Default)]
+pub struct RequestContext(pub AuditContext);
+
+#[async_trait]
+impl FromRequestParts<AppState> for RequestContext {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let request_id = parts
+            .headers
+            .get(REQUEST_ID_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .filter(|value| is_valid_request_id(value))
+            .map(str::to_string);
+
+        let peer = parts
+            .extensions
+            .get::<ConnectInfo<SocketAddr>>()
+            .map(|ConnectInfo(addr)| addr.ip());
+
+        let forwarded_for = parts
+            .headers
+            .get(FORWARDED_FOR_HEADER)
+            .and_then(|value| value.to_str().ok());
+
+        let ip_address =
+            peer.map(|peer| client_ip(peer, forwarded_for, &state.config.server.trusted_proxies));
+
+        Ok(RequestContext(AuditContext {
+            request_id,
+            ip_address,
+        }))
+    }
+}
+
+/// 丢弃格式无效的请求ID，交由 `SetRequestIdLayer` 重新生成
+///
+/// 需放在 `SetRequestIdLayer` 之前执行，保证响应头、日志和审计事件使用同一个请求ID。
+pub async fn sanitize_request_id(mut request: Request) -> Request {
+    let valid = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .map(|value| value.to_str().is_ok_and(is_valid_request_id));
+
+    if valid == Some(false) {
+        request.headers_mut().remove(REQUEST_ID_HEADER);
+    }
+
+    request
+}
+
+/// 请求ID只接受有限长度的字母、数字和 `-_.:`
+fn is_valid_request_id(value: &str) -> bool {
+    !value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LEN
+        && value
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b':'))
+}
+
+/// 解析客户端IP
+///
+/// 只有 TCP 对端是受信任的代理时才采信 `X-Forwarded-For`：从右向左跳过受信任的代理，
+/// 取第一个不受信任的地址；遇到无法解析的条目即停止，使用最后一个可信的地址。
+fn client_ip(peer: IpAddr, forwarded_for: Option<&str>, trusted_proxies: &[IpAddr]) -> IpAddr {
+    if !trusted_proxies.contains(&peer) {
+        return peer;
+    }
+
+    let mut client = peer;
+    for entry in forwarded_for.unwrap_or_default().rsplit(',') {
+        let Ok(ip) = entry.trim().parse::<IpAddr>() else {
+            break;
+        };
+        client = ip;
+        if !trusted_proxies.contains(&ip) {
+            break;
+        }
+    }
+
+    client
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn untrusted_peer_ignores_forwarded_for() {
+        let client = client_ip(ip("203.0.113.7"), Some("1.2.3.4"), &[]);
+        assert_eq!(client, ip("203.0.113.7"));
+    }
+
+    #[test]
+    fn trusted_proxy_uses_rightmost_untrusted_address() {
+        let proxies = [ip("10.0.0.1"), ip("10.0.0.2")];
+        let client = client_ip(
+            ip("10.0.0.2"),
+            Some("1.2.3.4, 198.51.100.9, 10.0.0.1"),
+            &proxies,
+        );
+        assert_eq!(client, ip("198.51.100.9"));
+    }
+
+    #[test]
+    fn trusted_proxy_stops_at_invalid_entry() {
+        let proxies = [ip("10.0.0.1")];
+        let client = client_ip(ip("10.0.0.1"), Some("1.2.3.4, not-an-ip"), &proxies);
+        assert_eq!(client, ip("10.0.0.1"));
+    }
+
+    #[test]
+    fn request_id_rejects_oversized_and_unsafe_values() {
+        assert!(is_valid_request_id("3f0c9d1e-6f4b-4c1a-9b0e-2f1a5c7d8e90"));
+        assert!(!is_valid_request_id(""));
+        assert!(!is_valid_request_id(&"a".repeat(MAX_REQUEST_ID_LEN + 1)));
+        assert!(!is_valid_request_id("id with spaces"));
+    }
+}
@@ -4,9 +4,38 @@ use utoipa::{OpenApi, Modify};
 use utoipa::openapi::security::{SecurityScheme, HttpBuilder, HttpAuthScheme};
 
 use crate::dto::{
+    admin::{
+        AdminMemberDto, AssignDeciderRequest, AuditEventDto, AuditEventListQuery,
+        ChangeMemberRoleRequest, ChangeMemberStatusRequest, MemberListQuery, RevokeDeciderRequest,
+    },
     common::{ApiResponse, PaginatedResponse, PaginationQuery},
+    dispute::{
+        AssignDisputeRequest, DisputeDetailsDto, DisputeDto, DisputeEvidenceDto, DisputeRulingDto,
+        ResolveDisputeRequest, SubmitEvidenceRequest,
+    },
+    isu::{
+        AdjustBalanceRequest, CirculationDto, ISUAccountDto, ISUTransactionDto, StatementEntryDto,
+        StatementQuery, StatementResponse,
+    },
     member::{LoginRequest, LoginResponse, MemberDto, RegisterRequest},
-    tool::{CreateToolRequest, ToolDto, UpdateToolRequest},
+    profession::{
+        CategoryDto, CategoryListQuery, CreateCategoryRequest, CreateProfessionRequest,
+        ProfessionDto, ProfessionListQuery, ProfessionRateDto, UpdateCategoryRequest,
+        UpdateProfessionRateRequest, UpdateProfessionRequest,
+    },
+    review::{MemberReviewsDto, RatingSummaryDto, ReviewDto, SubmitReviewRequest},
+    service::{
+        ListServicesQuery, PublishServiceRequest, SearchServicesQuery, ServiceDto,
+        UpdateServiceRequest,
+    },
+    tool::{
+        AvailabilityQuery, BlockToolDatesRequest, CreateToolRequest, TimeWindowDto,
+        ToolAvailabilityDto, ToolDto, ToolReservationDto, UpdateToolRequest,
+    },
+    transaction::{
+        CreateTransactionRequest, DisputeTransactionRequest, DisputeTransactionResponse,
+        RentToolRequest, RentToolResponse, ToolRentalDto, TransactionDto, TransactionListQuery,
+    },
 };
 
 /// OpenAPI 文档结构
@@ -20,12 +49,62 @@ use crate::dto::{
     paths(
         crate::v1::member::register,
         crate::v1::member::login,
+        crate::v1::member::refresh,
+        crate::v1::member::logout,
         crate::v1::tool::create_tool_handler,
         crate::v1::tool::get_tool_handler,
         crate::v1::tool::list_tools_handler,
         crate::v1::tool::update_tool_handler,
         crate::v1::tool::delete_tool_handler,
         crate::v1::tool::list_tools_by_owner_handler,
+        crate::v1::tool::get_tool_availability_handler,
+        crate::v1::tool::block_tool_dates_handler,
+        crate::v1::tool::unblock_tool_dates_handler,
+        crate::v1::service::publish_service_handler,
+        crate::v1::service::list_services_handler,
+        crate::v1::service::search_services_handler,
+        crate::v1::service::get_service_handler,
+        crate::v1::service::update_service_handler,
+        crate::v1::service::cancel_service_handler,
+        crate::v1::service::list_services_by_provider_handler,
+        crate::v1::transaction::create_transaction_handler,
+        crate::v1::transaction::rent_tool_handler,
+        crate::v1::transaction::list_purchases_handler,
+        crate::v1::transaction::list_sales_handler,
+        crate::v1::transaction::get_transaction_handler,
+        crate::v1::transaction::confirm_transaction_handler,
+        crate::v1::transaction::start_transaction_handler,
+        crate::v1::transaction::complete_transaction_handler,
+        crate::v1::transaction::cancel_transaction_handler,
+        crate::v1::transaction::dispute_transaction_handler,
+        crate::v1::transaction::return_tool_handler,
+        crate::v1::transaction::get_tool_rental_handler,
+        crate::v1::dispute::list_assigned_disputes_handler,
+        crate::v1::dispute::get_dispute_handler,
+        crate::v1::dispute::submit_evidence_handler,
+        crate::v1::dispute::assign_dispute_handler,
+        crate::v1::dispute::resolve_dispute_handler,
+        crate::v1::review::submit_review_handler,
+        crate::v1::review::list_member_reviews_handler,
+        crate::v1::review::list_transaction_reviews_handler,
+        crate::v1::isu::get_my_account_handler,
+        crate::v1::isu::get_my_statement_handler,
+        crate::v1::isu::adjust_balance_handler,
+        crate::v1::isu::get_circulation_handler,
+        crate::v1::profession::list_professions_handler,
+        crate::v1::profession::create_profession_handler,
+        crate::v1::profession::update_profession_handler,
+        crate::v1::profession::update_profession_rate_handler,
+        crate::v1::profession::list_categories_handler,
+        crate::v1::profession::create_category_handler,
+        crate::v1::profession::update_category_handler,
+        crate::v1::admin::list_members_handler,
+        crate::v1::admin::get_member_handler,
+        crate::v1::admin::change_member_status_handler,
+        crate::v1::admin::change_member_role_handler,
+        crate::v1::admin::assign_decider_handler,
+        crate::v1::admin::revoke_decider_handler,
+        crate::v1::admin::list_audit_events_handler,
     ),
     components(
         schemas(
@@ -42,11 +121,105 @@ use crate::dto::{
             UpdateToolRequest,
             ToolDto,
             PaginatedResponse<ToolDto>,
+            ApiResponse<ToolAvailabilityDto>,
+            ApiResponse<ToolReservationDto>,
+            AvailabilityQuery,
+            BlockToolDatesRequest,
+            TimeWindowDto,
+            ToolReservationDto,
+            ToolAvailabilityDto,
+            ApiResponse<ServiceDto>,
+            ApiResponse<Vec<ServiceDto>>,
+            ApiResponse<PaginatedResponse<ServiceDto>>,
+            PublishServiceRequest,
+            UpdateServiceRequest,
+            ListServicesQuery,
+            SearchServicesQuery,
+            ServiceDto,
+            PaginatedResponse<ServiceDto>,
+            ApiResponse<TransactionDto>,
+            ApiResponse<Vec<TransactionDto>>,
+            CreateTransactionRequest,
+            TransactionListQuery,
+            TransactionDto,
+            ApiResponse<RentToolResponse>,
+            ApiResponse<ToolRentalDto>,
+            RentToolRequest,
+            RentToolResponse,
+            ToolRentalDto,
+            ApiResponse<DisputeTransactionResponse>,
+            DisputeTransactionRequest,
+            DisputeTransactionResponse,
+            ApiResponse<DisputeDto>,
+            ApiResponse<Vec<DisputeDto>>,
+            ApiResponse<DisputeDetailsDto>,
+            ApiResponse<DisputeEvidenceDto>,
+            SubmitEvidenceRequest,
+            AssignDisputeRequest,
+            ResolveDisputeRequest,
+            DisputeDto,
+            DisputeRulingDto,
+            DisputeEvidenceDto,
+            DisputeDetailsDto,
+            ApiResponse<ReviewDto>,
+            ApiResponse<Vec<ReviewDto>>,
+            ApiResponse<MemberReviewsDto>,
+            SubmitReviewRequest,
+            RatingSummaryDto,
+            ReviewDto,
+            PaginatedResponse<ReviewDto>,
+            MemberReviewsDto,
+            ApiResponse<ISUAccountDto>,
+            ApiResponse<StatementResponse>,
+            ApiResponse<ISUTransactionDto>,
+            ApiResponse<CirculationDto>,
+            CirculationDto,
+            ISUAccountDto,
+            StatementQuery,
+            StatementEntryDto,
+            StatementResponse,
+            AdjustBalanceRequest,
+            ISUTransactionDto,
+            ApiResponse<ProfessionDto>,
+            ApiResponse<Vec<ProfessionDto>>,
+            ApiResponse<CategoryDto>,
+            ApiResponse<Vec<CategoryDto>>,
+            ApiResponse<ProfessionRateDto>,
+            ProfessionListQuery,
+            CategoryListQuery,
+            CreateProfessionRequest,
+            UpdateProfessionRequest,
+            CreateCategoryRequest,
+            UpdateCategoryRequest,
+            UpdateProfessionRateRequest,
+            ProfessionDto,
+            CategoryDto,
+            ProfessionRateDto,
+            ApiResponse<AdminMemberDto>,
+            ApiResponse<PaginatedResponse<AdminMemberDto>>,
+            MemberListQuery,
+            ChangeMemberStatusRequest,
+            ChangeMemberRoleRequest,
+            AssignDeciderRequest,
+            RevokeDeciderRequest,
+            AdminMemberDto,
+            PaginatedResponse<AdminMemberDto>,
+            ApiResponse<PaginatedResponse<AuditEventDto>>,
+            AuditEventListQuery,
+            AuditEventDto,
+            PaginatedResponse<AuditEventDto>,
         )
     ),
     tags(
         (name = "members", description = "会员管理"),
         (name = "tools", description = "工具管理"),
+        (name = "services", description = "服务市场"),
+        (name = "transactions", description = "交易管理"),
+        (name = "disputes", description = "争议仲裁"),
+        (name = "reviews", description = "交易评价"),
+        (name = "isu", description = "ISU钱包"),
+        (name = "professions", description = "职业目录"),
+        (name = "admin", description = "管理后台"),
     ),
     modifiers(&SecurityAddon)
 )]
@@ -1,42 +1,82 @@
 //! 路由配置
 
-use axum::{http::StatusCode, middleware, response::Json, routing::get, Router};
+use axum::{extract::State, http::StatusCode, middleware, response::Json, routing::get, Router};
+use jsonwebtoken::jwk::JwkSet;
 use serde_json::{json, Value};
 use shared::constants::API_VERSION_V1;
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 use crate::AppState;
 use crate::openapi::ApiDoc;
-use crate::middleware::auth::{auth_middleware_with_secret, Claims};
-use axum::extract::Request;
-use tower::Layer;
+use crate::middleware::auth::auth_middleware;
+use crate::middleware::request_context::sanitize_request_id;
 
 pub fn app_routes(state: AppState) -> Router {
-    let router = Router::new()
+    Router::new()
         .merge(SwaggerUi::new("/api/docs").url("/api/openapi.json", ApiDoc::openapi()))
+        .route("/.well-known/jwks.json", get(jwks).with_state(state.clone()))
         .nest(API_VERSION_V1, v1_routes(state))
         .route("/health", get(health_check))
-        .route("/ready", get(readiness_check));
-    
-    router
+        .route("/ready", get(readiness_check))
+        // 为每个请求分配请求ID（客户端已提供且格式有效则沿用），并回写到响应头
+        .layer(PropagateRequestIdLayer::x_request_id())
+        .layer(SetRequestIdLayer::x_request_id(MakeRequestUuid))
+        .layer(middleware::map_request(sanitize_request_id))
 }
 
 fn v1_routes(state: AppState) -> Router {
-    let secret = state.config.jwt.secret.clone();
-    
     Router::new()
         .nest("/members", crate::v1::member::routes())
         .nest(
             "/tools",
             crate::v1::tool::routes()
-                .layer(middleware::from_fn(move |req: Request, next| {
-                    auth_middleware_with_secret(req, next, secret.clone())
-                })),
+                .layer(middleware::from_fn_with_state(state.clone(), auth_middleware)),
+        )
+        .nest(
+            "/services",
+            crate::v1::service::routes()
+                .layer(middleware::from_fn_with_state(state.clone(), auth_middleware)),
+        )
+        .nest(
+            "/transactions",
+            crate::v1::transaction::routes()
+                .layer(middleware::from_fn_with_state(state.clone(), auth_middleware)),
+        )
+        .nest(
+            "/disputes",
+            crate::v1::dispute::routes()
+                .layer(middleware::from_fn_with_state(state.clone(), auth_middleware)),
+        )
+        .nest(
+            "/reviews",
+            crate::v1::review::routes()
+                .layer(middleware::from_fn_with_state(state.clone(), auth_middleware)),
+        )
+        .nest(
+            "/isu",
+            crate::v1::isu::routes()
+                .layer(middleware::from_fn_with_state(state.clone(), auth_middleware)),
+        )
+        .nest(
+            "/professions",
+            crate::v1::profession::routes()
+                .layer(middleware::from_fn_with_state(state.clone(), auth_middleware)),
+        )
+        .nest(
+            "/admin",
+            crate::v1::admin::routes()
+                .layer(middleware::from_fn_with_state(state.clone(), auth_middleware)),
         )
         .with_state(state)
 }
 
+/// 公开当前有效的验证公钥，供其他服务验证访问令牌
+async fn jwks(State(state): State<AppState>) -> Json<JwkSet> {
+    Json(state.jwt_keys.jwks().clone())
+}
+
 async fn health_check() -> (StatusCode, Json<Value>) {
     (StatusCode::OK, Json(json!({ "status": "ok" })))
 }
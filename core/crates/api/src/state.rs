@@ -2,29 +2,32 @@
 
 use std::sync::Arc;
 
-use domain::{member::MemberRepository, tool::ToolRepository};
+use domain::{
+    audit::AuditEventRepository, auth::RefreshTokenRepository, dispute::DisputeRepository, isu::ISUAccountRepository, member::MemberRepository, profession::{ProfessionCatalogRepository, ProfessionStandardRepository},
+    review::ReviewRepository,
+    service::ServiceRepository, tool::{ToolRentalRepository, ToolRepository, ToolReservationRepository}, transaction::TransactionRepository, unit_of_work::UnitOfWorkFactory,
+};
 use shared::AppConfig;
 
+use crate::jwt_keys::JwtKeys;
+
 #[derive(Clone)]
 pub struct AppState {
     pub member_repo: Arc<dyn MemberRepository>,
     pub tool_repo: Arc<dyn ToolRepository>,
+    pub tool_rental_repo: Arc<dyn ToolRentalRepository>,
+    pub tool_reservation_repo: Arc<dyn ToolReservationRepository>,
+    pub service_repo: Arc<dyn ServiceRepository>,
+    pub transaction_repo: Arc<dyn TransactionRepository>,
+    pub dispute_repo: Arc<dyn DisputeRepository>,
+    pub review_repo: Arc<dyn ReviewRepository>,
+    pub refresh_token_repo: Arc<dyn RefreshTokenRepository>,
+    pub audit_event_repo: Arc<dyn AuditEventRepository>,
+    pub isu_repo: Arc<dyn ISUAccountRepository>,
+    pub profession_repo: Arc<dyn ProfessionStandardRepository>,
+    pub profession_catalog_repo: Arc<dyn ProfessionCatalogRepository>,
+    pub uow_factory: Arc<dyn UnitOfWorkFactory>,
     pub password_hasher: Arc<dyn infra::PasswordHasher>,
+    pub jwt_keys: Arc<JwtKeys>,
     pub config: Arc<AppConfig>,
 }
-
-impl AppState {
-    pub fn new(
-        member_repo: Arc<dyn MemberRepository>,
-        tool_repo: Arc<dyn ToolRepository>,
-        password_hasher: Arc<dyn infra::PasswordHasher>,
-        config: Arc<AppConfig>,
-    ) -> Self {
-        Self {
-            member_repo,
-            tool_repo,
-            password_hasher,
-            config,
-        }
-    }
-}
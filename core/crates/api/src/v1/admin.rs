@@ -0,0 +1,382 @@
+//! 管理后台 API 端点
+
+use axum::{
+    extract::{Path, Query, State},
+    routing::{get, put},
+    Json, Router,
+};
+use domain::{
+    audit::{AuditAction, AuditEventCriteria, AuditTargetType},
+    member::{MemberSearchCriteria, MemberStatus, UserRole},
+    profession::ProfessionType,
+};
+
+use crate::{
+    dto::{
+        admin::{
+            AdminMemberDto, AssignDeciderRequest, AuditEventDto, AuditEventListQuery,
+            ChangeMemberRoleRequest, ChangeMemberStatusRequest, MemberListQuery,
+            RevokeDeciderRequest,
+        },
+        common::{ApiResponse, PaginatedResponse},
+    },
+    middleware::{
+        auth::{Admin, RequireRole},
+        request_context::RequestContext,
+    },
+    v1::parse_id,
+    AppState,
+};
+use app::{
+    audit::{list_audit_events, ListAuditEventsInput},
+    member::{
+        change_member_role, change_member_status, get_member, list_members,
+        ChangeMemberRoleInput, ChangeMemberStatusInput, ListMembersInput,
+    },
+    profession::{assign_decider, revoke_decider, AssignDeciderInput},
+};
+use shared::AppError;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/members", get(list_members_handler))
+        .route("/members/:id", get(get_member_handler))
+        .route("/members/:id/status", put(change_member_status_handler))
+        .route("/members/:id/role", put(change_member_role_handler))
+        .route(
+            "/members/:id/decider",
+            put(assign_decider_handler).delete(revoke_decider_handler),
+        )
+        .route("/audit-events", get(list_audit_events_handler))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/members",
+    tag = "admin",
+    params(MemberListQuery),
+    responses(
+        (status = 200, description = "会员列表", body = ApiResponse<PaginatedResponse<AdminMemberDto>>),
+        (status = 403, description = "需要管理员权限")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_members_handler(
+    State(state): State<AppState>,
+    _admin: RequireRole<Admin>,
+    Query(query): Query<MemberListQuery>,
+) -> Result<Json<ApiResponse<PaginatedResponse<AdminMemberDto>>>, AppError> {
+    let (limit, offset) = page_window(&state, query.page, query.page_size)?;
+
+    let criteria = MemberSearchCriteria {
+        keyword: query.keyword.trim().to_string(),
+        status: non_empty(query.status.as_deref())
+            .map(str::parse::<MemberStatus>)
+            .transpose()?,
+        role: non_empty(query.role.as_deref())
+            .map(str::parse::<UserRole>)
+            .transpose()?,
+    };
+
+    let output = list_members(
+        state.member_repo.as_ref(),
+        ListMembersInput {
+            criteria,
+            limit: Some(limit),
+            offset: Some(offset),
+        },
+    )
+    .await?;
+
+    let dtos: Vec<AdminMemberDto> = output.members.iter().map(AdminMemberDto::from).collect();
+    let response = PaginatedResponse::new(
+        dtos,
+        output.total_count as i64,
+        query.page,
+        i64::from(limit),
+    );
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/members/{id}",
+    tag = "admin",
+    params(
+        ("id" = String, Path, description = "会员ID")
+    ),
+    responses(
+        (status = 200, description = "会员详情", body = ApiResponse<AdminMemberDto>),
+        (status = 403, description = "需要管理员权限"),
+        (status = 404, description = "会员不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_member_handler(
+    State(state): State<AppState>,
+    _admin: RequireRole<Admin>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<AdminMemberDto>>, AppError> {
+    let member_id = parse_id(&id, "无效的会员 ID")?;
+
+    let member = get_member(state.member_repo.as_ref(), member_id).await?;
+
+    Ok(Json(ApiResponse::success(AdminMemberDto::from(&member))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/members/{id}/status",
+    tag = "admin",
+    params(
+        ("id" = String, Path, description = "会员ID")
+    ),
+    request_body = ChangeMemberStatusRequest,
+    responses(
+        (status = 200, description = "状态已变更，停用或封禁立即生效", body = ApiResponse<AdminMemberDto>),
+        (status = 400, description = "缺少原因或状态未变化"),
+        (status = 403, description = "需要管理员权限")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn change_member_status_handler(
+    State(state): State<AppState>,
+    RequireRole(admin_id, ..): RequireRole<Admin>,
+    RequestContext(context): RequestContext,
+    Path(id): Path<String>,
+    Json(req): Json<ChangeMemberStatusRequest>,
+) -> Result<Json<ApiResponse<AdminMemberDto>>, AppError> {
+    let member_id = parse_id(&id, "无效的会员 ID")?;
+
+    let member = change_member_status(
+        state.uow_factory.as_ref(),
+        ChangeMemberStatusInput {
+            admin_id,
+            member_id,
+            status: req.status.parse()?,
+            reason: req.reason,
+            context,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(AdminMemberDto::from(&member))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/members/{id}/role",
+    tag = "admin",
+    params(
+        ("id" = String, Path, description = "会员ID")
+    ),
+    request_body = ChangeMemberRoleRequest,
+    responses(
+        (status = 200, description = "角色已变更", body = ApiResponse<AdminMemberDto>),
+        (status = 400, description = "角色无效或未变化"),
+        (status = 403, description = "需要管理员权限")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn change_member_role_handler(
+    State(state): State<AppState>,
+    RequireRole(admin_id, ..): RequireRole<Admin>,
+    RequestContext(context): RequestContext,
+    Path(id): Path<String>,
+    Json(req): Json<ChangeMemberRoleRequest>,
+) -> Result<Json<ApiResponse<AdminMemberDto>>, AppError> {
+    let member_id = parse_id(&id, "无效的会员 ID")?;
+
+    let member = change_member_role(
+        state.uow_factory.as_ref(),
+        ChangeMemberRoleInput {
+            admin_id,
+            member_id,
+            role: req.role.parse()?,
+            reason: req.reason,
+            context,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(AdminMemberDto::from(&member))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/members/{id}/decider",
+    tag = "admin",
+    params(
+        ("id" = String, Path, description = "会员ID")
+    ),
+    request_body = AssignDeciderRequest,
+    responses(
+        (status = 200, description = "已设置为决策者", body = ApiResponse<AdminMemberDto>),
+        (status = 400, description = "职业无效"),
+        (status = 403, description = "需要管理员权限")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn assign_decider_handler(
+    State(state): State<AppState>,
+    RequireRole(admin_id, ..): RequireRole<Admin>,
+    RequestContext(context): RequestContext,
+    Path(id): Path<String>,
+    Json(req): Json<AssignDeciderRequest>,
+) -> Result<Json<ApiResponse<AdminMemberDto>>, AppError> {
+    let member_id = parse_id(&id, "无效的会员 ID")?;
+
+    let managed_professions = req
+        .professions
+        .iter()
+        .map(|code| code.parse::<ProfessionType>())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let output = assign_decider(
+        state.uow_factory.as_ref(),
+        AssignDeciderInput {
+            admin_id,
+            target_member_id: member_id,
+            managed_professions,
+            reason: req.reason,
+            context,
+        },
+    )
+    .await?;
+
+    let member = get_member(state.member_repo.as_ref(), member_id).await?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        AdminMemberDto::from(&member),
+        output.message,
+    )))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/members/{id}/decider",
+    tag = "admin",
+    params(
+        ("id" = String, Path, description = "会员ID")
+    ),
+    request_body = RevokeDeciderRequest,
+    responses(
+        (status = 200, description = "已撤销决策者权限", body = ApiResponse<AdminMemberDto>),
+        (status = 403, description = "需要管理员权限")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn revoke_decider_handler(
+    State(state): State<AppState>,
+    RequireRole(admin_id, ..): RequireRole<Admin>,
+    RequestContext(context): RequestContext,
+    Path(id): Path<String>,
+    req: Option<Json<RevokeDeciderRequest>>,
+) -> Result<Json<ApiResponse<AdminMemberDto>>, AppError> {
+    let member_id = parse_id(&id, "无效的会员 ID")?;
+    let Json(req) = req.unwrap_or_default();
+
+    let message = revoke_decider(
+        state.uow_factory.as_ref(),
+        admin_id,
+        member_id,
+        req.reason,
+        &context,
+    )
+    .await?;
+
+    let member = get_member(state.member_repo.as_ref(), member_id).await?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        AdminMemberDto::from(&member),
+        message,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/audit-events",
+    tag = "admin",
+    params(AuditEventListQuery),
+    responses(
+        (status = 200, description = "按时间倒序的审计事件", body = ApiResponse<PaginatedResponse<AuditEventDto>>),
+        (status = 403, description = "需要管理员权限")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_audit_events_handler(
+    State(state): State<AppState>,
+    _admin: RequireRole<Admin>,
+    Query(query): Query<AuditEventListQuery>,
+) -> Result<Json<ApiResponse<PaginatedResponse<AuditEventDto>>>, AppError> {
+    let (limit, offset) = page_window(&state, query.page, query.page_size)?;
+
+    let criteria = AuditEventCriteria {
+        actor_id: non_empty(query.actor_id.as_deref())
+            .map(|id| parse_id(id, "无效的操作者 ID"))
+            .transpose()?,
+        action: non_empty(query.action.as_deref())
+            .map(str::parse::<AuditAction>)
+            .transpose()?,
+        target_type: non_empty(query.target_type.as_deref())
+            .map(str::parse::<AuditTargetType>)
+            .transpose()?,
+        target_id: non_empty(query.target_id.as_deref()).map(str::to_string),
+        from: query.from,
+        to: query.to,
+    };
+
+    let output = list_audit_events(
+        state.audit_event_repo.as_ref(),
+        ListAuditEventsInput {
+            criteria,
+            limit: Some(limit),
+            offset: Some(offset),
+        },
+    )
+    .await?;
+
+    let dtos: Vec<AuditEventDto> = output.events.iter().map(AuditEventDto::from).collect();
+    let response = PaginatedResponse::new(
+        dtos,
+        output.total_count as i64,
+        query.page,
+        i64::from(limit),
+    );
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|s| !s.is_empty())
+}
+
+/// 将分页参数换算为 limit/offset，页大小受配置上限约束
+fn page_window(state: &AppState, page: i64, page_size: i64) -> Result<(u32, u32), AppError> {
+    if page < 1 || page_size < 1 {
+        return Err(AppError::validation("页码和页大小必须大于0"));
+    }
+
+    let limit = page_size.min(state.config.pagination.max_page_size);
+    let offset = (page - 1)
+        .checked_mul(limit)
+        .and_then(|offset| u32::try_from(offset).ok())
+        .ok_or_else(|| AppError::validation("页码过大"))?;
+
+    Ok((limit as u32, offset))
+}
@@ -0,0 +1,203 @@
+//! 争议仲裁 API 端点
+
+use axum::{
+    extract::{Path, State},
+    routing::{get, post},
+    Json, Router,
+};
+use domain::isu::ISU;
+
+use crate::{
+    dto::{
+        common::ApiResponse,
+        dispute::{
+            AssignDisputeRequest, DisputeDetailsDto, DisputeDto, DisputeEvidenceDto,
+            ResolveDisputeRequest, SubmitEvidenceRequest,
+        },
+    },
+    middleware::{
+        auth::{CurrentUser, Decider, RequireRole},
+        request_context::RequestContext,
+    },
+    v1::parse_id,
+    AppState,
+};
+use app::dispute::{
+    assign_dispute, get_dispute, list_assigned_disputes, resolve_dispute, submit_evidence,
+    AssignDisputeInput, ResolveDisputeInput, SubmitEvidenceInput,
+};
+use shared::AppError;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/assigned", get(list_assigned_disputes_handler))
+        .route("/:id", get(get_dispute_handler))
+        .route("/:id/evidence", post(submit_evidence_handler))
+        .route("/:id/assign", post(assign_dispute_handler))
+        .route("/:id/resolve", post(resolve_dispute_handler))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/disputes/assigned",
+    tag = "disputes",
+    responses(
+        (status = 200, description = "分配给我的处理中争议", body = ApiResponse<Vec<DisputeDto>>),
+        (status = 403, description = "需要决策者或管理员权限")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_assigned_disputes_handler(
+    State(state): State<AppState>,
+    RequireRole(decider_id, ..): RequireRole<Decider>,
+) -> Result<Json<ApiResponse<Vec<DisputeDto>>>, AppError> {
+    let disputes = list_assigned_disputes(state.dispute_repo.as_ref(), decider_id).await?;
+
+    Ok(Json(ApiResponse::success(
+        disputes.iter().map(DisputeDto::from).collect(),
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/disputes/{id}",
+    tag = "disputes",
+    responses(
+        (status = 200, description = "争议详情（含证据）", body = ApiResponse<DisputeDetailsDto>),
+        (status = 403, description = "无权查看该争议"),
+        (status = 404, description = "争议不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_dispute_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(requester_id): CurrentUser,
+) -> Result<Json<ApiResponse<DisputeDetailsDto>>, AppError> {
+    let details = get_dispute(
+        state.dispute_repo.as_ref(),
+        state.transaction_repo.as_ref(),
+        state.member_repo.as_ref(),
+        parse_id(&id, "无效的争议 ID")?,
+        requester_id,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(DisputeDetailsDto::from(&details))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/disputes/{id}/evidence",
+    tag = "disputes",
+    request_body = SubmitEvidenceRequest,
+    responses(
+        (status = 200, description = "证据已提交", body = ApiResponse<DisputeEvidenceDto>),
+        (status = 403, description = "只有交易参与者和分配的决策者可以提交证据")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn submit_evidence_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(author_id): CurrentUser,
+    Json(req): Json<SubmitEvidenceRequest>,
+) -> Result<Json<ApiResponse<DisputeEvidenceDto>>, AppError> {
+    let evidence = submit_evidence(
+        state.dispute_repo.as_ref(),
+        state.transaction_repo.as_ref(),
+        SubmitEvidenceInput {
+            dispute_id: parse_id(&id, "无效的争议 ID")?,
+            author_id,
+            message: req.message,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(DisputeEvidenceDto::from(&evidence))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/disputes/{id}/assign",
+    tag = "disputes",
+    request_body = AssignDisputeRequest,
+    responses(
+        (status = 200, description = "争议已分配", body = ApiResponse<DisputeDto>),
+        (status = 403, description = "只有管理员可以把争议分配给其他决策者")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn assign_dispute_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    RequireRole(requester_id, ..): RequireRole<Decider>,
+    Json(req): Json<AssignDisputeRequest>,
+) -> Result<Json<ApiResponse<DisputeDto>>, AppError> {
+    let decider_id = req
+        .decider_id
+        .as_deref()
+        .map(|id| parse_id(id, "无效的决策者 ID"))
+        .transpose()?;
+
+    let dispute = assign_dispute(
+        state.uow_factory.as_ref(),
+        AssignDisputeInput {
+            dispute_id: parse_id(&id, "无效的争议 ID")?,
+            requester_id,
+            decider_id,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(DisputeDto::from(&dispute))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/disputes/{id}/resolve",
+    tag = "disputes",
+    request_body = ResolveDisputeRequest,
+    responses(
+        (status = 200, description = "争议已裁决，托管款项已结算", body = ApiResponse<DisputeDto>),
+        (status = 403, description = "只有分配的决策者或管理员可以裁决争议")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn resolve_dispute_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    RequireRole(decider_id, ..): RequireRole<Decider>,
+    RequestContext(context): RequestContext,
+    Json(req): Json<ResolveDisputeRequest>,
+) -> Result<Json<ApiResponse<DisputeDto>>, AppError> {
+    let buyer_refund = req.buyer_refund.map(ISU::from_input).transpose()?;
+
+    let dispute = resolve_dispute(
+        state.uow_factory.as_ref(),
+        ResolveDisputeInput {
+            dispute_id: parse_id(&id, "无效的争议 ID")?,
+            decider_id,
+            outcome: req.outcome.parse()?,
+            buyer_refund,
+            note: req.note,
+            context,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        DisputeDto::from(&dispute),
+        "争议已裁决，托管款项已结算".to_string(),
+    )))
+}
@@ -0,0 +1,177 @@
+//! ISU 钱包 API 端点
+
+use axum::{
+    extract::{Query, State},
+    routing::{get, post},
+    Json, Router,
+};
+use domain::isu::ISUTransactionType;
+
+use crate::{
+    dto::{
+        common::ApiResponse,
+        isu::{
+            AdjustBalanceRequest, CirculationDto, ISUAccountDto, ISUTransactionDto, StatementEntryDto,
+            StatementQuery, StatementResponse,
+        },
+    },
+    middleware::{
+        auth::{Admin, CurrentUser, RequireRole},
+        request_context::RequestContext,
+    },
+    AppState,
+};
+use app::isu::{
+    adjust_balance, check_circulation, get_account, get_statement, AdjustBalanceInput,
+    StatementInput,
+};
+use shared::{AppError, Id};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/me", get(get_my_account_handler))
+        .route("/me/statement", get(get_my_statement_handler))
+        .route("/adjustments", post(adjust_balance_handler))
+        .route("/circulation", get(get_circulation_handler))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/isu/me",
+    tag = "isu",
+    responses(
+        (status = 200, description = "当前用户的ISU余额", body = ApiResponse<ISUAccountDto>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_my_account_handler(
+    State(state): State<AppState>,
+    CurrentUser(member_id): CurrentUser,
+) -> Result<Json<ApiResponse<ISUAccountDto>>, AppError> {
+    let account = get_account(state.isu_repo.as_ref(), member_id).await?;
+
+    Ok(Json(ApiResponse::success(ISUAccountDto::from(&account))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/isu/me/statement",
+    tag = "isu",
+    params(StatementQuery),
+    responses(
+        (status = 200, description = "按时间倒序的账户流水", body = ApiResponse<StatementResponse>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_my_statement_handler(
+    State(state): State<AppState>,
+    CurrentUser(member_id): CurrentUser,
+    Query(query): Query<StatementQuery>,
+) -> Result<Json<ApiResponse<StatementResponse>>, AppError> {
+    if query.limit < 1 {
+        return Err(AppError::validation("每页条数必须大于0"));
+    }
+
+    let transaction_types = query
+        .types
+        .as_deref()
+        .unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(str::parse::<ISUTransactionType>)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let output = get_statement(
+        state.isu_repo.as_ref(),
+        StatementInput {
+            member_id,
+            transaction_types,
+            cursor: query.cursor,
+            limit: query.limit.min(state.config.pagination.max_page_size) as u32,
+        },
+    )
+    .await?;
+
+    let entries = output
+        .entries
+        .iter()
+        .map(|entry| StatementEntryDto::new(entry, &output.account.id))
+        .collect();
+
+    Ok(Json(ApiResponse::success(StatementResponse {
+        account: ISUAccountDto::from(&output.account),
+        entries,
+        next_cursor: output.next_cursor,
+    })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/isu/adjustments",
+    tag = "isu",
+    request_body = AdjustBalanceRequest,
+    responses(
+        (status = 200, description = "调整成功，返回对应流水", body = ApiResponse<ISUTransactionDto>),
+        (status = 400, description = "调整金额为0或超过两位小数"),
+        (status = 403, description = "只有管理员可以调整余额")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn adjust_balance_handler(
+    State(state): State<AppState>,
+    RequireRole(admin_id, ..): RequireRole<Admin>,
+    RequestContext(context): RequestContext,
+    Json(req): Json<AdjustBalanceRequest>,
+) -> Result<Json<ApiResponse<ISUTransactionDto>>, AppError> {
+    let member_id = Id::from_string(&req.member_id)
+        .map_err(|_| AppError::validation("无效的会员 ID"))?;
+
+    let isu_transaction = adjust_balance(
+        state.uow_factory.as_ref(),
+        AdjustBalanceInput {
+            admin_id,
+            member_id,
+            amount: req.amount,
+            reason: req.reason,
+            context,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(ISUTransactionDto::from(
+        &isu_transaction,
+    ))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/isu/circulation",
+    tag = "isu",
+    responses(
+        (status = 200, description = "ISU流通量快照及不变式核对结果", body = ApiResponse<CirculationDto>),
+        (status = 403, description = "只有管理员可以查看")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_circulation_handler(
+    State(state): State<AppState>,
+    RequireRole(admin_id, ..): RequireRole<Admin>,
+) -> Result<Json<ApiResponse<CirculationDto>>, AppError> {
+    let circulation = check_circulation(
+        state.member_repo.as_ref(),
+        state.isu_repo.as_ref(),
+        admin_id,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(CirculationDto::from(&circulation))))
+}
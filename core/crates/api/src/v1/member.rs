@@ -1,6 +1,7 @@
 //! 会员 API 端点
 
 use axum::{extract::State, routing::post, Json, Router};
+use axum_extra::extract::cookie::{Cookie, CookieJar, SameSite};
 
 use crate::{
     dto::{
@@ -10,13 +11,26 @@ use crate::{
     middleware::auth::generate_token,
     AppState,
 };
-use app::member::{login_member, register_member, LoginInput, RegisterInput};
+use app::member::{
+    login_member, logout_member, refresh_session, register_member, LoginInput,
+    RefreshSessionInput, RegisterInput,
+};
+use domain::isu::ISU;
+use domain::member::Member;
 use shared::AppError;
 
+/// 刷新令牌 Cookie 名
+const REFRESH_TOKEN_COOKIE: &str = "refresh_token";
+
+/// 刷新令牌 Cookie 的作用路径，只随会员会话接口（续期、登出）发送
+const REFRESH_TOKEN_PATH: &str = "/api/v1/members";
+
 pub fn routes() -> Router<AppState> {
     Router::new()
         .route("/register", post(register))
         .route("/login", post(login))
+        .route("/refresh", post(refresh))
+        .route("/logout", post(logout))
 }
 
 #[utoipa::path(post, path = "/api/v1/members/register", tag = "members")]
@@ -30,9 +44,13 @@ async fn register(
         password: req.password,
     };
 
+    let initial_balance = ISU::new(state.config.isu.initial_balance)
+        .map_err(|e| AppError::internal(format!("初始余额配置无效: {}", e)))?;
+
     let member = register_member(
-        state.member_repo.as_ref(),
+        state.uow_factory.as_ref(),
         state.password_hasher.as_ref(),
+        initial_balance,
         input,
     )
     .await?;
@@ -43,29 +61,109 @@ async fn register(
 #[utoipa::path(post, path = "/api/v1/members/login", tag = "members")]
 async fn login(
     State(state): State<AppState>,
+    jar: CookieJar,
     Json(req): Json<LoginRequest>,
-) -> Result<Json<ApiResponse<LoginResponse>>, AppError> {
+) -> Result<(CookieJar, Json<ApiResponse<LoginResponse>>), AppError> {
     let input = LoginInput {
         email: req.email,
         password: req.password,
+        refresh_token_ttl: chrono::Duration::seconds(state.config.jwt.refresh_expires_in),
     };
 
     let output = login_member(
         state.member_repo.as_ref(),
+        state.refresh_token_repo.as_ref(),
         state.password_hasher.as_ref(),
         input,
     )
     .await?;
 
-    // 生成 JWT token
-    let token = generate_token(
-        &output.member.id.to_string(),
-        &state.config.jwt.secret,
-        state.config.jwt.expires_in,
-    )?;
+    let response = session_response(&state, &output.member)?;
+    let jar = jar.add(refresh_token_cookie(&state, output.refresh_token));
+    Ok((jar, Json(ApiResponse::success(response))))
+}
 
-    let dto = MemberDto::from(&output.member);
-    let response = LoginResponse { token, member: dto };
+#[utoipa::path(
+    post,
+    path = "/api/v1/members/refresh",
+    tag = "members",
+    params(
+        ("refresh_token" = String, Cookie, description = "登录或上次续期时下发的刷新令牌（HttpOnly Cookie）")
+    ),
+    responses(
+        (status = 200, description = "续期成功，返回新的访问令牌，轮换后的刷新令牌通过 Set-Cookie 下发", body = ApiResponse<LoginResponse>),
+        (status = 401, description = "刷新令牌缺失、无效、过期或已被使用（重用会吊销整个令牌家族）"),
+        (status = 403, description = "账号未激活或已被封禁")
+    )
+)]
+async fn refresh(
+    State(state): State<AppState>,
+    jar: CookieJar,
+) -> Result<(CookieJar, Json<ApiResponse<LoginResponse>>), AppError> {
+    let refresh_token = jar
+        .get(REFRESH_TOKEN_COOKIE)
+        .map(|cookie| cookie.value().to_string())
+        .ok_or(AppError::Unauthorized)?;
+
+    let output = refresh_session(
+        state.uow_factory.as_ref(),
+        RefreshSessionInput {
+            refresh_token,
+            refresh_token_ttl: chrono::Duration::seconds(state.config.jwt.refresh_expires_in),
+        },
+    )
+    .await?;
+
+    let response = session_response(&state, &output.member)?;
+    let jar = jar.add(refresh_token_cookie(&state, output.refresh_token));
+    Ok((jar, Json(ApiResponse::success(response))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/members/logout",
+    tag = "members",
+    params(
+        ("refresh_token" = String, Cookie, description = "当前会话的刷新令牌（HttpOnly Cookie）")
+    ),
+    responses(
+        (status = 200, description = "已登出，刷新令牌所在的令牌家族全部吊销，Cookie 被清除")
+    )
+)]
+async fn logout(
+    State(state): State<AppState>,
+    jar: CookieJar,
+) -> Result<(CookieJar, Json<ApiResponse<()>>), AppError> {
+    if let Some(cookie) = jar.get(REFRESH_TOKEN_COOKIE) {
+        logout_member(state.refresh_token_repo.as_ref(), cookie.value()).await?;
+    }
+
+    let jar = jar.remove(
+        Cookie::build(REFRESH_TOKEN_COOKIE)
+            .path(REFRESH_TOKEN_PATH)
+            .secure(state.config.server.secure_cookies),
+    );
+    Ok((jar, Json(ApiResponse::success(()))))
+}
+
+/// 签发访问令牌并组装会话响应
+fn session_response(state: &AppState, member: &Member) -> Result<LoginResponse, AppError> {
+    let token = generate_token(member, &state.jwt_keys, state.config.jwt.expires_in)?;
+
+    Ok(LoginResponse {
+        token,
+        expires_in: state.config.jwt.expires_in,
+        member: MemberDto::from(member),
+    })
+}
 
-    Ok(Json(ApiResponse::success(response)))
+/// 刷新令牌 Cookie：脚本不可读，只随续期和登出请求携带；是否限定 HTTPS 由 `server.secure_cookies` 决定
+fn refresh_token_cookie(state: &AppState, refresh_token: String) -> Cookie<'static> {
+    Cookie::build((REFRESH_TOKEN_COOKIE, refresh_token))
+        .path(REFRESH_TOKEN_PATH)
+        .http_only(true)
+        .secure(state.config.server.secure_cookies)
+        .same_site(SameSite::Strict)
+        .max_age(time::Duration::seconds(state.config.jwt.refresh_expires_in))
+        .build()
 }
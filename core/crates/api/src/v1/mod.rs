@@ -1,6 +1,18 @@
 //! v1 API 模块
 
+pub mod admin;
+pub mod dispute;
+pub mod isu;
 pub mod member;
+pub mod profession;
+pub mod review;
+pub mod service;
 pub mod tool;
+pub mod transaction;
 
-// pub mod transaction;
+use shared::{AppError, Id};
+
+/// 解析路径中的 ID，格式无效时返回校验错误
+pub(crate) fn parse_id<T>(id_str: &str, error_msg: &str) -> Result<Id<T>, AppError> {
+    Id::from_string(id_str).map_err(|_| AppError::validation(error_msg))
+}
@@ -0,0 +1,273 @@
+//! 职业目录 API 端点
+
+use axum::{
+    extract::{Path, Query, State},
+    routing::{get, put},
+    Json, Router,
+};
+
+use crate::{
+    dto::{
+        common::ApiResponse,
+        profession::{
+            CategoryDto, CategoryListQuery, CreateCategoryRequest, CreateProfessionRequest,
+            ProfessionDto, ProfessionListQuery, ProfessionRateDto, UpdateCategoryRequest,
+            UpdateProfessionRateRequest, UpdateProfessionRequest,
+        },
+    },
+    middleware::auth::{Admin, RequireProfessionManager, RequireRole},
+    AppState,
+};
+use app::profession::{
+    create_category, create_profession, list_categories, list_professions, update_category,
+    update_profession, update_profession_rate, CreateCategoryInput, CreateProfessionInput,
+    UpdateCategoryInput, UpdateProfessionInput, UpdateProfessionRateInput,
+};
+use shared::AppError;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_professions_handler).post(create_profession_handler))
+        .route("/:code", put(update_profession_handler))
+        .route("/:code/rate", put(update_profession_rate_handler))
+        .route("/categories", get(list_categories_handler).post(create_category_handler))
+        .route("/categories/:code", put(update_category_handler))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/professions",
+    tag = "professions",
+    params(ProfessionListQuery),
+    responses(
+        (status = 200, description = "职业目录", body = ApiResponse<Vec<ProfessionDto>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_professions_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ProfessionListQuery>,
+) -> Result<Json<ApiResponse<Vec<ProfessionDto>>>, AppError> {
+    let professions =
+        list_professions(state.profession_catalog_repo.as_ref(), query.include_inactive).await?;
+
+    let locale = query.locale.as_deref();
+    Ok(Json(ApiResponse::success(
+        professions
+            .iter()
+            .map(|profession| ProfessionDto::new(profession, locale))
+            .collect(),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/professions",
+    tag = "professions",
+    request_body = CreateProfessionRequest,
+    responses(
+        (status = 200, description = "职业新增成功", body = ApiResponse<ProfessionDto>),
+        (status = 403, description = "只有管理员可以维护职业目录")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_profession_handler(
+    State(state): State<AppState>,
+    RequireRole(admin_id, ..): RequireRole<Admin>,
+    Json(req): Json<CreateProfessionRequest>,
+) -> Result<Json<ApiResponse<ProfessionDto>>, AppError> {
+    let profession = create_profession(
+        state.member_repo.as_ref(),
+        state.profession_catalog_repo.as_ref(),
+        CreateProfessionInput {
+            admin_id,
+            code: req.code.parse()?,
+            display_name: req.display_name,
+            localized_names: req.localized_names,
+            category_code: req.category_code,
+            default_rate: req.default_rate,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(ProfessionDto::new(&profession, None))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/professions/{code}",
+    tag = "professions",
+    params(
+        ("code" = String, Path, description = "职业编码")
+    ),
+    request_body = UpdateProfessionRequest,
+    responses(
+        (status = 200, description = "职业更新成功", body = ApiResponse<ProfessionDto>),
+        (status = 403, description = "只有管理员可以维护职业目录"),
+        (status = 404, description = "职业不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_profession_handler(
+    State(state): State<AppState>,
+    RequireRole(admin_id, ..): RequireRole<Admin>,
+    Path(code): Path<String>,
+    Json(req): Json<UpdateProfessionRequest>,
+) -> Result<Json<ApiResponse<ProfessionDto>>, AppError> {
+    let profession = update_profession(
+        state.member_repo.as_ref(),
+        state.profession_catalog_repo.as_ref(),
+        UpdateProfessionInput {
+            admin_id,
+            code: code.parse()?,
+            display_name: req.display_name,
+            localized_names: req.localized_names,
+            category_code: req.category_code,
+            default_rate: req.default_rate,
+            is_active: req.is_active,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(ProfessionDto::new(&profession, None))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/professions/{code}/rate",
+    tag = "professions",
+    params(
+        ("code" = String, Path, description = "职业编码")
+    ),
+    request_body = UpdateProfessionRateRequest,
+    responses(
+        (status = 200, description = "标准费率更新成功", body = ApiResponse<ProfessionRateDto>),
+        (status = 403, description = "只有管理员或该职业的决策者可以调整费率"),
+        (status = 404, description = "职业不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_profession_rate_handler(
+    State(state): State<AppState>,
+    manager: RequireProfessionManager,
+    Json(req): Json<UpdateProfessionRateRequest>,
+) -> Result<Json<ApiResponse<ProfessionRateDto>>, AppError> {
+    let output = update_profession_rate(
+        state.uow_factory.as_ref(),
+        UpdateProfessionRateInput {
+            requester_id: manager.member_id,
+            profession_type: manager.profession,
+            new_rate: req.new_rate,
+            reason: req.reason,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(ProfessionRateDto::from(&output))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/professions/categories",
+    tag = "professions",
+    params(CategoryListQuery),
+    responses(
+        (status = 200, description = "职业分类", body = ApiResponse<Vec<CategoryDto>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_categories_handler(
+    State(state): State<AppState>,
+    Query(query): Query<CategoryListQuery>,
+) -> Result<Json<ApiResponse<Vec<CategoryDto>>>, AppError> {
+    let categories = list_categories(state.profession_catalog_repo.as_ref()).await?;
+
+    let locale = query.locale.as_deref();
+    Ok(Json(ApiResponse::success(
+        categories
+            .iter()
+            .map(|category| CategoryDto::new(category, locale))
+            .collect(),
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/professions/categories",
+    tag = "professions",
+    request_body = CreateCategoryRequest,
+    responses(
+        (status = 200, description = "职业分类新增成功", body = ApiResponse<CategoryDto>),
+        (status = 403, description = "只有管理员可以维护职业目录")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_category_handler(
+    State(state): State<AppState>,
+    RequireRole(admin_id, ..): RequireRole<Admin>,
+    Json(req): Json<CreateCategoryRequest>,
+) -> Result<Json<ApiResponse<CategoryDto>>, AppError> {
+    let category = create_category(
+        state.member_repo.as_ref(),
+        state.profession_catalog_repo.as_ref(),
+        CreateCategoryInput {
+            admin_id,
+            code: req.code,
+            display_name: req.display_name,
+            localized_names: req.localized_names,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(CategoryDto::new(&category, None))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/professions/categories/{code}",
+    tag = "professions",
+    params(
+        ("code" = String, Path, description = "职业分类编码")
+    ),
+    request_body = UpdateCategoryRequest,
+    responses(
+        (status = 200, description = "职业分类更新成功", body = ApiResponse<CategoryDto>),
+        (status = 403, description = "只有管理员可以维护职业目录"),
+        (status = 404, description = "职业分类不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_category_handler(
+    State(state): State<AppState>,
+    RequireRole(admin_id, ..): RequireRole<Admin>,
+    Path(code): Path<String>,
+    Json(req): Json<UpdateCategoryRequest>,
+) -> Result<Json<ApiResponse<CategoryDto>>, AppError> {
+    let category = update_category(
+        state.member_repo.as_ref(),
+        state.profession_catalog_repo.as_ref(),
+        UpdateCategoryInput {
+            admin_id,
+            code,
+            display_name: req.display_name,
+            localized_names: req.localized_names,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(CategoryDto::new(&category, None))))
+}
@@ -0,0 +1,126 @@
+//! 交易评价 API 端点
+
+use axum::{
+    extract::{Path, Query, State},
+    routing::{get, post},
+    Json, Router,
+};
+
+use crate::{
+    dto::{
+        common::{ApiResponse, PaginationQuery},
+        review::{MemberReviewsDto, ReviewDto, SubmitReviewRequest},
+    },
+    middleware::auth::CurrentUser,
+    v1::parse_id,
+    AppState,
+};
+use app::review::{list_member_reviews, list_transaction_reviews, submit_review, SubmitReviewInput};
+use shared::AppError;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", post(submit_review_handler))
+        .route("/members/:member_id", get(list_member_reviews_handler))
+        .route("/transactions/:transaction_id", get(list_transaction_reviews_handler))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/reviews",
+    tag = "reviews",
+    request_body = SubmitReviewRequest,
+    responses(
+        (status = 200, description = "评价已提交", body = ApiResponse<ReviewDto>),
+        (status = 400, description = "交易未完成或评分无效"),
+        (status = 403, description = "只有交易参与者可以评价"),
+        (status = 409, description = "已评价过该交易")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn submit_review_handler(
+    State(state): State<AppState>,
+    CurrentUser(reviewer_id): CurrentUser,
+    Json(req): Json<SubmitReviewRequest>,
+) -> Result<Json<ApiResponse<ReviewDto>>, AppError> {
+    let review = submit_review(
+        state.uow_factory.as_ref(),
+        SubmitReviewInput {
+            transaction_id: parse_id(&req.transaction_id, "无效的交易 ID")?,
+            reviewer_id,
+            rating: req.rating,
+            comment: req.comment,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(ReviewDto::from(&review))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/reviews/members/{member_id}",
+    tag = "reviews",
+    responses(
+        (status = 200, description = "会员收到的评价及评分汇总", body = ApiResponse<MemberReviewsDto>),
+        (status = 404, description = "会员不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_member_reviews_handler(
+    State(state): State<AppState>,
+    Path(member_id): Path<String>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<ApiResponse<MemberReviewsDto>>, AppError> {
+    let page_size = pagination.page_size.min(state.config.pagination.max_page_size);
+
+    let member_reviews = list_member_reviews(
+        state.member_repo.as_ref(),
+        state.review_repo.as_ref(),
+        parse_id(&member_id, "无效的会员 ID")?,
+        pagination.page,
+        page_size,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(MemberReviewsDto::new(
+        &member_reviews,
+        pagination.page,
+        page_size,
+    ))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/reviews/transactions/{transaction_id}",
+    tag = "reviews",
+    responses(
+        (status = 200, description = "交易的评价", body = ApiResponse<Vec<ReviewDto>>),
+        (status = 403, description = "无权查看此交易的评价"),
+        (status = 404, description = "交易不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_transaction_reviews_handler(
+    State(state): State<AppState>,
+    Path(transaction_id): Path<String>,
+    CurrentUser(requester_id): CurrentUser,
+) -> Result<Json<ApiResponse<Vec<ReviewDto>>>, AppError> {
+    let reviews = list_transaction_reviews(
+        state.transaction_repo.as_ref(),
+        state.review_repo.as_ref(),
+        parse_id(&transaction_id, "无效的交易 ID")?,
+        requester_id,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(
+        reviews.iter().map(ReviewDto::from).collect(),
+    )))
+}
@@ -0,0 +1,284 @@
+//! 服务 API 端点
+
+use axum::{
+    extract::{Path, Query, State},
+    routing::{get, post, put},
+    Json, Router,
+};
+use domain::{profession::ProfessionType, service::ServiceSearchCriteria};
+
+use crate::{
+    dto::{
+        common::{ApiResponse, PaginatedResponse},
+        service::{
+            ListServicesQuery, PublishServiceRequest, SearchServicesQuery, ServiceDto,
+            UpdateServiceRequest,
+        },
+    },
+    middleware::auth::CurrentUser,
+    v1::parse_id,
+    AppState,
+};
+use app::service::{
+    cancel_service, get_service, list_services, list_services_by_provider, publish_service,
+    search_services, update_service, ListServicesInput, PublishServiceInput, UpdateServiceInput,
+};
+use shared::AppError;
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", post(publish_service_handler))
+        .route("/", get(list_services_handler))
+        .route("/search", get(search_services_handler))
+        .route("/:id", get(get_service_handler))
+        .route("/:id", put(update_service_handler))
+        .route("/:id/cancel", post(cancel_service_handler))
+        .route("/provider/:provider_id", get(list_services_by_provider_handler))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/services",
+    tag = "services",
+    request_body = PublishServiceRequest,
+    responses(
+        (status = 200, description = "服务发布成功", body = ApiResponse<ServiceDto>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn publish_service_handler(
+    State(state): State<AppState>,
+    CurrentUser(provider_id): CurrentUser,
+    Json(req): Json<PublishServiceRequest>,
+) -> Result<Json<ApiResponse<ServiceDto>>, AppError> {
+    let input = PublishServiceInput {
+        provider_id,
+        profession_type: req.profession_type.parse()?,
+        title: req.title,
+        description: req.description,
+        estimated_hours: req.estimated_hours,
+    };
+
+    let output = publish_service(
+        state.member_repo.as_ref(),
+        state.service_repo.as_ref(),
+        state.profession_catalog_repo.as_ref(),
+        state.profession_repo.as_ref(),
+        input,
+    )
+    .await?;
+
+    let service = get_service(state.service_repo.as_ref(), output.service_id).await?;
+    Ok(Json(ApiResponse::success(ServiceDto::from(&service))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/services",
+    tag = "services",
+    params(ListServicesQuery),
+    responses(
+        (status = 200, description = "可用服务列表", body = ApiResponse<PaginatedResponse<ServiceDto>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_services_handler(
+    State(state): State<AppState>,
+    Query(query): Query<ListServicesQuery>,
+) -> Result<Json<ApiResponse<PaginatedResponse<ServiceDto>>>, AppError> {
+    let (limit, offset) = page_window(&state, query.page, query.page_size)?;
+
+    let output = list_services(
+        state.service_repo.as_ref(),
+        ListServicesInput {
+            profession_type: parse_profession(query.profession_type.as_deref())?,
+            limit: Some(limit),
+            offset: Some(offset),
+        },
+    )
+    .await?;
+
+    let dtos: Vec<ServiceDto> = output.services.iter().map(ServiceDto::from).collect();
+    let response = PaginatedResponse::new(
+        dtos,
+        output.total_count as i64,
+        query.page,
+        i64::from(limit),
+    );
+
+    Ok(Json(ApiResponse::success(response)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/services/search",
+    tag = "services",
+    params(SearchServicesQuery),
+    responses(
+        (status = 200, description = "按相关度或提供者信誉排序的搜索结果", body = ApiResponse<Vec<ServiceDto>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn search_services_handler(
+    State(state): State<AppState>,
+    Query(query): Query<SearchServicesQuery>,
+) -> Result<Json<ApiResponse<Vec<ServiceDto>>>, AppError> {
+    let (limit, offset) = page_window(&state, query.page, query.page_size)?;
+
+    let criteria = ServiceSearchCriteria {
+        keyword: query.keyword,
+        profession_type: parse_profession(query.profession_type.as_deref())?,
+        min_provider_reputation: query.min_reputation,
+        sort: query
+            .sort
+            .as_deref()
+            .filter(|s| !s.is_empty())
+            .map(str::parse)
+            .transpose()?
+            .unwrap_or_default(),
+    };
+
+    let services = search_services(
+        state.service_repo.as_ref(),
+        criteria,
+        Some(limit),
+        Some(offset),
+    )
+    .await?;
+
+    let dtos: Vec<ServiceDto> = services.iter().map(ServiceDto::from).collect();
+    Ok(Json(ApiResponse::success(dtos)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/services/{id}",
+    tag = "services",
+    responses(
+        (status = 200, description = "服务详情", body = ApiResponse<ServiceDto>),
+        (status = 404, description = "服务不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_service_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+) -> Result<Json<ApiResponse<ServiceDto>>, AppError> {
+    let service_id = parse_id(&id, "无效的服务 ID")?;
+
+    let service = get_service(state.service_repo.as_ref(), service_id).await?;
+
+    Ok(Json(ApiResponse::success(ServiceDto::from(&service))))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/services/{id}",
+    tag = "services",
+    request_body = UpdateServiceRequest,
+    responses(
+        (status = 200, description = "服务更新成功", body = ApiResponse<ServiceDto>),
+        (status = 403, description = "只有服务提供者可以修改")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn update_service_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(requester_id): CurrentUser,
+    Json(req): Json<UpdateServiceRequest>,
+) -> Result<Json<ApiResponse<ServiceDto>>, AppError> {
+    let service_id = parse_id(&id, "无效的服务 ID")?;
+
+    let input = UpdateServiceInput {
+        service_id,
+        requester_id,
+        title: req.title,
+        description: req.description,
+        estimated_hours: req.estimated_hours,
+    };
+
+    let service = update_service(state.service_repo.as_ref(), input).await?;
+
+    Ok(Json(ApiResponse::success(ServiceDto::from(&service))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/services/{id}/cancel",
+    tag = "services",
+    responses(
+        (status = 200, description = "服务已取消", body = ApiResponse<ServiceDto>),
+        (status = 400, description = "服务不是可用状态或还有未结束的交易"),
+        (status = 403, description = "只有服务提供者可以取消")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn cancel_service_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(requester_id): CurrentUser,
+) -> Result<Json<ApiResponse<ServiceDto>>, AppError> {
+    let service_id = parse_id(&id, "无效的服务 ID")?;
+
+    let service = cancel_service(state.uow_factory.as_ref(), service_id, requester_id).await?;
+
+    Ok(Json(ApiResponse::success(ServiceDto::from(&service))))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/services/provider/{provider_id}",
+    tag = "services",
+    responses(
+        (status = 200, description = "提供者发布的全部服务", body = ApiResponse<Vec<ServiceDto>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_services_by_provider_handler(
+    State(state): State<AppState>,
+    Path(provider_id): Path<String>,
+) -> Result<Json<ApiResponse<Vec<ServiceDto>>>, AppError> {
+    let provider_id = parse_id(&provider_id, "无效的提供者 ID")?;
+
+    let services = list_services_by_provider(state.service_repo.as_ref(), provider_id).await?;
+
+    let dtos: Vec<ServiceDto> = services.iter().map(ServiceDto::from).collect();
+    Ok(Json(ApiResponse::success(dtos)))
+}
+
+/// 将分页参数换算为 limit/offset，页大小受配置上限约束
+fn page_window(state: &AppState, page: i64, page_size: i64) -> Result<(u32, u32), AppError> {
+    if page < 1 || page_size < 1 {
+        return Err(AppError::validation("页码和页大小必须大于0"));
+    }
+
+    let limit = page_size.min(state.config.pagination.max_page_size);
+    let offset = (page - 1)
+        .checked_mul(limit)
+        .and_then(|offset| u32::try_from(offset).ok())
+        .ok_or_else(|| AppError::validation("页码过大"))?;
+
+    Ok((limit as u32, offset))
+}
+
+fn parse_profession(profession_type: Option<&str>) -> Result<Option<ProfessionType>, AppError> {
+    profession_type
+        .filter(|s| !s.is_empty())
+        .map(str::parse)
+        .transpose()
+}
@@ -9,16 +9,21 @@ use axum::{
 use crate::{
     dto::{
         common::{ApiResponse, PaginatedResponse, PaginationQuery},
-        tool::{CreateToolRequest, ToolDto, UpdateToolRequest},
+        tool::{
+            AvailabilityQuery, BlockToolDatesRequest, CreateToolRequest, ToolAvailabilityDto,
+            ToolDto, ToolReservationDto, UpdateToolRequest,
+        },
     },
-    middleware::auth::CurrentUser,
+    middleware::{auth::CurrentUser, request_context::RequestContext},
+    v1::parse_id,
     AppState,
 };
 use app::tool::{
-    count_tools, create_tool, delete_tool, get_tool, list_available_tools,
-    list_tools_by_owner, update_tool, CreateToolInput, UpdateToolInput,
+    block_tool_dates, count_tools, create_tool, delete_tool, get_tool, get_tool_availability,
+    list_available_tools, list_tools_by_owner, unblock_tool_dates, update_tool,
+    BlockToolDatesInput, CreateToolInput, UpdateToolInput,
 };
-use shared::{AppError, Id};
+use shared::AppError;
 
 pub fn routes() -> Router<AppState> {
     Router::new()
@@ -28,6 +33,9 @@ pub fn routes() -> Router<AppState> {
         .route("/:id", put(update_tool_handler))
         .route("/:id", delete(delete_tool_handler))
         .route("/owner/:owner_id", get(list_tools_by_owner_handler))
+        .route("/:id/availability", get(get_tool_availability_handler))
+        .route("/:id/blocks", post(block_tool_dates_handler))
+        .route("/:id/blocks/:reservation_id", delete(unblock_tool_dates_handler))
 }
 
 #[utoipa::path(
@@ -51,6 +59,8 @@ pub async fn create_tool_handler(
         category: req.category,
         price_amount: req.price_amount,
         price_currency: req.price_currency,
+        daily_rate: req.daily_rate,
+        deposit: req.deposit,
     };
 
     let tool = create_tool(state.tool_repo.as_ref(), input).await?;
@@ -130,6 +140,8 @@ pub async fn update_tool_handler(
         category: req.category,
         price_amount: req.price_amount,
         price_currency: req.price_currency,
+        daily_rate: req.daily_rate,
+        deposit: req.deposit,
     };
 
     let tool = update_tool(state.tool_repo.as_ref(), input).await?;
@@ -150,10 +162,11 @@ pub async fn delete_tool_handler(
     State(state): State<AppState>,
     Path(id): Path<String>,
     CurrentUser(requester_id): CurrentUser,
+    RequestContext(context): RequestContext,
 ) -> Result<Json<ApiResponse<()>>, AppError> {
     let tool_id = parse_id(&id, "无效的工具 ID")?;
 
-    delete_tool(state.tool_repo.as_ref(), tool_id, requester_id).await?;
+    delete_tool(state.uow_factory.as_ref(), tool_id, requester_id, &context).await?;
 
     Ok(Json(ApiResponse::success(())))
 }
@@ -189,6 +202,114 @@ pub async fn list_tools_by_owner_handler(
     Ok(Json(ApiResponse::success(response)))
 }
 
-fn parse_id<T>(id_str: &str, error_msg: &str) -> Result<shared::Id<T>, AppError> {
-    Id::from_string(id_str).map_err(|_| AppError::validation(error_msg))
+#[utoipa::path(
+    get,
+    path = "/api/v1/tools/{id}/availability",
+    tag = "tools",
+    params(
+        ("id" = String, Path, description = "工具 ID"),
+        AvailabilityQuery
+    ),
+    responses(
+        (status = 200, description = "时间窗内的预订和空闲时段", body = ApiResponse<ToolAvailabilityDto>),
+        (status = 404, description = "工具不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_tool_availability_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(query): Query<AvailabilityQuery>,
+) -> Result<Json<ApiResponse<ToolAvailabilityDto>>, AppError> {
+    let tool_id = parse_id(&id, "无效的工具 ID")?;
+
+    let availability = get_tool_availability(
+        state.tool_repo.as_ref(),
+        state.tool_reservation_repo.as_ref(),
+        tool_id,
+        query.from,
+        query.to,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(ToolAvailabilityDto::from(&availability))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/tools/{id}/blocks",
+    tag = "tools",
+    params(
+        ("id" = String, Path, description = "工具 ID")
+    ),
+    request_body = BlockToolDatesRequest,
+    responses(
+        (status = 200, description = "日期已封锁", body = ApiResponse<ToolReservationDto>),
+        (status = 400, description = "时间段无效或与已有预订重叠"),
+        (status = 403, description = "只有工具所有者可以封锁日期")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn block_tool_dates_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(owner_id): CurrentUser,
+    Json(req): Json<BlockToolDatesRequest>,
+) -> Result<Json<ApiResponse<ToolReservationDto>>, AppError> {
+    let tool_id = parse_id(&id, "无效的工具 ID")?;
+
+    let reservation = block_tool_dates(
+        state.tool_repo.as_ref(),
+        state.tool_reservation_repo.as_ref(),
+        BlockToolDatesInput {
+            tool_id,
+            owner_id,
+            starts_at: req.starts_at,
+            ends_at: req.ends_at,
+            note: req.note,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(ToolReservationDto::from(&reservation))))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/tools/{id}/blocks/{reservation_id}",
+    tag = "tools",
+    params(
+        ("id" = String, Path, description = "工具 ID"),
+        ("reservation_id" = String, Path, description = "封锁记录 ID")
+    ),
+    responses(
+        (status = 200, description = "封锁已取消"),
+        (status = 403, description = "只有工具所有者可以取消封锁"),
+        (status = 404, description = "封锁记录不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn unblock_tool_dates_handler(
+    State(state): State<AppState>,
+    Path((id, reservation_id)): Path<(String, String)>,
+    CurrentUser(owner_id): CurrentUser,
+) -> Result<Json<ApiResponse<()>>, AppError> {
+    let tool_id = parse_id(&id, "无效的工具 ID")?;
+    let reservation_id = parse_id(&reservation_id, "无效的封锁记录 ID")?;
+
+    unblock_tool_dates(
+        state.tool_reservation_repo.as_ref(),
+        tool_id,
+        reservation_id,
+        owner_id,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(())))
 }
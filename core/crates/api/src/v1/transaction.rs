@@ -0,0 +1,467 @@
+//! 交易 API 端点
+
+use axum::{
+    extract::{Path, Query, State},
+    routing::{get, post},
+    Json, Router,
+};
+use chrono::Duration;
+use domain::transaction::{CancellationPolicy, Transaction, TransactionStatus};
+
+use crate::{
+    dto::{
+        common::ApiResponse,
+        dispute::DisputeDto,
+        transaction::{
+            CreateTransactionRequest, DisputeTransactionRequest, DisputeTransactionResponse,
+            RentToolRequest, RentToolResponse, ToolRentalDto, TransactionDto,
+            TransactionListQuery,
+        },
+    },
+    middleware::auth::CurrentUser,
+    v1::parse_id,
+    AppState,
+};
+use app::transaction::{
+    cancel_transaction, complete_transaction, confirm_transaction, create_transaction,
+    dispute_transaction, get_tool_rental, get_transaction, list_purchases, list_sales, rent_tool,
+    return_tool, start_transaction, CancelTransactionInput, CompleteTransactionInput,
+    ConfirmTransactionInput, CreateTransactionInput, DisputeTransactionInput, RentToolInput,
+    ReturnToolInput, StartTransactionInput,
+};
+use shared::{config::CancellationConfig, AppError};
+
+pub fn routes() -> Router<AppState> {
+    Router::new()
+        .route("/", post(create_transaction_handler))
+        .route("/rentals", post(rent_tool_handler))
+        .route("/purchases", get(list_purchases_handler))
+        .route("/sales", get(list_sales_handler))
+        .route("/:id", get(get_transaction_handler))
+        .route("/:id/confirm", post(confirm_transaction_handler))
+        .route("/:id/start", post(start_transaction_handler))
+        .route("/:id/complete", post(complete_transaction_handler))
+        .route("/:id/cancel", post(cancel_transaction_handler))
+        .route("/:id/dispute", post(dispute_transaction_handler))
+        .route("/:id/return", post(return_tool_handler))
+        .route("/:id/rental", get(get_tool_rental_handler))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/transactions",
+    tag = "transactions",
+    request_body = CreateTransactionRequest,
+    responses(
+        (status = 200, description = "交易已创建，等待卖家确认", body = ApiResponse<TransactionDto>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn create_transaction_handler(
+    State(state): State<AppState>,
+    CurrentUser(buyer_id): CurrentUser,
+    Json(req): Json<CreateTransactionRequest>,
+) -> Result<Json<ApiResponse<TransactionDto>>, AppError> {
+    let input = CreateTransactionInput {
+        buyer_id,
+        service_id: parse_id(&req.service_id, "无效的服务 ID")?,
+        description: req.description,
+    };
+
+    let output = create_transaction(state.uow_factory.as_ref(), input).await?;
+    let transaction =
+        get_transaction(state.transaction_repo.as_ref(), output.transaction_id, buyer_id).await?;
+
+    Ok(Json(ApiResponse::success(TransactionDto::from(&transaction))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/transactions/rentals",
+    tag = "transactions",
+    request_body = RentToolRequest,
+    responses(
+        (status = 200, description = "租用已创建，等待工具所有者确认", body = ApiResponse<RentToolResponse>),
+        (status = 400, description = "租用期无效、与已有预订重叠或余额不足")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn rent_tool_handler(
+    State(state): State<AppState>,
+    CurrentUser(renter_id): CurrentUser,
+    Json(req): Json<RentToolRequest>,
+) -> Result<Json<ApiResponse<RentToolResponse>>, AppError> {
+    let input = RentToolInput {
+        renter_id,
+        tool_id: parse_id(&req.tool_id, "无效的工具 ID")?,
+        start_date: req.start_date,
+        end_date: req.end_date,
+        description: req.description,
+    };
+
+    let output = rent_tool(state.uow_factory.as_ref(), input).await?;
+
+    Ok(Json(ApiResponse::success(RentToolResponse {
+        transaction: TransactionDto::from(&output.transaction),
+        rental: ToolRentalDto::from(&output.rental),
+    })))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/transactions/purchases",
+    tag = "transactions",
+    params(TransactionListQuery),
+    responses(
+        (status = 200, description = "当前用户作为买家的交易", body = ApiResponse<Vec<TransactionDto>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_purchases_handler(
+    State(state): State<AppState>,
+    CurrentUser(buyer_id): CurrentUser,
+    Query(query): Query<TransactionListQuery>,
+) -> Result<Json<ApiResponse<Vec<TransactionDto>>>, AppError> {
+    let transactions = list_purchases(state.transaction_repo.as_ref(), buyer_id).await?;
+
+    Ok(Json(ApiResponse::success(filter_by_status(
+        transactions,
+        query.status.as_deref(),
+    )?)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/transactions/sales",
+    tag = "transactions",
+    params(TransactionListQuery),
+    responses(
+        (status = 200, description = "当前用户作为卖家的交易", body = ApiResponse<Vec<TransactionDto>>)
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn list_sales_handler(
+    State(state): State<AppState>,
+    CurrentUser(seller_id): CurrentUser,
+    Query(query): Query<TransactionListQuery>,
+) -> Result<Json<ApiResponse<Vec<TransactionDto>>>, AppError> {
+    let transactions = list_sales(state.transaction_repo.as_ref(), seller_id).await?;
+
+    Ok(Json(ApiResponse::success(filter_by_status(
+        transactions,
+        query.status.as_deref(),
+    )?)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/transactions/{id}",
+    tag = "transactions",
+    responses(
+        (status = 200, description = "交易详情", body = ApiResponse<TransactionDto>),
+        (status = 403, description = "非交易参与者"),
+        (status = 404, description = "交易不存在")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_transaction_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(requester_id): CurrentUser,
+) -> Result<Json<ApiResponse<TransactionDto>>, AppError> {
+    let transaction_id = parse_id(&id, "无效的交易 ID")?;
+
+    let transaction =
+        get_transaction(state.transaction_repo.as_ref(), transaction_id, requester_id).await?;
+
+    Ok(Json(ApiResponse::success(TransactionDto::from(&transaction))))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/transactions/{id}/confirm",
+    tag = "transactions",
+    responses(
+        (status = 200, description = "卖家已接单，买家款项已冻结", body = ApiResponse<TransactionDto>),
+        (status = 403, description = "只有卖家可以确认")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn confirm_transaction_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(seller_id): CurrentUser,
+) -> Result<Json<ApiResponse<TransactionDto>>, AppError> {
+    let transaction_id = parse_id(&id, "无效的交易 ID")?;
+
+    let output = confirm_transaction(
+        state.uow_factory.as_ref(),
+        ConfirmTransactionInput {
+            transaction_id,
+            seller_id,
+        },
+    )
+    .await?;
+
+    let transaction =
+        get_transaction(state.transaction_repo.as_ref(), transaction_id, seller_id).await?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        TransactionDto::from(&transaction),
+        output.message,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/transactions/{id}/start",
+    tag = "transactions",
+    responses(
+        (status = 200, description = "交易开始", body = ApiResponse<TransactionDto>),
+        (status = 403, description = "只有卖家可以开始")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn start_transaction_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(seller_id): CurrentUser,
+) -> Result<Json<ApiResponse<TransactionDto>>, AppError> {
+    let transaction_id = parse_id(&id, "无效的交易 ID")?;
+
+    let output = start_transaction(
+        state.uow_factory.as_ref(),
+        StartTransactionInput {
+            transaction_id,
+            seller_id,
+        },
+    )
+    .await?;
+
+    let transaction =
+        get_transaction(state.transaction_repo.as_ref(), transaction_id, seller_id).await?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        TransactionDto::from(&transaction),
+        output.message,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/transactions/{id}/complete",
+    tag = "transactions",
+    responses(
+        (status = 200, description = "交易已完成，托管款项已放款给卖家", body = ApiResponse<TransactionDto>),
+        (status = 403, description = "非交易参与者")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn complete_transaction_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(requester_id): CurrentUser,
+) -> Result<Json<ApiResponse<TransactionDto>>, AppError> {
+    let transaction_id = parse_id(&id, "无效的交易 ID")?;
+
+    let output = complete_transaction(
+        state.uow_factory.as_ref(),
+        CompleteTransactionInput {
+            transaction_id,
+            requester_id,
+        },
+    )
+    .await?;
+
+    let transaction =
+        get_transaction(state.transaction_repo.as_ref(), transaction_id, requester_id).await?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        TransactionDto::from(&transaction),
+        output.message,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/transactions/{id}/cancel",
+    tag = "transactions",
+    responses(
+        (status = 200, description = "交易已取消，冻结款项按取消费政策结算后退还买家", body = ApiResponse<TransactionDto>),
+        (status = 403, description = "非交易参与者")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn cancel_transaction_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(requester_id): CurrentUser,
+) -> Result<Json<ApiResponse<TransactionDto>>, AppError> {
+    let transaction_id = parse_id(&id, "无效的交易 ID")?;
+
+    let policy = cancellation_policy(&state.config.cancellation)?;
+    let output = cancel_transaction(
+        state.uow_factory.as_ref(),
+        &policy,
+        CancelTransactionInput {
+            transaction_id,
+            requester_id,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        TransactionDto::from(&output.transaction),
+        output.message,
+    )))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/transactions/{id}/dispute",
+    tag = "transactions",
+    request_body = DisputeTransactionRequest,
+    responses(
+        (status = 200, description = "交易已进入争议，款项保持冻结等待裁决", body = ApiResponse<DisputeTransactionResponse>),
+        (status = 403, description = "非交易参与者")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn dispute_transaction_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(requester_id): CurrentUser,
+    Json(req): Json<DisputeTransactionRequest>,
+) -> Result<Json<ApiResponse<DisputeTransactionResponse>>, AppError> {
+    let transaction_id = parse_id(&id, "无效的交易 ID")?;
+
+    let output = dispute_transaction(
+        state.uow_factory.as_ref(),
+        DisputeTransactionInput {
+            transaction_id,
+            requester_id,
+            reason: req.reason,
+        },
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(DisputeTransactionResponse {
+        transaction: TransactionDto::from(&output.transaction),
+        dispute: DisputeDto::from(&output.dispute),
+    })))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/transactions/{id}/return",
+    tag = "transactions",
+    responses(
+        (status = 200, description = "工具已归还，交易完成并退还押金", body = ApiResponse<TransactionDto>),
+        (status = 403, description = "只有工具所有者可以登记归还")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn return_tool_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(owner_id): CurrentUser,
+) -> Result<Json<ApiResponse<TransactionDto>>, AppError> {
+    let transaction_id = parse_id(&id, "无效的交易 ID")?;
+
+    let output = return_tool(
+        state.uow_factory.as_ref(),
+        ReturnToolInput {
+            transaction_id,
+            owner_id,
+        },
+    )
+    .await?;
+
+    let transaction =
+        get_transaction(state.transaction_repo.as_ref(), transaction_id, owner_id).await?;
+
+    Ok(Json(ApiResponse::success_with_message(
+        TransactionDto::from(&transaction),
+        output.message,
+    )))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/transactions/{id}/rental",
+    tag = "transactions",
+    responses(
+        (status = 200, description = "工具租用详情", body = ApiResponse<ToolRentalDto>),
+        (status = 403, description = "非交易参与者"),
+        (status = 404, description = "交易不存在或不是工具租用")
+    ),
+    security(
+        ("bearer_auth" = [])
+    )
+)]
+pub async fn get_tool_rental_handler(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    CurrentUser(requester_id): CurrentUser,
+) -> Result<Json<ApiResponse<ToolRentalDto>>, AppError> {
+    let transaction_id = parse_id(&id, "无效的交易 ID")?;
+
+    let rental = get_tool_rental(
+        state.transaction_repo.as_ref(),
+        state.tool_rental_repo.as_ref(),
+        transaction_id,
+        requester_id,
+    )
+    .await?;
+
+    Ok(Json(ApiResponse::success(ToolRentalDto::from(&rental))))
+}
+
+fn filter_by_status(
+    transactions: Vec<Transaction>,
+    status: Option<&str>,
+) -> Result<Vec<TransactionDto>, AppError> {
+    let status = status
+        .filter(|s| !s.is_empty())
+        .map(str::parse::<TransactionStatus>)
+        .transpose()?;
+
+    Ok(transactions
+        .iter()
+        .filter(|t| status.is_none_or(|status| t.status == status))
+        .map(TransactionDto::from)
+        .collect())
+}
+
+/// 由配置构建取消费政策
+fn cancellation_policy(config: &CancellationConfig) -> Result<CancellationPolicy, AppError> {
+    CancellationPolicy::new(
+        Duration::minutes(config.grace_period_minutes),
+        config.buyer_fee_rate,
+        config.late_fee_rate,
+        Duration::hours(config.late_window_hours),
+    )
+    .map_err(|e| AppError::internal(format!("取消费配置无效: {}", e)))
+}
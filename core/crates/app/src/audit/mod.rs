@@ -0,0 +1,8 @@
+//! 审计日志相关用例
+
+pub mod queries;
+pub mod record;
+
+// 重导出
+pub use queries::{list_audit_events, ListAuditEventsInput, ListAuditEventsOutput};
+pub use record::record;
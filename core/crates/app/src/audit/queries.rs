@@ -0,0 +1,37 @@
+//! 审计日志查询用例
+
+use domain::audit::{AuditEvent, AuditEventCriteria, AuditEventRepository};
+use shared::Result;
+use tracing::instrument;
+
+/// 审计事件查询输入
+#[derive(Debug)]
+pub struct ListAuditEventsInput {
+    pub criteria: AuditEventCriteria,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// 审计事件查询输出
+#[derive(Debug)]
+pub struct ListAuditEventsOutput {
+    pub events: Vec<AuditEvent>,
+    pub total_count: u64,
+}
+
+/// 按条件查询审计事件（按时间倒序）
+#[instrument(name = "list_audit_events", skip(audit_repo))]
+pub async fn list_audit_events(
+    audit_repo: &dyn AuditEventRepository,
+    input: ListAuditEventsInput,
+) -> Result<ListAuditEventsOutput> {
+    let events = audit_repo
+        .search(&input.criteria, input.limit, input.offset)
+        .await?;
+    let total_count = audit_repo.count(&input.criteria).await?;
+
+    Ok(ListAuditEventsOutput {
+        events,
+        total_count,
+    })
+}
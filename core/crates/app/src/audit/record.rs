@@ -0,0 +1,23 @@
+//! 记录审计事件
+
+use domain::{audit::AuditEvent, unit_of_work::UnitOfWork};
+use shared::Result;
+use tracing::info;
+
+/// 在工作单元中追加审计事件
+///
+/// 审计事件与用例的其他写操作在同一事务中提交，操作回滚时审计记录一并回滚。
+pub async fn record(uow: &dyn UnitOfWork, event: AuditEvent) -> Result<()> {
+    uow.audit_events().append(&event).await?;
+
+    info!(
+        audit_event_id = %event.id,
+        actor_id = %event.actor_id,
+        action = %event.action,
+        target_type = %event.target_type,
+        target_id = %event.target_id,
+        "已记录审计事件"
+    );
+
+    Ok(())
+}
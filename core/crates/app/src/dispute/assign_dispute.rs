@@ -0,0 +1,88 @@
+//! 分配争议决策者用例
+
+use domain::{
+    dispute::{Dispute, DisputeId},
+    member::MemberId,
+    unit_of_work::UnitOfWorkFactory,
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument, warn};
+
+/// 分配争议输入
+#[derive(Debug)]
+pub struct AssignDisputeInput {
+    pub dispute_id: DisputeId,
+    pub requester_id: MemberId,
+    /// 被分配的决策者，为空表示操作者认领
+    pub decider_id: Option<MemberId>,
+}
+
+/// 分配争议用例
+///
+/// 管理员可以把争议分配给任意有权裁决的决策者；决策者只能认领自己有权裁决的争议。
+#[instrument(
+    name = "assign_dispute",
+    skip(uow_factory),
+    fields(
+        dispute_id = %input.dispute_id,
+        requester_id = %input.requester_id,
+        decider_id = ?input.decider_id
+    )
+)]
+pub async fn execute(uow_factory: &dyn UnitOfWorkFactory, input: AssignDisputeInput) -> Result<Dispute> {
+    info!("开始分配争议");
+
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证操作者
+    let requester = uow
+        .members()
+        .find_by_id(input.requester_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("用户不存在"))?;
+
+    let decider_id = input.decider_id.unwrap_or(input.requester_id);
+    if decider_id != input.requester_id && !requester.is_admin() {
+        warn!(requester_id = %input.requester_id, "非管理员尝试分配争议");
+        return Err(AppError::forbidden("只有管理员可以把争议分配给其他决策者"));
+    }
+
+    // 2. 验证争议存在（锁定争议行）
+    let mut dispute = uow
+        .disputes()
+        .find_by_id_for_update(&input.dispute_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("争议不存在"))?;
+
+    // 3. 验证决策者有权裁决且不是交易参与者
+    let decider = if decider_id == input.requester_id {
+        requester
+    } else {
+        uow.members()
+            .find_by_id(decider_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("决策者不存在"))?
+    };
+
+    if !decider.is_active() {
+        return Err(AppError::validation("决策者账户未激活"));
+    }
+
+    let transaction = uow
+        .transactions()
+        .find_by_id(&dispute.transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    if transaction.is_participant(&decider.id) {
+        return Err(AppError::validation("交易参与者不能裁决自己的争议"));
+    }
+
+    // 4. 分配并保存
+    dispute.assign(&decider)?;
+    uow.disputes().update(&dispute).await?;
+    uow.commit().await?;
+
+    info!(dispute_id = %dispute.id, decider_id = %decider.id, "争议已分配");
+    Ok(dispute)
+}
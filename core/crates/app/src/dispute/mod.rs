@@ -0,0 +1,12 @@
+//! 交易争议仲裁相关用例
+
+pub mod assign_dispute;
+pub mod queries;
+pub mod resolve_dispute;
+pub mod submit_evidence;
+
+// 重导出
+pub use assign_dispute::{execute as assign_dispute, AssignDisputeInput};
+pub use queries::{get_dispute, list_assigned_disputes, DisputeDetails};
+pub use resolve_dispute::{execute as resolve_dispute, ResolveDisputeInput};
+pub use submit_evidence::{execute as submit_evidence, SubmitEvidenceInput};
@@ -0,0 +1,70 @@
+//! 争议查询用例
+
+use domain::{
+    dispute::{Dispute, DisputeEvidence, DisputeId, DisputeRepository},
+    member::{MemberId, MemberRepository},
+    transaction::TransactionRepository,
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+/// 争议详情（含证据）
+#[derive(Debug)]
+pub struct DisputeDetails {
+    pub dispute: Dispute,
+    pub evidence: Vec<DisputeEvidence>,
+}
+
+/// 获取争议详情
+///
+/// 交易参与者、分配的决策者以及有权裁决该争议的决策者和管理员可见。
+#[instrument(
+    name = "get_dispute",
+    skip(dispute_repo, transaction_repo, member_repo),
+    fields(dispute_id = %dispute_id, requester_id = %requester_id)
+)]
+pub async fn get_dispute(
+    dispute_repo: &dyn DisputeRepository,
+    transaction_repo: &dyn TransactionRepository,
+    member_repo: &dyn MemberRepository,
+    dispute_id: DisputeId,
+    requester_id: MemberId,
+) -> Result<DisputeDetails> {
+    info!("获取争议详情");
+
+    let dispute = dispute_repo
+        .find_by_id(&dispute_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("争议不存在"))?;
+
+    let transaction = transaction_repo
+        .find_by_id(&dispute.transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    let visible = transaction.is_participant(&requester_id)
+        || dispute.is_assigned_to(&requester_id)
+        || member_repo
+            .find_by_id(requester_id)
+            .await?
+            .is_some_and(|member| dispute.can_be_arbitrated_by(&member));
+
+    if !visible {
+        return Err(AppError::forbidden("无权查看该争议"));
+    }
+
+    let evidence = dispute_repo.list_evidence(&dispute.id).await?;
+
+    Ok(DisputeDetails { dispute, evidence })
+}
+
+/// 分配给我的处理中争议
+#[instrument(name = "list_assigned_disputes", skip(dispute_repo), fields(decider_id = %decider_id))]
+pub async fn list_assigned_disputes(
+    dispute_repo: &dyn DisputeRepository,
+    decider_id: MemberId,
+) -> Result<Vec<Dispute>> {
+    info!("查询分配给我的争议");
+
+    dispute_repo.find_open_by_decider(&decider_id).await
+}
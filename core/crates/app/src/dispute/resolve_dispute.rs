@@ -0,0 +1,502 @@
+//! 裁决争议用例
+
+use chrono::Utc;
+use domain::{
+    audit::{AuditAction, AuditContext, AuditEvent, AuditTargetType},
+    dispute::{Dispute, DisputeId, DisputeRuling, RulingOutcome},
+    isu::{ISUTransactionType, ISU},
+    member::MemberId,
+    transaction::TransactionItemType,
+    unit_of_work::UnitOfWorkFactory,
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument, warn};
+
+use crate::audit;
+use crate::member::reputation::recalculate_participants_reputation;
+use crate::transaction::return_tool::close_rental;
+
+/// 裁决争议输入
+#[derive(Debug)]
+pub struct ResolveDisputeInput {
+    pub dispute_id: DisputeId,
+    pub decider_id: MemberId,
+    pub outcome: RulingOutcome,
+    /// 拆分裁决时退还买家的金额，其余放款给卖家
+    pub buyer_refund: Option<ISU>,
+    pub note: Option<String>,
+    pub context: AuditContext,
+}
+
+/// 裁决争议用例
+///
+/// 按裁决结算托管中的交易款项：退还买家和放款卖家各自产生独立的ISU流水，并与裁决一并记录。
+/// 全额退还时交易视为取消、服务恢复可用，否则视为完成。工具租用裁决后视为已归还，押金退还租用者。
+#[instrument(
+    name = "resolve_dispute",
+    skip(uow_factory, input),
+    fields(
+        dispute_id = %input.dispute_id,
+        decider_id = %input.decider_id,
+        outcome = %input.outcome
+    )
+)]
+pub async fn execute(uow_factory: &dyn UnitOfWorkFactory, input: ResolveDisputeInput) -> Result<Dispute> {
+    info!("开始裁决争议");
+
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证争议存在且处理中（锁定争议行）
+    let mut dispute = uow
+        .disputes()
+        .find_by_id_for_update(&input.dispute_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("争议不存在"))?;
+
+    if !dispute.is_open() {
+        return Err(AppError::validation("争议已裁决"));
+    }
+
+    // 2. 验证裁决者：分配的决策者或管理员，且仍有权裁决该职业
+    let decider = uow
+        .members()
+        .find_by_id(input.decider_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("决策者不存在"))?;
+
+    if !dispute.is_assigned_to(&decider.id) && !decider.is_admin() {
+        warn!(decider_id = %input.decider_id, "非分配的决策者尝试裁决争议");
+        return Err(AppError::forbidden("只有分配的决策者或管理员可以裁决争议"));
+    }
+
+    if !dispute.can_be_arbitrated_by(&decider) {
+        return Err(AppError::forbidden("无权裁决该职业的争议"));
+    }
+
+    // 3. 锁定交易，计算托管款项的拆分
+    let mut transaction = uow
+        .transactions()
+        .find_by_id_for_update(&dispute.transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    if transaction.is_participant(&decider.id) {
+        return Err(AppError::validation("交易参与者不能裁决自己的争议"));
+    }
+
+    let escrowed = transaction.take_escrow();
+    let (buyer_refund, seller_payout) = input.outcome.settle(&escrowed, input.buyer_refund)?;
+    transaction.resolve_dispute(input.outcome == RulingOutcome::FullRefund)?;
+
+    // 4. 结算：退还买家、放款卖家，各自独立记账
+    let buyer_isu_account = uow
+        .isu_accounts()
+        .find_by_owner_id(&transaction.buyer_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("买家ISU账户不存在"))?;
+
+    let refund_isu_transaction_id = if buyer_refund.value().is_zero() {
+        None
+    } else {
+        let refund = uow
+            .isu_accounts()
+            .release(
+                &buyer_isu_account.id,
+                &buyer_isu_account.id,
+                &buyer_refund,
+                ISUTransactionType::EscrowRefund,
+                Some(format!("争议裁决退款 - {}", transaction.id.value())),
+            )
+            .await?;
+        Some(refund.id)
+    };
+
+    let payout_isu_transaction_id = if seller_payout.value().is_zero() {
+        None
+    } else {
+        let seller_isu_account = uow
+            .isu_accounts()
+            .find_by_owner_id(&transaction.seller_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("卖家ISU账户不存在"))?;
+
+        let payout_type = match &transaction.item_type {
+            TransactionItemType::Service(_) => ISUTransactionType::ServicePayment,
+            TransactionItemType::Tool(_) => ISUTransactionType::ToolRental,
+        };
+
+        let payout = uow
+            .isu_accounts()
+            .release(
+                &buyer_isu_account.id,
+                &seller_isu_account.id,
+                &seller_payout,
+                payout_type,
+                Some(format!("争议裁决放款 - {}", transaction.id.value())),
+            )
+            .await?;
+        Some(payout.id)
+    };
+
+    // 5. 结束交易项目：工具租用视为归还（押金退还、工具恢复可用、释放预订）；
+    //    服务在全额退还时恢复可用，否则视为完成
+    match transaction.item_type {
+        TransactionItemType::Tool(tool_id) => {
+            close_rental(uow.as_ref(), &transaction, tool_id).await?;
+        }
+        TransactionItemType::Service(service_id) => {
+            if let Some(mut service) = uow.services().find_by_id(&service_id).await? {
+                if input.outcome == RulingOutcome::FullRefund {
+                    service.reopen()?;
+                } else {
+                    service.complete()?;
+                }
+                uow.services().save(&service).await?;
+            }
+        }
+    }
+
+    // 6. 记录裁决并保存，重新计算双方信誉分
+    let before = dispute.clone();
+    dispute.resolve(DisputeRuling {
+        outcome: input.outcome,
+        buyer_refund,
+        seller_payout,
+        note: input.note,
+        decided_by: decider.id,
+        decided_at: Utc::now(),
+        refund_isu_transaction_id,
+        payout_isu_transaction_id,
+    })?;
+
+    uow.transactions().update(&transaction).await?;
+    uow.disputes().update(&dispute).await?;
+    recalculate_participants_reputation(uow.as_ref(), &transaction).await?;
+
+    // 7. 记录审计事件
+    audit::record(
+        uow.as_ref(),
+        AuditEvent::new(
+            &input.context,
+            decider.id,
+            AuditAction::DisputeResolved,
+            AuditTargetType::Dispute,
+            dispute.id,
+        )
+        .with_before(&before)?
+        .with_after(&dispute)?,
+    )
+    .await?;
+
+    uow.commit().await?;
+
+    info!(
+        dispute_id = %dispute.id,
+        transaction_id = %transaction.id,
+        buyer_refund = %buyer_refund,
+        seller_payout = %seller_payout,
+        "争议已裁决"
+    );
+
+    Ok(dispute)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::{create_tool, CreateToolInput};
+    use crate::transaction::{
+        confirm_transaction, dispute_transaction, rent_tool, start_transaction, ConfirmTransactionInput,
+        DisputeTransactionInput, RentToolInput, StartTransactionInput,
+    };
+    use domain::dispute::DisputeRepository;
+    use domain::isu::{ISUAccount, ISUAccountRepository};
+    use domain::member::{Email, Member, MemberRepository, Username};
+    use domain::tool::{DepositStatus, ToolId, ToolRentalRepository, ToolRepository};
+    use domain::transaction::{Transaction, TransactionRepository, TransactionStatus};
+    use infra::{
+        PgPool, PostgresDisputeRepository, PostgresISUAccountRepository, PostgresMemberRepository,
+        PostgresToolRentalRepository, PostgresToolRepository, PostgresTransactionRepository,
+        PostgresUnitOfWorkFactory,
+    };
+    use rust_decimal::Decimal;
+
+    /// 租用者初始余额
+    const RENTER_FUNDS: i64 = 100;
+
+    /// 争议中的工具租用：两天租金 20 ISU 已托管，押金 50 ISU 已冻结
+    struct DisputedRental {
+        renter: MemberId,
+        owner: MemberId,
+        admin: MemberId,
+        tool_id: ToolId,
+        transaction: Transaction,
+        dispute: Dispute,
+    }
+
+    fn isu(amount: i64, scale: u32) -> ISU {
+        ISU::new(Decimal::new(amount, scale)).unwrap()
+    }
+
+    /// 创建会员及其ISU账户，并由金库发放初始余额（为零时不发放）
+    async fn member(pool: &PgPool, name: &str, funds: i64) -> Member {
+        let member = Member::new(
+            Email::new(format!("{}@example.com", name)).unwrap(),
+            Username::new(name).unwrap(),
+            "hash".to_string(),
+        );
+        PostgresMemberRepository::new(pool.clone())
+            .save(&member)
+            .await
+            .unwrap();
+
+        let accounts = PostgresISUAccountRepository::new(pool.clone());
+        let account = ISUAccount::new(member.id, ISU::default());
+        accounts.save(&account).await.unwrap();
+        if funds > 0 {
+            accounts
+                .transfer(
+                    &ISUAccount::treasury_id(),
+                    &account.id,
+                    &isu(funds, 0),
+                    ISUTransactionType::InitialBalance,
+                    None,
+                )
+                .await
+                .unwrap();
+        }
+
+        member
+    }
+
+    async fn disputed_rental(pool: &PgPool) -> DisputedRental {
+        let uow_factory = PostgresUnitOfWorkFactory::new(pool.clone());
+        let renter = member(pool, "renter", RENTER_FUNDS).await;
+        let owner = member(pool, "owner", 0).await;
+        let mut admin = member(pool, "admin", 0).await;
+        admin.promote_to_admin();
+        PostgresMemberRepository::new(pool.clone())
+            .update(&admin)
+            .await
+            .unwrap();
+
+        let tool = create_tool(
+            &PostgresToolRepository::new(pool.clone()),
+            CreateToolInput {
+                owner_id: owner.id,
+                name: "电钻".to_string(),
+                description: None,
+                category: "工具".to_string(),
+                price_amount: 0,
+                price_currency: "ISU".to_string(),
+                daily_rate: Some(Decimal::new(10, 0)),
+                deposit: Some(Decimal::new(50, 0)),
+            },
+        )
+        .await
+        .unwrap();
+
+        let today = Utc::now().date_naive();
+        let rented = rent_tool(
+            &uow_factory,
+            RentToolInput {
+                renter_id: renter.id,
+                tool_id: tool.id,
+                start_date: today,
+                end_date: today + chrono::Duration::days(1),
+                description: None,
+            },
+        )
+        .await
+        .unwrap();
+        let transaction_id = rented.transaction.id;
+
+        confirm_transaction(
+            &uow_factory,
+            ConfirmTransactionInput {
+                transaction_id,
+                seller_id: owner.id,
+            },
+        )
+        .await
+        .unwrap();
+        start_transaction(
+            &uow_factory,
+            StartTransactionInput {
+                transaction_id,
+                seller_id: owner.id,
+            },
+        )
+        .await
+        .unwrap();
+        let disputed = dispute_transaction(
+            &uow_factory,
+            DisputeTransactionInput {
+                transaction_id,
+                requester_id: renter.id,
+                reason: "工具无法使用".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+        DisputedRental {
+            renter: renter.id,
+            owner: owner.id,
+            admin: admin.id,
+            tool_id: tool.id,
+            transaction: disputed.transaction,
+            dispute: disputed.dispute,
+        }
+    }
+
+    async fn resolve(
+        pool: &PgPool,
+        rental: &DisputedRental,
+        outcome: RulingOutcome,
+        buyer_refund: Option<ISU>,
+    ) -> Result<Dispute> {
+        execute(
+            &PostgresUnitOfWorkFactory::new(pool.clone()),
+            ResolveDisputeInput {
+                dispute_id: rental.dispute.id,
+                decider_id: rental.admin,
+                outcome,
+                buyer_refund,
+                note: None,
+                context: AuditContext::default(),
+            },
+        )
+        .await
+    }
+
+    /// 返回 (可用余额, 冻结余额)
+    async fn balances(pool: &PgPool, owner_id: &MemberId) -> (ISU, ISU) {
+        let account = PostgresISUAccountRepository::new(pool.clone())
+            .find_by_owner_id(owner_id)
+            .await
+            .unwrap()
+            .unwrap();
+        (account.balance, account.held_balance)
+    }
+
+    async fn transaction_status(pool: &PgPool, rental: &DisputedRental) -> TransactionStatus {
+        PostgresTransactionRepository::new(pool.clone())
+            .find_by_id(&rental.transaction.id)
+            .await
+            .unwrap()
+            .unwrap()
+            .status
+    }
+
+    /// 裁决后工具恢复可用，押金已退还租用者
+    async fn assert_rental_closed(pool: &PgPool, rental: &DisputedRental) {
+        let tool = PostgresToolRepository::new(pool.clone())
+            .find_by_id(rental.tool_id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(tool.is_available());
+
+        let tool_rental = PostgresToolRentalRepository::new(pool.clone())
+            .find_by_transaction_id(&rental.transaction.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(tool_rental.deposit_status, DepositStatus::Refunded);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn split_settles_the_escrow_and_refunds_the_deposit(pool: PgPool) {
+        let rental = disputed_rental(&pool).await;
+        assert_eq!(balances(&pool, &rental.renter).await, (isu(30, 0), isu(70, 0)));
+
+        let dispute = resolve(&pool, &rental, RulingOutcome::Split, Some(isu(525, 2)))
+            .await
+            .unwrap();
+
+        let ruling = dispute.ruling.unwrap();
+        assert_eq!(ruling.buyer_refund, isu(525, 2));
+        assert_eq!(ruling.seller_payout, isu(1475, 2));
+        assert!(ruling.refund_isu_transaction_id.is_some());
+        assert!(ruling.payout_isu_transaction_id.is_some());
+
+        // 押金全额退还，租金按裁决拆分
+        assert_eq!(balances(&pool, &rental.renter).await, (isu(8525, 2), ISU::default()));
+        assert_eq!(balances(&pool, &rental.owner).await, (isu(1475, 2), ISU::default()));
+        assert_eq!(transaction_status(&pool, &rental).await, TransactionStatus::Completed);
+        assert_rental_closed(&pool, &rental).await;
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn full_refund_returns_rent_and_deposit_and_cancels(pool: PgPool) {
+        let rental = disputed_rental(&pool).await;
+
+        let dispute = resolve(&pool, &rental, RulingOutcome::FullRefund, None)
+            .await
+            .unwrap();
+
+        assert!(dispute.ruling.unwrap().payout_isu_transaction_id.is_none());
+        assert_eq!(
+            balances(&pool, &rental.renter).await,
+            (isu(RENTER_FUNDS, 0), ISU::default())
+        );
+        assert_eq!(balances(&pool, &rental.owner).await, (ISU::default(), ISU::default()));
+        assert_eq!(transaction_status(&pool, &rental).await, TransactionStatus::Cancelled);
+        assert_rental_closed(&pool, &rental).await;
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn release_to_seller_pays_the_rent_and_refunds_the_deposit(pool: PgPool) {
+        let rental = disputed_rental(&pool).await;
+
+        let dispute = resolve(&pool, &rental, RulingOutcome::ReleaseToSeller, None)
+            .await
+            .unwrap();
+
+        assert!(dispute.ruling.unwrap().refund_isu_transaction_id.is_none());
+        assert_eq!(balances(&pool, &rental.renter).await, (isu(80, 0), ISU::default()));
+        assert_eq!(balances(&pool, &rental.owner).await, (isu(20, 0), ISU::default()));
+        assert_eq!(transaction_status(&pool, &rental).await, TransactionStatus::Completed);
+        assert_rental_closed(&pool, &rental).await;
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn invalid_split_leaves_the_dispute_open(pool: PgPool) {
+        let rental = disputed_rental(&pool).await;
+
+        for refund in [None, Some(ISU::default()), Some(isu(20, 0)), Some(isu(33335, 4))] {
+            let result = resolve(&pool, &rental, RulingOutcome::Split, refund).await;
+            assert!(matches!(result, Err(AppError::Validation(_))));
+        }
+
+        let dispute = PostgresDisputeRepository::new(pool.clone())
+            .find_by_id(&rental.dispute.id)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(dispute.is_open());
+        assert_eq!(balances(&pool, &rental.renter).await, (isu(30, 0), isu(70, 0)));
+        assert_eq!(transaction_status(&pool, &rental).await, TransactionStatus::Disputed);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn participants_cannot_resolve_their_own_dispute(pool: PgPool) {
+        let rental = disputed_rental(&pool).await;
+
+        let result = execute(
+            &PostgresUnitOfWorkFactory::new(pool.clone()),
+            ResolveDisputeInput {
+                dispute_id: rental.dispute.id,
+                decider_id: rental.owner,
+                outcome: RulingOutcome::ReleaseToSeller,
+                buyer_refund: None,
+                note: None,
+                context: AuditContext::default(),
+            },
+        )
+        .await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+}
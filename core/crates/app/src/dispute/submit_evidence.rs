@@ -0,0 +1,54 @@
+//! 提交争议证据用例
+
+use domain::{
+    dispute::{DisputeEvidence, DisputeId, DisputeRepository},
+    member::MemberId,
+    transaction::TransactionRepository,
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+/// 提交证据输入
+#[derive(Debug)]
+pub struct SubmitEvidenceInput {
+    pub dispute_id: DisputeId,
+    pub author_id: MemberId, // 交易双方或分配的决策者
+    pub message: String,
+}
+
+/// 提交争议证据用例（只增不改，裁决后不再接受）
+#[instrument(
+    name = "submit_dispute_evidence",
+    skip(dispute_repo, transaction_repo, input),
+    fields(dispute_id = %input.dispute_id, author_id = %input.author_id)
+)]
+pub async fn execute(
+    dispute_repo: &dyn DisputeRepository,
+    transaction_repo: &dyn TransactionRepository,
+    input: SubmitEvidenceInput,
+) -> Result<DisputeEvidence> {
+    info!("开始提交争议证据");
+
+    // 1. 验证争议存在
+    let dispute = dispute_repo
+        .find_by_id(&input.dispute_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("争议不存在"))?;
+
+    // 2. 验证提交者是交易参与者或分配的决策者
+    let transaction = transaction_repo
+        .find_by_id(&dispute.transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    if !transaction.is_participant(&input.author_id) && !dispute.is_assigned_to(&input.author_id) {
+        return Err(AppError::forbidden("只有交易参与者和分配的决策者可以提交证据"));
+    }
+
+    // 3. 保存证据
+    let evidence = DisputeEvidence::new(&dispute, input.author_id, input.message)?;
+    dispute_repo.add_evidence(&evidence).await?;
+
+    info!(evidence_id = %evidence.id, "争议证据已提交");
+    Ok(evidence)
+}
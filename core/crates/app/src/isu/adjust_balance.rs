@@ -0,0 +1,234 @@
+//! 管理员调整ISU余额用例
+
+use domain::{
+    audit::{AuditAction, AuditContext, AuditEvent, AuditTargetType},
+    isu::{ISUAccount, ISUTransaction, ISUTransactionType, ISU},
+    member::MemberId,
+    unit_of_work::UnitOfWorkFactory,
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use serde_json::json;
+use tracing::{info, instrument, warn};
+
+use crate::audit;
+
+/// 调整余额输入
+#[derive(Debug)]
+pub struct AdjustBalanceInput {
+    pub admin_id: MemberId,
+    pub member_id: MemberId,
+    /// 正数为发放（金库 → 会员），负数为扣减（会员 → 金库）
+    pub amount: Decimal,
+    pub reason: String,
+    pub context: AuditContext,
+}
+
+/// 管理员调整ISU余额用例
+///
+/// 调整通过与平台金库账户之间的转账完成，保证每一笔变动都有流水记录。
+#[instrument(
+    name = "adjust_isu_balance",
+    skip(uow_factory, input),
+    fields(
+        admin_id = %input.admin_id,
+        member_id = %input.member_id,
+        amount = %input.amount
+    )
+)]
+pub async fn execute(
+    uow_factory: &dyn UnitOfWorkFactory,
+    input: AdjustBalanceInput,
+) -> Result<ISUTransaction> {
+    info!("开始调整ISU余额");
+
+    // 1. 验证输入：调整金额非零且最多两位小数（否则入库时四舍五入，流通总量将无法对平）
+    if input.amount.is_zero() {
+        return Err(AppError::validation("调整金额不能为0"));
+    }
+    let amount = ISU::from_input(input.amount.abs())?;
+
+    let reason = input.reason.trim();
+    if reason.is_empty() {
+        return Err(AppError::validation("调整原因不能为空"));
+    }
+
+    let uow = uow_factory.begin().await?;
+
+    // 2. 验证操作者是管理员
+    let admin = uow
+        .members()
+        .find_by_id(input.admin_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("管理员不存在"))?;
+
+    if !admin.is_admin() {
+        warn!(admin_id = %input.admin_id, "非管理员用户尝试调整ISU余额");
+        return Err(AppError::forbidden("只有管理员可以调整ISU余额"));
+    }
+
+    // 3. 获取会员账户
+    let account = uow
+        .isu_accounts()
+        .find_by_owner_id(&input.member_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("ISU账户不存在"))?;
+
+    if account.is_system_account() {
+        return Err(AppError::validation("不能调整平台系统账户"));
+    }
+
+    // 4. 与金库账户之间转账
+    let treasury_id = ISUAccount::treasury_id();
+    let (from, to) = if input.amount.is_sign_positive() {
+        (&treasury_id, &account.id)
+    } else {
+        (&account.id, &treasury_id)
+    };
+
+    let isu_transaction = uow
+        .isu_accounts()
+        .transfer(
+            from,
+            to,
+            &amount,
+            ISUTransactionType::AdminAdjustment,
+            Some(format!("管理员调整 - {}", reason)),
+        )
+        .await?;
+
+    // 5. 记录审计事件并提交工作单元
+    let adjusted = uow
+        .isu_accounts()
+        .find_by_id(&account.id)
+        .await?
+        .ok_or_else(|| AppError::not_found("ISU账户不存在"))?;
+
+    audit::record(
+        uow.as_ref(),
+        AuditEvent::new(
+            &input.context,
+            input.admin_id,
+            AuditAction::IsuBalanceAdjusted,
+            AuditTargetType::IsuAccount,
+            account.id,
+        )
+        .with_before(&json!({
+            "owner_id": account.owner_id,
+            "balance": account.balance,
+        }))?
+        .with_after(&json!({
+            "owner_id": adjusted.owner_id,
+            "balance": adjusted.balance,
+            "amount": input.amount,
+            "isu_transaction_id": isu_transaction.id,
+        }))?
+        .with_reason(Some(reason.to_string())),
+    )
+    .await?;
+
+    uow.commit().await?;
+
+    info!(
+        isu_transaction_id = isu_transaction.id,
+        member_id = %input.member_id,
+        amount = %input.amount,
+        "ISU余额调整成功"
+    );
+
+    Ok(isu_transaction)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::isu::ISUAccountRepository;
+    use domain::member::{Email, Member, MemberRepository, Username};
+    use infra::{PgPool, PostgresISUAccountRepository, PostgresMemberRepository, PostgresUnitOfWorkFactory};
+
+    /// 创建管理员和一个普通会员（带空ISU账户），返回 (管理员, 会员)
+    async fn admin_and_member(pool: &PgPool) -> (MemberId, MemberId) {
+        let members = PostgresMemberRepository::new(pool.clone());
+        let mut admin = Member::new(
+            Email::new("admin@example.com").unwrap(),
+            Username::new("admin").unwrap(),
+            "hash".to_string(),
+        );
+        admin.promote_to_admin();
+        members.save(&admin).await.unwrap();
+
+        let member = Member::new(
+            Email::new("member@example.com").unwrap(),
+            Username::new("member").unwrap(),
+            "hash".to_string(),
+        );
+        members.save(&member).await.unwrap();
+        PostgresISUAccountRepository::new(pool.clone())
+            .save(&ISUAccount::new(member.id, ISU::default()))
+            .await
+            .unwrap();
+
+        (admin.id, member.id)
+    }
+
+    async fn adjust(pool: &PgPool, admin_id: MemberId, member_id: MemberId, amount: Decimal) -> Result<ISUTransaction> {
+        execute(
+            &PostgresUnitOfWorkFactory::new(pool.clone()),
+            AdjustBalanceInput {
+                admin_id,
+                member_id,
+                amount,
+                reason: "测试调整".to_string(),
+                context: AuditContext::default(),
+            },
+        )
+        .await
+    }
+
+    async fn balance(pool: &PgPool, member_id: &MemberId) -> Decimal {
+        PostgresISUAccountRepository::new(pool.clone())
+            .find_by_owner_id(member_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .balance
+            .value()
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn adjustments_move_isu_to_and_from_the_treasury(pool: PgPool) {
+        let (admin_id, member_id) = admin_and_member(&pool).await;
+
+        adjust(&pool, admin_id, member_id, Decimal::new(1025, 2)).await.unwrap();
+        adjust(&pool, admin_id, member_id, Decimal::new(-25, 2)).await.unwrap();
+
+        assert_eq!(balance(&pool, &member_id).await, Decimal::new(10, 0));
+        let circulation = PostgresISUAccountRepository::new(pool.clone())
+            .get_circulation()
+            .await
+            .unwrap();
+        assert!(circulation.check_invariant().is_ok());
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn amounts_beyond_two_decimal_places_are_rejected(pool: PgPool) {
+        let (admin_id, member_id) = admin_and_member(&pool).await;
+
+        // 0.004 入库会变成 0，1.005 会被四舍五入为 1.01
+        for amount in [Decimal::new(4, 3), Decimal::new(1005, 3), Decimal::new(-1005, 3)] {
+            let result = adjust(&pool, admin_id, member_id, amount).await;
+            assert!(matches!(result, Err(AppError::Validation(_))));
+        }
+
+        assert!(balance(&pool, &member_id).await.is_zero());
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn zero_adjustment_is_rejected(pool: PgPool) {
+        let (admin_id, member_id) = admin_and_member(&pool).await;
+
+        let result = adjust(&pool, admin_id, member_id, Decimal::ZERO).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+}
@@ -0,0 +1,45 @@
+//! ISU流通量核对用例
+
+use domain::{
+    isu::{ISUAccountRepository, ISUCirculation},
+    member::{MemberId, MemberRepository},
+};
+use shared::{AppError, Result};
+use tracing::{error, info, instrument};
+
+/// 核对ISU流通量（仅管理员）
+///
+/// 返回流通量快照；不变式被破坏时记录错误日志，由调用方根据 `is_balanced` 决定后续处理。
+#[instrument(name = "check_isu_circulation", skip(member_repo, isu_repo), fields(admin_id = %admin_id))]
+pub async fn check_circulation(
+    member_repo: &dyn MemberRepository,
+    isu_repo: &dyn ISUAccountRepository,
+    admin_id: MemberId,
+) -> Result<ISUCirculation> {
+    info!("开始核对ISU流通量");
+
+    // 1. 验证操作者是管理员
+    let admin = member_repo
+        .find_by_id(admin_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("管理员不存在"))?;
+
+    if !admin.is_admin() {
+        return Err(AppError::forbidden("只有管理员可以查看ISU流通量"));
+    }
+
+    // 2. 获取快照并校验不变式
+    let circulation = isu_repo.get_circulation().await?;
+
+    if let Err(e) = circulation.check_invariant() {
+        error!(error = %e, "ISU流通量不变式被破坏");
+    } else {
+        info!(
+            circulating = %circulation.circulating,
+            treasury_balance = %circulation.treasury_balance,
+            "ISU流通量核对通过"
+        );
+    }
+
+    Ok(circulation)
+}
@@ -0,0 +1,10 @@
+//! ISU钱包相关用例
+
+pub mod adjust_balance;
+pub mod circulation;
+pub mod wallet;
+
+// 重导出
+pub use adjust_balance::{execute as adjust_balance, AdjustBalanceInput};
+pub use circulation::check_circulation;
+pub use wallet::{get_account, get_statement, StatementInput, StatementOutput};
@@ -0,0 +1,100 @@
+//! ISU钱包查询用例
+
+use domain::{
+    isu::{ISUAccount, ISUAccountRepository, ISUStatementQuery, ISUTransaction, ISUTransactionType},
+    member::MemberId,
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+use uuid::Uuid;
+
+/// 账户流水查询输入
+#[derive(Debug)]
+pub struct StatementInput {
+    pub member_id: MemberId,
+    pub transaction_types: Vec<ISUTransactionType>,
+    pub cursor: Option<String>,
+    pub limit: u32,
+}
+
+/// 账户流水查询输出
+#[derive(Debug)]
+pub struct StatementOutput {
+    pub account: ISUAccount,
+    pub entries: Vec<ISUTransaction>,
+    /// 下一页游标，为 None 表示没有更多记录
+    pub next_cursor: Option<String>,
+}
+
+/// 获取会员的ISU账户
+#[instrument(name = "get_isu_account", skip(isu_repo), fields(member_id = %member_id))]
+pub async fn get_account(
+    isu_repo: &dyn ISUAccountRepository,
+    member_id: MemberId,
+) -> Result<ISUAccount> {
+    info!("获取ISU账户");
+
+    isu_repo
+        .find_by_owner_id(&member_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("ISU账户不存在"))
+}
+
+/// 获取会员的ISU账户流水（游标分页）
+#[instrument(
+    name = "get_isu_statement",
+    skip(isu_repo, input),
+    fields(
+        member_id = %input.member_id,
+        cursor = ?input.cursor,
+        limit = input.limit
+    )
+)]
+pub async fn get_statement(
+    isu_repo: &dyn ISUAccountRepository,
+    input: StatementInput,
+) -> Result<StatementOutput> {
+    info!("开始查询ISU账户流水");
+
+    // 1. 验证游标和分页参数
+    let before = input
+        .cursor
+        .as_deref()
+        .map(Uuid::parse_str)
+        .transpose()
+        .map_err(|_| AppError::validation("无效的分页游标"))?;
+
+    if input.limit == 0 {
+        return Err(AppError::validation("每页条数必须大于0"));
+    }
+
+    // 2. 获取账户
+    let account = get_account(isu_repo, input.member_id).await?;
+
+    // 3. 多取一条用于判断是否还有下一页
+    let mut entries = isu_repo
+        .get_statement(
+            &account.id,
+            &ISUStatementQuery {
+                transaction_types: input.transaction_types,
+                before,
+                limit: input.limit + 1,
+            },
+        )
+        .await?;
+
+    let next_cursor = if entries.len() > input.limit as usize {
+        entries.truncate(input.limit as usize);
+        entries.last().map(|entry| entry.id.clone())
+    } else {
+        None
+    };
+
+    info!(entries_count = entries.len(), has_more = next_cursor.is_some(), "ISU账户流水查询完成");
+
+    Ok(StatementOutput {
+        account,
+        entries,
+        next_cursor,
+    })
+}
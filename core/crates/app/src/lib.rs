@@ -1,8 +1,12 @@
 //! 应用层
 //! 编排用例，协调领域逻辑
 
+pub mod audit;
+pub mod dispute;
+pub mod isu;
 pub mod member;
 pub mod profession;
+pub mod review;
 pub mod service;
 pub mod tool;
 pub mod transaction;
@@ -1,6 +1,9 @@
 //! 会员登录用例
 
+use chrono::Duration;
+use domain::auth::{RefreshToken, RefreshTokenRepository};
 use domain::member::{Email, Member, MemberRepository};
+use infra::{generate_refresh_token, hash_refresh_token};
 use shared::{AppError, Result};
 use tracing::instrument;
 
@@ -8,21 +11,26 @@ use tracing::instrument;
 pub struct LoginInput {
     pub email: String,
     pub password: String,
+    /// 刷新令牌有效期
+    pub refresh_token_ttl: Duration,
 }
 
 /// 登录输出
 pub struct LoginOutput {
     pub member: Member,
+    /// 刷新令牌明文（只在此处返回，服务端只保存哈希）
+    pub refresh_token: String,
 }
 
 /// 会员登录
 #[instrument(
     name = "login_member",
-    skip(repo, hasher, input),
+    skip(repo, token_repo, hasher, input),
     fields(email = %input.email)
 )]
 pub async fn login_member(
     repo: &dyn MemberRepository,
+    token_repo: &dyn RefreshTokenRepository,
     hasher: &dyn infra::PasswordHasher,
     input: LoginInput,
 ) -> Result<LoginOutput> {
@@ -47,7 +55,27 @@ pub async fn login_member(
         return Err(AppError::validation("邮箱或密码错误"));
     }
 
+    // 签发新家族的刷新令牌
+    let refresh_token = issue_refresh_token(token_repo, &member, input.refresh_token_ttl).await?;
+
     tracing::info!(member_id = %member.id, "会员登录成功");
-    Ok(LoginOutput { member })
+    Ok(LoginOutput {
+        member,
+        refresh_token,
+    })
+}
+
+/// 签发新家族的刷新令牌，返回令牌明文
+async fn issue_refresh_token(
+    token_repo: &dyn RefreshTokenRepository,
+    member: &Member,
+    ttl: Duration,
+) -> Result<String> {
+    let token = generate_refresh_token();
+    token_repo
+        .save(&RefreshToken::issue(member.id, hash_refresh_token(&token), ttl))
+        .await?;
+
+    Ok(token)
 }
 
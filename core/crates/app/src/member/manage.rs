@@ -0,0 +1,263 @@
+//! 会员管理用例（管理后台）
+//!
+//! 状态和角色变更与审计记录在同一工作单元中写入。
+
+use domain::{
+    audit::{AuditAction, AuditContext, AuditEvent},
+    member::{
+        Member, MemberId, MemberRepository, MemberSearchCriteria, MemberStatus, UserRole,
+    },
+    unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument, warn};
+
+use crate::audit;
+
+/// 会员列表查询输入
+#[derive(Debug)]
+pub struct ListMembersInput {
+    pub criteria: MemberSearchCriteria,
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+}
+
+/// 会员列表查询输出
+#[derive(Debug)]
+pub struct ListMembersOutput {
+    pub members: Vec<Member>,
+    pub total_count: u64,
+}
+
+/// 变更会员状态输入
+#[derive(Debug)]
+pub struct ChangeMemberStatusInput {
+    pub admin_id: MemberId,
+    pub member_id: MemberId,
+    pub status: MemberStatus,
+    pub reason: String,
+    pub context: AuditContext,
+}
+
+/// 变更会员角色输入
+#[derive(Debug)]
+pub struct ChangeMemberRoleInput {
+    pub admin_id: MemberId,
+    pub member_id: MemberId,
+    pub role: UserRole,
+    pub reason: Option<String>,
+    pub context: AuditContext,
+}
+
+/// 会员列表查询（按邮箱/用户名、状态、角色筛选，不包含平台系统会员）
+#[instrument(
+    name = "list_members",
+    skip(member_repo),
+    fields(
+        keyword = %input.criteria.keyword,
+        status = ?input.criteria.status,
+        role = ?input.criteria.role
+    )
+)]
+pub async fn list_members(
+    member_repo: &dyn MemberRepository,
+    input: ListMembersInput,
+) -> Result<ListMembersOutput> {
+    let members = member_repo
+        .search_members(&input.criteria, input.limit, input.offset)
+        .await?;
+    let total_count = member_repo.count_members(&input.criteria).await?;
+
+    Ok(ListMembersOutput {
+        members,
+        total_count,
+    })
+}
+
+/// 查询会员详情
+#[instrument(name = "get_member", skip(member_repo))]
+pub async fn get_member(member_repo: &dyn MemberRepository, member_id: MemberId) -> Result<Member> {
+    member_repo
+        .find_by_id(member_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("会员不存在"))
+}
+
+/// 变更会员状态（激活、停用、封禁）
+///
+/// 状态变更会递增令牌版本，停用或封禁立即使访问令牌失效，并吊销全部刷新令牌。
+#[instrument(
+    name = "change_member_status",
+    skip(uow_factory, input),
+    fields(
+        admin_id = %input.admin_id,
+        member_id = %input.member_id,
+        status = %input.status
+    )
+)]
+pub async fn change_member_status(
+    uow_factory: &dyn UnitOfWorkFactory,
+    input: ChangeMemberStatusInput,
+) -> Result<Member> {
+    info!("开始变更会员状态");
+
+    let reason = input.reason.trim().to_string();
+    if reason.is_empty() {
+        return Err(AppError::validation("必须填写状态变更原因"));
+    }
+
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证管理员，且不能变更自己的状态
+    verify_admin(uow.as_ref(), input.admin_id).await?;
+    if input.member_id == input.admin_id {
+        return Err(AppError::validation("不能变更自己的账户状态"));
+    }
+
+    // 2. 验证目标会员
+    let mut member = uow
+        .members()
+        .find_by_id(input.member_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("会员不存在"))?;
+
+    if member.is_system_member() {
+        return Err(AppError::validation("不能变更平台系统账户的状态"));
+    }
+
+    if member.status == input.status {
+        return Err(AppError::validation(format!("会员状态已是 {}", input.status)));
+    }
+
+    // 3. 变更状态，停用或封禁时吊销全部刷新令牌，并记录审计日志
+    let before = member.clone();
+    match input.status {
+        MemberStatus::Active => member.activate(),
+        MemberStatus::Inactive => member.deactivate(),
+        MemberStatus::Banned => member.ban(),
+    }
+
+    uow.members().update(&member).await?;
+    if !member.is_active() {
+        let revoked = uow.refresh_tokens().revoke_all_for_member(&member.id).await?;
+        info!(member_id = %member.id, revoked, "已吊销会员的刷新令牌");
+    }
+
+    audit::record(
+        uow.as_ref(),
+        AuditEvent::member_access_changed(
+            &input.context,
+            input.admin_id,
+            AuditAction::MemberStatusChanged,
+            &before,
+            &member,
+        )?
+        .with_reason(Some(reason)),
+    )
+    .await?;
+    uow.commit().await?;
+
+    info!(
+        member_id = %member.id,
+        old_status = %before.status,
+        new_status = %member.status,
+        "会员状态变更成功"
+    );
+
+    Ok(member)
+}
+
+/// 变更会员角色（提升为管理员或降级为普通用户；决策者通过分配职业设置）
+#[instrument(
+    name = "change_member_role",
+    skip(uow_factory, input),
+    fields(
+        admin_id = %input.admin_id,
+        member_id = %input.member_id,
+        role = %input.role
+    )
+)]
+pub async fn change_member_role(
+    uow_factory: &dyn UnitOfWorkFactory,
+    input: ChangeMemberRoleInput,
+) -> Result<Member> {
+    info!("开始变更会员角色");
+
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证管理员，且不能变更自己的角色
+    verify_admin(uow.as_ref(), input.admin_id).await?;
+    if input.member_id == input.admin_id {
+        return Err(AppError::validation("不能变更自己的角色"));
+    }
+
+    // 2. 验证目标会员
+    let mut member = uow
+        .members()
+        .find_by_id(input.member_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("会员不存在"))?;
+
+    if member.is_system_member() {
+        return Err(AppError::validation("不能变更平台系统账户的角色"));
+    }
+
+    if member.role == input.role {
+        return Err(AppError::validation(format!("会员角色已是 {}", input.role)));
+    }
+
+    // 3. 变更角色并记录审计日志
+    let before = member.clone();
+    match input.role {
+        UserRole::Admin => {
+            if !member.is_active() {
+                return Err(AppError::validation("不能将未激活的会员提升为管理员"));
+            }
+            member.promote_to_admin();
+        }
+        UserRole::Regular => member.demote_to_regular(),
+        UserRole::Decider => {
+            return Err(AppError::validation("决策者角色请通过分配决策者职业设置"));
+        }
+    }
+
+    uow.members().update(&member).await?;
+    audit::record(
+        uow.as_ref(),
+        AuditEvent::member_access_changed(
+            &input.context,
+            input.admin_id,
+            AuditAction::MemberRoleChanged,
+            &before,
+            &member,
+        )?
+        .with_reason(input.reason),
+    )
+    .await?;
+    uow.commit().await?;
+
+    info!(
+        member_id = %member.id,
+        old_role = %before.role,
+        new_role = %member.role,
+        "会员角色变更成功"
+    );
+
+    Ok(member)
+}
+
+/// 验证操作者是已激活的管理员
+async fn verify_admin(uow: &dyn UnitOfWork, admin_id: MemberId) -> Result<()> {
+    let admin = uow
+        .members()
+        .find_by_id(admin_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("管理员不存在"))?;
+
+    if !admin.is_admin() || !admin.is_active() {
+        warn!(admin_id = %admin_id, role = %admin.role, "非管理员尝试管理会员");
+        return Err(AppError::forbidden("只有管理员可以管理会员"));
+    }
+
+    Ok(())
+}
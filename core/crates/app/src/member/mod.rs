@@ -2,6 +2,15 @@
 
 pub mod register;
 pub mod login;
+pub mod manage;
+pub mod reputation;
+pub mod session;
 
 pub use register::{register_member, RegisterInput};
 pub use login::{login_member, LoginInput, LoginOutput};
+pub use manage::{
+    change_member_role, change_member_status, get_member, list_members, ChangeMemberRoleInput,
+    ChangeMemberStatusInput, ListMembersInput, ListMembersOutput,
+};
+pub use reputation::recalculate_reputation;
+pub use session::{logout_member, refresh_session, RefreshSessionInput, RefreshSessionOutput};
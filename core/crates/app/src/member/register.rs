@@ -1,6 +1,11 @@
 //! 会员注册用例
 
-use domain::member::{Email, Member, MemberRepository, Password, Username};
+use domain::{
+    isu::{ISUAccount, ISUTransactionType, ISU},
+    member::{Email, Member, Password, Username},
+    unit_of_work::UnitOfWorkFactory,
+};
+use rust_decimal::Decimal;
 use shared::{AppError, Result};
 use tracing::instrument;
 
@@ -12,17 +17,22 @@ pub struct RegisterInput {
 }
 
 /// 注册会员
+///
+/// 会员、ISU账户和初始余额发放在同一个工作单元内完成，
+/// 初始余额以 `InitialBalance` 流水从平台金库转入。
 #[instrument(
     name = "register_member",
-    skip(repo, hasher, input),
+    skip(uow_factory, hasher, input),
     fields(
         email = %input.email,
-        username = %input.username
+        username = %input.username,
+        initial_balance = %initial_balance
     )
 )]
 pub async fn register_member(
-    repo: &dyn MemberRepository,
+    uow_factory: &dyn UnitOfWorkFactory,
     hasher: &dyn infra::PasswordHasher,
+    initial_balance: ISU,
     input: RegisterInput,
 ) -> Result<Member> {
     tracing::info!("开始注册会员");
@@ -32,26 +42,44 @@ pub async fn register_member(
     let username = Username::new(input.username)?;
     let password = Password::new(input.password)?;
 
+    // 哈希密码（耗时操作，放在开启事务之前）
+    let password_hash = hasher.hash(password.value())?;
+
+    let uow = uow_factory.begin().await?;
+
     // 检查邮箱是否已存在
-    if repo.find_by_email(&email).await?.is_some() {
+    if uow.members().find_by_email(&email).await?.is_some() {
         return Err(AppError::validation("邮箱已被注册"));
     }
 
     // 检查用户名是否已存在
-    if repo.find_by_username(&username).await?.is_some() {
+    if uow.members().find_by_username(&username).await?.is_some() {
         return Err(AppError::validation("用户名已被使用"));
     }
 
-    // 哈希密码
-    let password_hash = hasher.hash(password.value())?;
-
     // 创建会员
     let member = Member::new(email, username, password_hash);
+    uow.members().save(&member).await?;
 
-    // 保存到仓储
-    repo.save(&member).await?;
+    // 开设ISU账户
+    let account = ISUAccount::new(member.id, ISU::default());
+    uow.isu_accounts().save(&account).await?;
 
-    tracing::info!(member_id = %member.id, "会员注册成功");
+    // 从平台金库发放初始余额
+    if initial_balance.value() > Decimal::ZERO {
+        uow.isu_accounts()
+            .transfer(
+                &ISUAccount::treasury_id(),
+                &account.id,
+                &initial_balance,
+                ISUTransactionType::InitialBalance,
+                Some("新会员注册初始余额".to_string()),
+            )
+            .await?;
+    }
+
+    uow.commit().await?;
+
+    tracing::info!(member_id = %member.id, isu_account_id = %account.id, "会员注册成功");
     Ok(member)
 }
-
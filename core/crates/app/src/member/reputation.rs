@@ -0,0 +1,61 @@
+//! 会员信誉分计算
+
+use domain::{
+    dispute::DisputeRepository,
+    member::{MemberId, MemberRepository, ReputationStats},
+    transaction::{Transaction, TransactionRepository},
+    unit_of_work::UnitOfWork,
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+/// 按交易历史重新计算并保存会员的信誉分
+///
+/// 在交易事件（完成、取消、争议裁决、收到评价）写入之后调用，统计已包含本次事件。
+#[instrument(
+    name = "recalculate_reputation",
+    skip(member_repo, transaction_repo, dispute_repo),
+    fields(member_id = %member_id)
+)]
+pub async fn recalculate_reputation(
+    member_repo: &dyn MemberRepository,
+    transaction_repo: &dyn TransactionRepository,
+    dispute_repo: &dyn DisputeRepository,
+    member_id: MemberId,
+) -> Result<Decimal> {
+    let member = member_repo
+        .find_by_id(member_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("会员不存在"))?;
+
+    let stats = ReputationStats {
+        completed_transactions: transaction_repo
+            .count_completed_by_participant(&member_id)
+            .await?,
+        rating: member.rating,
+        disputes_lost: dispute_repo.count_lost_by(&member_id).await?,
+        cancellations: transaction_repo
+            .count_confirmed_cancelled_by(&member_id)
+            .await?,
+    };
+
+    let score = stats.score();
+    if score != member.reputation {
+        member_repo.update_reputation(member_id, score).await?;
+        info!(previous = %member.reputation, score = %score, "信誉分已更新");
+    }
+
+    Ok(score)
+}
+
+/// 在工作单元中重新计算交易双方的信誉分
+pub(crate) async fn recalculate_participants_reputation(
+    uow: &dyn UnitOfWork,
+    transaction: &Transaction,
+) -> Result<()> {
+    for member_id in [transaction.buyer_id, transaction.seller_id] {
+        recalculate_reputation(uow.members(), uow.transactions(), uow.disputes(), member_id).await?;
+    }
+    Ok(())
+}
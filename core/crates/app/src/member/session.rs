@@ -0,0 +1,201 @@
+//! 会话续期与登出用例
+
+use chrono::Duration;
+use domain::auth::RefreshTokenRepository;
+use domain::member::Member;
+use domain::unit_of_work::UnitOfWorkFactory;
+use infra::{generate_refresh_token, hash_refresh_token};
+use shared::{AppError, Result};
+use tracing::{info, instrument, warn};
+
+/// 刷新会话输入
+pub struct RefreshSessionInput {
+    pub refresh_token: String,
+    /// 新刷新令牌的有效期
+    pub refresh_token_ttl: Duration,
+}
+
+/// 刷新会话输出
+pub struct RefreshSessionOutput {
+    pub member: Member,
+    /// 轮换后的刷新令牌明文
+    pub refresh_token: String,
+}
+
+/// 用刷新令牌换取新的会话（轮换刷新令牌）
+///
+/// 锁定令牌、标记已使用和保存轮换后的令牌在同一事务中完成；
+/// 已使用或已吊销的刷新令牌再次出现视为泄露，吊销整个令牌家族。
+#[instrument(name = "refresh_session", skip(uow_factory, input))]
+pub async fn refresh_session(
+    uow_factory: &dyn UnitOfWorkFactory,
+    input: RefreshSessionInput,
+) -> Result<RefreshSessionOutput> {
+    let uow = uow_factory.begin().await?;
+
+    // 1. 查找并锁定刷新令牌，并发刷新同一令牌时后到的请求等待前一个提交
+    let token = uow
+        .refresh_tokens()
+        .find_by_hash_for_update(&hash_refresh_token(&input.refresh_token))
+        .await?
+        .ok_or(AppError::Unauthorized)?;
+
+    // 2. 重用检测：已轮换或已吊销的令牌再次出现，吊销整个家族
+    if token.is_spent() {
+        let revoked = uow.refresh_tokens().revoke_family(&token.family_id).await?;
+        uow.commit().await?;
+        warn!(
+            member_id = %token.member_id,
+            family_id = %token.family_id,
+            revoked,
+            "检测到刷新令牌重用，已吊销整个令牌家族"
+        );
+        return Err(AppError::Unauthorized);
+    }
+
+    if token.is_expired() {
+        return Err(AppError::Unauthorized);
+    }
+
+    // 3. 标记已使用（令牌已锁定，条件更新失败说明状态异常）
+    if !uow.refresh_tokens().mark_used(&token.id).await? {
+        return Err(AppError::Unauthorized);
+    }
+
+    // 4. 会员被停用或封禁时吊销其全部会话
+    let member = uow
+        .members()
+        .find_by_id(token.member_id)
+        .await?
+        .ok_or(AppError::Unauthorized)?;
+
+    if !member.is_active() {
+        uow.refresh_tokens().revoke_all_for_member(&member.id).await?;
+        uow.commit().await?;
+        return Err(AppError::forbidden("账号未激活或已被封禁"));
+    }
+
+    // 5. 签发同一家族的新刷新令牌，与标记已使用一起提交
+    let refresh_token = generate_refresh_token();
+    uow.refresh_tokens()
+        .save(&token.rotate(hash_refresh_token(&refresh_token), input.refresh_token_ttl))
+        .await?;
+    uow.commit().await?;
+
+    info!(member_id = %member.id, "会话已续期");
+
+    Ok(RefreshSessionOutput {
+        member,
+        refresh_token,
+    })
+}
+
+/// 登出：吊销刷新令牌所在的令牌家族（未知令牌直接忽略）
+#[instrument(name = "logout_member", skip(token_repo, refresh_token))]
+pub async fn logout_member(token_repo: &dyn RefreshTokenRepository, refresh_token: &str) -> Result<()> {
+    if let Some(token) = token_repo
+        .find_by_hash(&hash_refresh_token(refresh_token))
+        .await?
+    {
+        token_repo.revoke_family(&token.family_id).await?;
+        info!(member_id = %token.member_id, "会员已登出");
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::auth::RefreshToken;
+    use domain::member::{Email, MemberRepository, Username};
+    use infra::{PgPool, PostgresMemberRepository, PostgresRefreshTokenRepository, PostgresUnitOfWorkFactory};
+
+    /// 创建会员并签发一个刷新令牌，返回令牌明文
+    async fn issue_token(pool: &PgPool, ttl: Duration) -> (Member, String) {
+        let member = Member::new(
+            Email::new("session@example.com").unwrap(),
+            Username::new("session").unwrap(),
+            "hash".to_string(),
+        );
+        PostgresMemberRepository::new(pool.clone())
+            .save(&member)
+            .await
+            .unwrap();
+
+        let refresh_token = generate_refresh_token();
+        PostgresRefreshTokenRepository::new(pool.clone())
+            .save(&RefreshToken::issue(member.id, hash_refresh_token(&refresh_token), ttl))
+            .await
+            .unwrap();
+
+        (member, refresh_token)
+    }
+
+    async fn refresh(pool: &PgPool, refresh_token: &str) -> Result<RefreshSessionOutput> {
+        refresh_session(
+            &PostgresUnitOfWorkFactory::new(pool.clone()),
+            RefreshSessionInput {
+                refresh_token: refresh_token.to_string(),
+                refresh_token_ttl: Duration::days(7),
+            },
+        )
+        .await
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn refresh_rotates_the_token(pool: PgPool) {
+        let (member, original) = issue_token(&pool, Duration::days(7)).await;
+
+        let output = refresh(&pool, &original).await.unwrap();
+
+        assert_eq!(output.member.id, member.id);
+        assert_ne!(output.refresh_token, original);
+        assert!(refresh(&pool, &output.refresh_token).await.is_ok());
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn reusing_a_rotated_token_revokes_the_whole_family(pool: PgPool) {
+        let (_, original) = issue_token(&pool, Duration::days(7)).await;
+        let rotated = refresh(&pool, &original).await.unwrap().refresh_token;
+
+        // 旧令牌再次出现视为泄露
+        assert!(matches!(refresh(&pool, &original).await, Err(AppError::Unauthorized)));
+
+        // 同一家族中尚未使用的新令牌也随之失效
+        let repo = PostgresRefreshTokenRepository::new(pool.clone());
+        let token = repo.find_by_hash(&hash_refresh_token(&rotated)).await.unwrap().unwrap();
+        assert!(token.revoked_at.is_some());
+        assert!(matches!(refresh(&pool, &rotated).await, Err(AppError::Unauthorized)));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn expired_token_is_rejected(pool: PgPool) {
+        let (_, expired) = issue_token(&pool, Duration::seconds(-1)).await;
+
+        assert!(matches!(refresh(&pool, &expired).await, Err(AppError::Unauthorized)));
+
+        // 过期令牌不会被轮换
+        let repo = PostgresRefreshTokenRepository::new(pool.clone());
+        let token = repo.find_by_hash(&hash_refresh_token(&expired)).await.unwrap().unwrap();
+        assert!(token.used_at.is_none());
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn unknown_token_is_rejected(pool: PgPool) {
+        let unknown = generate_refresh_token();
+        assert!(matches!(refresh(&pool, &unknown).await, Err(AppError::Unauthorized)));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn logout_revokes_the_family(pool: PgPool) {
+        let (_, original) = issue_token(&pool, Duration::days(7)).await;
+        let rotated = refresh(&pool, &original).await.unwrap().refresh_token;
+
+        logout_member(&PostgresRefreshTokenRepository::new(pool.clone()), &rotated)
+            .await
+            .unwrap();
+
+        assert!(matches!(refresh(&pool, &rotated).await, Err(AppError::Unauthorized)));
+    }
+}
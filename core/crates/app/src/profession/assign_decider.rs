@@ -1,18 +1,24 @@
 //! 分配决策者权限用例
 
 use domain::{
-    member::{MemberRepository, MemberId},
+    audit::{AuditAction, AuditContext, AuditEvent},
+    member::MemberId,
     profession::ProfessionType,
+    unit_of_work::UnitOfWorkFactory,
 };
 use shared::{AppError, Result};
 use tracing::{info, instrument, warn};
 
+use crate::audit;
+
 /// 分配决策者权限输入
 #[derive(Debug)]
 pub struct AssignDeciderInput {
     pub admin_id: MemberId,
     pub target_member_id: MemberId,
     pub managed_professions: Vec<ProfessionType>,
+    pub reason: Option<String>,
+    pub context: AuditContext,
 }
 
 /// 分配决策者权限输出
@@ -26,7 +32,7 @@ pub struct AssignDeciderOutput {
 /// 分配决策者权限用例
 #[instrument(
     name = "assign_decider",
-    skip(member_repo),
+    skip(uow_factory),
     fields(
         admin_id = %input.admin_id,
         target_member_id = %input.target_member_id,
@@ -34,13 +40,16 @@ pub struct AssignDeciderOutput {
     )
 )]
 pub async fn execute(
-    member_repo: &impl MemberRepository,
+    uow_factory: &dyn UnitOfWorkFactory,
     input: AssignDeciderInput,
 ) -> Result<AssignDeciderOutput> {
     info!("开始分配决策者权限");
 
+    let uow = uow_factory.begin().await?;
+
     // 1. 验证管理员存在且是管理员角色
-    let admin = member_repo
+    let admin = uow
+        .members()
         .find_by_id(input.admin_id)
         .await?
         .ok_or_else(|| AppError::not_found("管理员不存在"))?;
@@ -59,7 +68,8 @@ pub async fn execute(
     }
 
     // 2. 验证目标用户存在且状态正常
-    let mut target_member = member_repo
+    let mut target_member = uow
+        .members()
         .find_by_id(input.target_member_id)
         .await?
         .ok_or_else(|| AppError::not_found("目标用户不存在"))?;
@@ -85,13 +95,38 @@ pub async fn execute(
         }
     }
     
+    // 6. 验证职业均存在于职业目录中
+    let mut display_names = Vec::with_capacity(input.managed_professions.len());
+    for profession_type in &input.managed_professions {
+        let profession = uow
+            .profession_catalog()
+            .find_profession(profession_type)
+            .await?
+            .ok_or_else(|| AppError::validation(format!("职业不存在: {}", profession_type)))?;
+        display_names.push(profession.display_name);
+    }
+
     let unique_professions = input.managed_professions.clone();
 
-    // 6. 提升为决策者
+    // 7. 提升为决策者
+    let before = target_member.clone();
     target_member.promote_to_decider(unique_professions.clone());
 
-    // 7. 保存更新
-    member_repo.update(&target_member).await?;
+    // 8. 保存更新并记录审计日志
+    uow.members().update(&target_member).await?;
+    audit::record(
+        uow.as_ref(),
+        AuditEvent::member_access_changed(
+            &input.context,
+            input.admin_id,
+            AuditAction::DeciderAssigned,
+            &before,
+            &target_member,
+        )?
+        .with_reason(input.reason),
+    )
+    .await?;
+    uow.commit().await?;
 
     info!(
         admin_id = %input.admin_id,
@@ -105,11 +140,7 @@ pub async fn execute(
         assigned_professions: unique_professions.clone(),
         message: format!(
             "用户已成功提升为决策者，可管理职业: {}",
-            unique_professions
-                .iter()
-                .map(|p| p.display_name())
-                .collect::<Vec<_>>()
-                .join(", ")
+            display_names.join(", ")
         ),
     })
 }
@@ -117,21 +148,26 @@ pub async fn execute(
 /// 撤销决策者权限用例
 #[instrument(
     name = "revoke_decider",
-    skip(member_repo),
+    skip(uow_factory, reason, context),
     fields(
         admin_id = %admin_id,
         target_member_id = %target_member_id
     )
 )]
 pub async fn revoke_decider(
-    member_repo: &impl MemberRepository,
+    uow_factory: &dyn UnitOfWorkFactory,
     admin_id: MemberId,
     target_member_id: MemberId,
+    reason: Option<String>,
+    context: &AuditContext,
 ) -> Result<String> {
     info!("开始撤销决策者权限");
 
+    let uow = uow_factory.begin().await?;
+
     // 1. 验证管理员权限
-    let admin = member_repo
+    let admin = uow
+        .members()
         .find_by_id(admin_id)
         .await?
         .ok_or_else(|| AppError::not_found("管理员不存在"))?;
@@ -141,7 +177,8 @@ pub async fn revoke_decider(
     }
 
     // 2. 验证目标用户存在
-    let mut target_member = member_repo
+    let mut target_member = uow
+        .members()
         .find_by_id(target_member_id)
         .await?
         .ok_or_else(|| AppError::not_found("目标用户不存在"))?;
@@ -151,10 +188,24 @@ pub async fn revoke_decider(
     }
 
     // 3. 降级为普通用户
+    let before = target_member.clone();
     target_member.demote_to_regular();
 
-    // 4. 保存更新
-    member_repo.update(&target_member).await?;
+    // 4. 保存更新并记录审计日志
+    uow.members().update(&target_member).await?;
+    audit::record(
+        uow.as_ref(),
+        AuditEvent::member_access_changed(
+            context,
+            admin_id,
+            AuditAction::DeciderRevoked,
+            &before,
+            &target_member,
+        )?
+        .with_reason(reason),
+    )
+    .await?;
+    uow.commit().await?;
 
     info!(
         admin_id = %admin_id,
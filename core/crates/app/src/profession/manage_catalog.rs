@@ -0,0 +1,261 @@
+//! 职业目录维护用例
+
+use domain::{
+    isu::ISURate,
+    member::{MemberId, MemberRepository},
+    profession::{
+        LocalizedNames, Profession, ProfessionCatalogRepository, ProfessionCategory, ProfessionType,
+    },
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+/// 新增职业输入
+#[derive(Debug)]
+pub struct CreateProfessionInput {
+    pub admin_id: MemberId,
+    pub code: ProfessionType,
+    pub display_name: String,
+    pub localized_names: LocalizedNames,
+    pub category_code: String,
+    pub default_rate: Decimal,
+}
+
+/// 更新职业输入（未提供的字段保持不变）
+#[derive(Debug)]
+pub struct UpdateProfessionInput {
+    pub admin_id: MemberId,
+    pub code: ProfessionType,
+    pub display_name: Option<String>,
+    pub localized_names: Option<LocalizedNames>,
+    pub category_code: Option<String>,
+    pub default_rate: Option<Decimal>,
+    pub is_active: Option<bool>,
+}
+
+/// 新增职业分类输入
+#[derive(Debug)]
+pub struct CreateCategoryInput {
+    pub admin_id: MemberId,
+    pub code: String,
+    pub display_name: String,
+    pub localized_names: LocalizedNames,
+}
+
+/// 更新职业分类输入（未提供的字段保持不变）
+#[derive(Debug)]
+pub struct UpdateCategoryInput {
+    pub admin_id: MemberId,
+    pub code: String,
+    pub display_name: Option<String>,
+    pub localized_names: Option<LocalizedNames>,
+}
+
+/// 列出职业目录
+#[instrument(name = "list_professions", skip(catalog_repo))]
+pub async fn list_professions(
+    catalog_repo: &dyn ProfessionCatalogRepository,
+    include_inactive: bool,
+) -> Result<Vec<Profession>> {
+    catalog_repo.list_professions(include_inactive).await
+}
+
+/// 列出职业分类
+#[instrument(name = "list_profession_categories", skip(catalog_repo))]
+pub async fn list_categories(
+    catalog_repo: &dyn ProfessionCatalogRepository,
+) -> Result<Vec<ProfessionCategory>> {
+    catalog_repo.list_categories().await
+}
+
+/// 新增职业（仅管理员）
+#[instrument(
+    name = "create_profession",
+    skip(member_repo, catalog_repo),
+    fields(admin_id = %input.admin_id, code = %input.code)
+)]
+pub async fn create_profession(
+    member_repo: &dyn MemberRepository,
+    catalog_repo: &dyn ProfessionCatalogRepository,
+    input: CreateProfessionInput,
+) -> Result<Profession> {
+    info!("开始新增职业");
+
+    // 1. 验证操作者是管理员
+    ensure_admin(member_repo, input.admin_id).await?;
+
+    // 2. 验证编码未被占用、分类存在
+    if catalog_repo.find_profession(&input.code).await?.is_some() {
+        return Err(AppError::validation(format!("职业编码已存在: {}", input.code)));
+    }
+    ensure_category_exists(catalog_repo, &input.category_code).await?;
+
+    // 3. 创建并保存职业
+    let profession = Profession::new(
+        input.code,
+        input.display_name,
+        input.localized_names,
+        input.category_code,
+        parse_rate(input.default_rate)?,
+    )?;
+    catalog_repo.create_profession(&profession).await?;
+
+    info!(code = %profession.code, "职业新增成功");
+
+    Ok(profession)
+}
+
+/// 更新职业：重命名、本地化、调整分类和默认费率、启用/停用（仅管理员）
+#[instrument(
+    name = "update_profession",
+    skip(member_repo, catalog_repo),
+    fields(admin_id = %input.admin_id, code = %input.code)
+)]
+pub async fn update_profession(
+    member_repo: &dyn MemberRepository,
+    catalog_repo: &dyn ProfessionCatalogRepository,
+    input: UpdateProfessionInput,
+) -> Result<Profession> {
+    info!("开始更新职业");
+
+    // 1. 验证操作者是管理员
+    ensure_admin(member_repo, input.admin_id).await?;
+
+    // 2. 查找职业
+    let mut profession = catalog_repo
+        .find_profession(&input.code)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("职业不存在: {}", input.code)))?;
+
+    // 3. 应用变更
+    if let Some(display_name) = input.display_name {
+        profession.rename(&display_name)?;
+    }
+    if let Some(localized_names) = input.localized_names {
+        profession.set_localized_names(localized_names)?;
+    }
+    if let Some(category_code) = input.category_code {
+        ensure_category_exists(catalog_repo, &category_code).await?;
+        profession.move_to_category(category_code)?;
+    }
+    if let Some(default_rate) = input.default_rate {
+        profession.update_default_rate(parse_rate(default_rate)?);
+    }
+    match input.is_active {
+        Some(true) => profession.activate(),
+        Some(false) => profession.deactivate(),
+        None => {}
+    }
+
+    // 4. 保存
+    catalog_repo.update_profession(&profession).await?;
+
+    info!(code = %profession.code, is_active = profession.is_active, "职业更新成功");
+
+    Ok(profession)
+}
+
+/// 新增职业分类（仅管理员）
+#[instrument(
+    name = "create_profession_category",
+    skip(member_repo, catalog_repo),
+    fields(admin_id = %input.admin_id, code = %input.code)
+)]
+pub async fn create_category(
+    member_repo: &dyn MemberRepository,
+    catalog_repo: &dyn ProfessionCatalogRepository,
+    input: CreateCategoryInput,
+) -> Result<ProfessionCategory> {
+    info!("开始新增职业分类");
+
+    // 1. 验证操作者是管理员
+    ensure_admin(member_repo, input.admin_id).await?;
+
+    // 2. 验证编码未被占用
+    if catalog_repo.find_category(&input.code).await?.is_some() {
+        return Err(AppError::validation(format!("职业分类编码已存在: {}", input.code)));
+    }
+
+    // 3. 创建并保存分类
+    let category = ProfessionCategory::new(input.code, input.display_name, input.localized_names)?;
+    catalog_repo.create_category(&category).await?;
+
+    info!(code = %category.code, "职业分类新增成功");
+
+    Ok(category)
+}
+
+/// 更新职业分类：重命名、本地化（仅管理员）
+#[instrument(
+    name = "update_profession_category",
+    skip(member_repo, catalog_repo),
+    fields(admin_id = %input.admin_id, code = %input.code)
+)]
+pub async fn update_category(
+    member_repo: &dyn MemberRepository,
+    catalog_repo: &dyn ProfessionCatalogRepository,
+    input: UpdateCategoryInput,
+) -> Result<ProfessionCategory> {
+    info!("开始更新职业分类");
+
+    // 1. 验证操作者是管理员
+    ensure_admin(member_repo, input.admin_id).await?;
+
+    // 2. 查找分类
+    let mut category = catalog_repo
+        .find_category(&input.code)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("职业分类不存在: {}", input.code)))?;
+
+    // 3. 应用变更并保存
+    if let Some(display_name) = input.display_name {
+        category.rename(&display_name)?;
+    }
+    if let Some(localized_names) = input.localized_names {
+        category.set_localized_names(localized_names)?;
+    }
+    catalog_repo.update_category(&category).await?;
+
+    info!(code = %category.code, "职业分类更新成功");
+
+    Ok(category)
+}
+
+/// 验证操作者是处于激活状态的管理员
+async fn ensure_admin(member_repo: &dyn MemberRepository, admin_id: MemberId) -> Result<()> {
+    let admin = member_repo
+        .find_by_id(admin_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("管理员不存在"))?;
+
+    if !admin.is_admin() {
+        return Err(AppError::forbidden("只有管理员可以维护职业目录"));
+    }
+
+    if !admin.is_active() {
+        return Err(AppError::validation("管理员账户未激活"));
+    }
+
+    Ok(())
+}
+
+/// 验证职业分类存在
+async fn ensure_category_exists(
+    catalog_repo: &dyn ProfessionCatalogRepository,
+    category_code: &str,
+) -> Result<()> {
+    catalog_repo
+        .find_category(category_code)
+        .await?
+        .map(|_| ())
+        .ok_or_else(|| AppError::validation(format!("职业分类不存在: {}", category_code)))
+}
+
+/// 校验默认费率
+fn parse_rate(rate: Decimal) -> Result<ISURate> {
+    if rate <= Decimal::ZERO {
+        return Err(AppError::validation("默认费率必须大于0"));
+    }
+    ISURate::new(rate)
+}
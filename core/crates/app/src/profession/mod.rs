@@ -1,8 +1,14 @@
 //! 职业标准管理相关用例
 
 pub mod assign_decider;
+pub mod manage_catalog;
 pub mod update_profession_rate;
 
 // 重导出
 pub use assign_decider::{execute as assign_decider, revoke_decider, AssignDeciderInput, AssignDeciderOutput};
+pub use manage_catalog::{
+    create_category, create_profession, list_categories, list_professions, update_category,
+    update_profession, CreateCategoryInput, CreateProfessionInput, UpdateCategoryInput,
+    UpdateProfessionInput,
+};
 pub use update_profession_rate::{execute as update_profession_rate, UpdateProfessionRateInput, UpdateProfessionRateOutput};
\ No newline at end of file
@@ -2,8 +2,9 @@
 
 use domain::{
     isu::ISURate,
-    member::{MemberRepository, MemberId},
-    profession::{ProfessionStandardEntity, ProfessionStandardHistory, ProfessionStandardRepository, ProfessionType, StandardAction},
+    member::MemberId,
+    profession::{ProfessionStandardEntity, ProfessionStandardHistory, ProfessionType, StandardAction},
+    unit_of_work::UnitOfWorkFactory,
 };
 use rust_decimal::Decimal;
 use shared::{AppError, Result};
@@ -31,7 +32,7 @@ pub struct UpdateProfessionRateOutput {
 /// 更新职业标准费率用例
 #[instrument(
     name = "update_profession_rate",
-    skip(member_repo, profession_repo),
+    skip(uow_factory),
     fields(
         requester_id = %input.requester_id,
         profession_type = %input.profession_type,
@@ -39,14 +40,16 @@ pub struct UpdateProfessionRateOutput {
     )
 )]
 pub async fn execute(
-    member_repo: &impl MemberRepository,
-    profession_repo: &impl ProfessionStandardRepository,
+    uow_factory: &dyn UnitOfWorkFactory,
     input: UpdateProfessionRateInput,
 ) -> Result<UpdateProfessionRateOutput> {
     info!("开始更新职业标准费率");
 
+    let uow = uow_factory.begin().await?;
+
     // 1. 验证请求者存在且状态正常
-    let requester = member_repo
+    let requester = uow
+        .members()
         .find_by_id(input.requester_id)
         .await?
         .ok_or_else(|| AppError::not_found("请求者不存在"))?;
@@ -66,7 +69,14 @@ pub async fn execute(
         return Err(AppError::forbidden("您没有权限管理此职业标准"));
     }
 
-    // 3. 验证新费率有效性
+    // 3. 验证职业存在于职业目录中
+    let profession = uow
+        .profession_catalog()
+        .find_profession(&input.profession_type)
+        .await?
+        .ok_or_else(|| AppError::not_found(format!("职业不存在: {}", input.profession_type)))?;
+
+    // 4. 验证新费率有效性
     if input.new_rate <= Decimal::ZERO {
         return Err(AppError::validation("费率必须大于0"));
     }
@@ -75,15 +85,17 @@ pub async fn execute(
         AppError::validation("无效的费率数值")
     })?)?;
 
-    // 4. 查找当前活跃的职业标准
-    let current_standard = profession_repo
-        .find_active_by_profession(input.profession_type)
+    // 5. 查找当前活跃的职业标准
+    let current_standard = uow
+        .professions()
+        .find_active_by_profession(&input.profession_type)
         .await?;
 
-    let (standard_entity, old_rate) = if let Some(mut standard) = current_standard {
+    // 6. 保存标准并记录变更历史（同一工作单元内完成，避免出现未审计的费率变更）
+    let old_rate = if let Some(mut standard) = current_standard {
         // 更新现有标准
         let old_rate = standard.isu_rate;
-        
+
         // 检查是否有实际变化
         if old_rate.value() == new_isu_rate.value() {
             return Err(AppError::validation("新费率与当前费率相同"));
@@ -95,37 +107,27 @@ pub async fn execute(
             input.requester_id,
         )?;
 
-        profession_repo.update(&standard).await?;
-        
-        (standard, old_rate)
+        let history = rate_history(&standard, Some(old_rate), new_isu_rate, &input);
+        uow.professions().update_with_history(&standard, &history).await?;
+
+        old_rate
     } else {
-        // 创建新的标准（如果不存在）
-        let default_rate = input.profession_type.default_rate()?;
+        // 创建新的标准（如果不存在），变更前的费率即职业目录中的默认费率
+        let default_rate = profession.default_rate;
         let standard = ProfessionStandardEntity::new(
-            input.profession_type,
+            input.profession_type.clone(),
             new_isu_rate,
             format!("初始设定：{}", input.reason),
             input.requester_id,
         );
 
-        profession_repo.save(&standard).await?;
-        
-        (standard, default_rate)
-    };
+        let history = rate_history(&standard, Some(default_rate), new_isu_rate, &input);
+        uow.professions().save_with_history(&standard, &history).await?;
 
-    // 5. 记录变更历史
-    let history = ProfessionStandardHistory {
-        id: Uuid::new_v4().to_string(),
-        standard_id: standard_entity.id,
-        action: StandardAction::RateUpdated,
-        old_rate: Some(old_rate),
-        new_rate: Some(new_isu_rate),
-        reason: input.reason.clone(),
-        changed_by: input.requester_id,
-        created_at: chrono::Utc::now(),
+        default_rate
     };
 
-    profession_repo.save_history(&history).await?;
+    uow.commit().await?;
 
     info!(
         profession_type = %input.profession_type,
@@ -141,9 +143,28 @@ pub async fn execute(
         new_rate: new_isu_rate,
         message: format!(
             "{}的标准费率已从 {} 更新为 {}",
-            input.profession_type.display_name(),
+            profession.display_name,
             old_rate,
             new_isu_rate
         ),
     })
-}
\ No newline at end of file
+}
+
+/// 构建费率变更历史记录
+fn rate_history(
+    standard: &ProfessionStandardEntity,
+    old_rate: Option<ISURate>,
+    new_rate: ISURate,
+    input: &UpdateProfessionRateInput,
+) -> ProfessionStandardHistory {
+    ProfessionStandardHistory {
+        id: Uuid::new_v4().to_string(),
+        standard_id: standard.id,
+        action: StandardAction::RateUpdated,
+        old_rate,
+        new_rate: Some(new_rate),
+        reason: input.reason.clone(),
+        changed_by: input.requester_id,
+        created_at: chrono::Utc::now(),
+    }
+}
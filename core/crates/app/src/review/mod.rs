@@ -0,0 +1,8 @@
+//! 交易评价相关用例
+
+pub mod queries;
+pub mod submit_review;
+
+// 重导出
+pub use queries::{list_member_reviews, list_transaction_reviews, MemberReviews};
+pub use submit_review::{execute as submit_review, SubmitReviewInput};
@@ -0,0 +1,77 @@
+//! 评价查询用例
+
+use domain::{
+    member::{Member, MemberId, MemberRepository},
+    review::{Review, ReviewRepository},
+    transaction::{TransactionId, TransactionRepository},
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+/// 会员收到的评价（含评分汇总）
+#[derive(Debug)]
+pub struct MemberReviews {
+    pub member: Member,
+    pub reviews: Vec<Review>,
+}
+
+/// 列出会员收到的评价（按时间倒序分页）
+#[instrument(
+    name = "list_member_reviews",
+    skip(member_repo, review_repo),
+    fields(member_id = %member_id)
+)]
+pub async fn list_member_reviews(
+    member_repo: &dyn MemberRepository,
+    review_repo: &dyn ReviewRepository,
+    member_id: MemberId,
+    page: i64,
+    page_size: i64,
+) -> Result<MemberReviews> {
+    info!("列出会员收到的评价");
+
+    if page < 1 || page_size < 1 {
+        return Err(AppError::validation("页码和每页数量必须大于0"));
+    }
+
+    let member = member_repo
+        .find_by_id(member_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("会员不存在"))?;
+
+    let limit = u32::try_from(page_size).map_err(|_| AppError::validation("每页数量无效"))?;
+    let offset = u32::try_from((page - 1) * page_size)
+        .map_err(|_| AppError::validation("页码无效"))?;
+
+    let reviews = review_repo
+        .find_by_reviewee(&member_id, Some(limit), Some(offset))
+        .await?;
+
+    Ok(MemberReviews { member, reviews })
+}
+
+/// 列出交易的评价（仅交易参与者可见）
+#[instrument(
+    name = "list_transaction_reviews",
+    skip(transaction_repo, review_repo),
+    fields(transaction_id = %transaction_id, requester_id = %requester_id)
+)]
+pub async fn list_transaction_reviews(
+    transaction_repo: &dyn TransactionRepository,
+    review_repo: &dyn ReviewRepository,
+    transaction_id: TransactionId,
+    requester_id: MemberId,
+) -> Result<Vec<Review>> {
+    info!("列出交易的评价");
+
+    let transaction = transaction_repo
+        .find_by_id(&transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    if !transaction.is_participant(&requester_id) {
+        return Err(AppError::forbidden("无权查看此交易的评价"));
+    }
+
+    review_repo.find_by_transaction_id(&transaction_id).await
+}
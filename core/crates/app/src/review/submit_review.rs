@@ -0,0 +1,200 @@
+//! 提交交易评价用例
+
+use domain::{
+    member::MemberId,
+    review::{Rating, Review},
+    transaction::TransactionId,
+    unit_of_work::UnitOfWorkFactory,
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+use crate::member::recalculate_reputation;
+
+/// 提交评价输入
+#[derive(Debug)]
+pub struct SubmitReviewInput {
+    pub transaction_id: TransactionId,
+    pub reviewer_id: MemberId, // 买家或卖家
+    pub rating: u8,
+    pub comment: Option<String>,
+}
+
+/// 提交交易评价用例
+///
+/// 交易完成后买卖双方各可评价对方一次，评价提交后不可修改。
+/// 评价、评分汇总和被评价者的信誉分在同一事务中写入。
+#[instrument(
+    name = "submit_review",
+    skip(uow_factory, input),
+    fields(
+        transaction_id = %input.transaction_id,
+        reviewer_id = %input.reviewer_id,
+        rating = input.rating
+    )
+)]
+pub async fn execute(uow_factory: &dyn UnitOfWorkFactory, input: SubmitReviewInput) -> Result<Review> {
+    info!("开始提交评价");
+
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证交易存在
+    let transaction = uow
+        .transactions()
+        .find_by_id(&input.transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    // 2. 创建评价（验证交易已完成、评价者是交易参与者）
+    let rating = Rating::new(input.rating)?;
+    let review = Review::new(&transaction, input.reviewer_id, rating, input.comment)?;
+
+    // 3. 每方只能评价一次（并发提交由唯一约束兜底，同样报告为冲突）
+    if uow
+        .reviews()
+        .find_by_transaction_and_reviewer(&transaction.id, &input.reviewer_id)
+        .await?
+        .is_some()
+    {
+        return Err(AppError::conflict("您已评价过该交易"));
+    }
+
+    // 4. 保存评价（同步累加评分汇总）
+    uow.reviews().save(&review).await?;
+
+    // 5. 重新计算被评价者的信誉分
+    recalculate_reputation(uow.members(), uow.transactions(), uow.disputes(), review.reviewee_id).await?;
+    uow.commit().await?;
+
+    info!(
+        review_id = %review.id,
+        reviewee_id = %review.reviewee_id,
+        "评价已提交"
+    );
+    Ok(review)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use domain::isu::ISU;
+    use domain::member::{Email, Member, MemberRepository, Username};
+    use domain::tool::ToolId;
+    use domain::transaction::{Transaction, TransactionItemType, TransactionRepository};
+    use rust_decimal::Decimal;
+    use infra::{PgPool, PostgresMemberRepository, PostgresTransactionRepository, PostgresUnitOfWorkFactory};
+
+    async fn member(pool: &PgPool, name: &str) -> Member {
+        let member = Member::new(
+            Email::new(format!("{}@example.com", name)).unwrap(),
+            Username::new(name).unwrap(),
+            "hash".to_string(),
+        );
+        PostgresMemberRepository::new(pool.clone())
+            .save(&member)
+            .await
+            .unwrap();
+        member
+    }
+
+    /// 保存一笔买家与卖家之间的交易，`completed` 为真时推进到已完成
+    async fn transaction(pool: &PgPool, completed: bool) -> Transaction {
+        let buyer = member(pool, "buyer").await;
+        let seller = member(pool, "seller").await;
+        let mut transaction = Transaction::new(
+            buyer.id,
+            seller.id,
+            TransactionItemType::Tool(ToolId::new()),
+            ISU::new(Decimal::new(20, 0)).unwrap(),
+            None,
+        )
+        .unwrap();
+        if completed {
+            transaction.confirm().unwrap();
+            transaction.start().unwrap();
+            transaction.complete().unwrap();
+        }
+        PostgresTransactionRepository::new(pool.clone())
+            .save(&transaction)
+            .await
+            .unwrap();
+        transaction
+    }
+
+    async fn review(pool: &PgPool, transaction: &Transaction, reviewer_id: MemberId, rating: u8) -> Result<Review> {
+        execute(
+            &PostgresUnitOfWorkFactory::new(pool.clone()),
+            SubmitReviewInput {
+                transaction_id: transaction.id,
+                reviewer_id,
+                rating,
+                comment: None,
+            },
+        )
+        .await
+    }
+
+    async fn rating_of(pool: &PgPool, member_id: MemberId) -> domain::review::RatingSummary {
+        PostgresMemberRepository::new(pool.clone())
+            .find_by_id(member_id)
+            .await
+            .unwrap()
+            .unwrap()
+            .rating
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn each_side_reviews_the_other_once(pool: PgPool) {
+        let transaction = transaction(&pool, true).await;
+
+        review(&pool, &transaction, transaction.buyer_id, 5).await.unwrap();
+        review(&pool, &transaction, transaction.seller_id, 4).await.unwrap();
+
+        let seller_rating = rating_of(&pool, transaction.seller_id).await;
+        let buyer_rating = rating_of(&pool, transaction.buyer_id).await;
+        assert_eq!((seller_rating.count, seller_rating.total), (1, 5));
+        assert_eq!((buyer_rating.count, buyer_rating.total), (1, 4));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn second_review_from_the_same_side_is_a_conflict(pool: PgPool) {
+        let transaction = transaction(&pool, true).await;
+        review(&pool, &transaction, transaction.buyer_id, 5).await.unwrap();
+
+        let result = review(&pool, &transaction, transaction.buyer_id, 1).await;
+
+        assert!(matches!(result, Err(AppError::Conflict(_))));
+        let seller_rating = rating_of(&pool, transaction.seller_id).await;
+        assert_eq!((seller_rating.count, seller_rating.total), (1, 5));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn unfinished_transactions_cannot_be_reviewed(pool: PgPool) {
+        let transaction = transaction(&pool, false).await;
+
+        let result = review(&pool, &transaction, transaction.buyer_id, 5).await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn outsiders_cannot_review(pool: PgPool) {
+        let transaction = transaction(&pool, true).await;
+        let outsider = member(&pool, "outsider").await;
+
+        let result = review(&pool, &transaction, outsider.id, 5).await;
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+        assert_eq!(rating_of(&pool, transaction.seller_id).await.count, 0);
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn out_of_range_rating_is_rejected(pool: PgPool) {
+        let transaction = transaction(&pool, true).await;
+
+        for rating in [0, 6] {
+            let result = review(&pool, &transaction, transaction.buyer_id, rating).await;
+            assert!(matches!(result, Err(AppError::Validation(_))));
+        }
+    }
+}
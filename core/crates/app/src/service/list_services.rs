@@ -1,10 +1,12 @@
 //! 服务列表查询用例
 
 use domain::{
+    member::MemberId,
     profession::ProfessionType,
-    service::{Service, ServiceRepository},
+    service::{Service, ServiceId, ServiceRepository, ServiceSearchCriteria},
 };
-use shared::Result;
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
 use tracing::{info, instrument};
 
 /// 服务列表查询输入
@@ -33,7 +35,7 @@ pub struct ListServicesOutput {
     )
 )]
 pub async fn list_services(
-    service_repo: &impl ServiceRepository,
+    service_repo: &dyn ServiceRepository,
     input: ListServicesInput,
 ) -> Result<ListServicesOutput> {
     info!("开始查询服务列表");
@@ -41,7 +43,7 @@ pub async fn list_services(
     let limit = input.limit.unwrap_or(20); // 默认20条
     let offset = input.offset.unwrap_or(0);
 
-    let services = match input.profession_type {
+    let services = match &input.profession_type {
         Some(profession_type) => {
             service_repo
                 .find_available_by_profession(profession_type, Some(limit), Some(offset))
@@ -73,31 +75,63 @@ pub async fn list_services(
     name = "search_services",
     skip(service_repo),
     fields(
-        keyword = %keyword,
-        profession_type = ?profession_type
+        keyword = %criteria.keyword,
+        profession_type = ?criteria.profession_type,
+        min_provider_reputation = ?criteria.min_provider_reputation,
+        sort = %criteria.sort
     )
 )]
 pub async fn search_services(
-    service_repo: &impl ServiceRepository,
-    keyword: String,
-    profession_type: Option<ProfessionType>,
+    service_repo: &dyn ServiceRepository,
+    criteria: ServiceSearchCriteria,
     limit: Option<u32>,
     offset: Option<u32>,
 ) -> Result<Vec<Service>> {
     info!("开始搜索服务");
 
+    if criteria
+        .min_provider_reputation
+        .is_some_and(|min| min < Decimal::ZERO || min > Decimal::ONE_HUNDRED)
+    {
+        return Err(AppError::validation("最低信誉分必须在0到100之间"));
+    }
+
     let limit = limit.unwrap_or(20);
     let offset = offset.unwrap_or(0);
 
     let services = service_repo
-        .search_services(&keyword, profession_type, Some(limit), Some(offset))
+        .search_services(&criteria, Some(limit), Some(offset))
         .await?;
 
     info!(
-        keyword = %keyword,
+        keyword = %criteria.keyword,
         results_count = services.len(),
         "服务搜索完成"
     );
 
     Ok(services)
-}
\ No newline at end of file
+}
+
+/// 获取服务详情
+#[instrument(name = "get_service", skip(service_repo), fields(service_id = %service_id))]
+pub async fn get_service(
+    service_repo: &dyn ServiceRepository,
+    service_id: ServiceId,
+) -> Result<Service> {
+    info!("获取服务详情");
+
+    service_repo
+        .find_by_id(&service_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("服务不存在"))
+}
+
+/// 列出提供者发布的服务
+#[instrument(name = "list_services_by_provider", skip(service_repo), fields(provider_id = %provider_id))]
+pub async fn list_services_by_provider(
+    service_repo: &dyn ServiceRepository,
+    provider_id: MemberId,
+) -> Result<Vec<Service>> {
+    info!("列出提供者的服务");
+    service_repo.find_by_provider_id(&provider_id).await
+}
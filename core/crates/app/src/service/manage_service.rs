@@ -0,0 +1,115 @@
+//! 服务管理用例（更新、取消）
+
+use domain::{
+    member::MemberId,
+    service::{Service, ServiceId, ServiceRepository},
+    transaction::TransactionItemType,
+    unit_of_work::UnitOfWorkFactory,
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+/// 更新服务输入
+#[derive(Debug)]
+pub struct UpdateServiceInput {
+    pub service_id: ServiceId,
+    pub requester_id: MemberId,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub estimated_hours: Option<Decimal>,
+}
+
+/// 更新服务用例
+#[instrument(
+    name = "update_service",
+    skip(service_repo, input),
+    fields(
+        service_id = %input.service_id,
+        requester_id = %input.requester_id
+    )
+)]
+pub async fn update_service(
+    service_repo: &dyn ServiceRepository,
+    input: UpdateServiceInput,
+) -> Result<Service> {
+    info!("开始更新服务");
+
+    // 1. 验证服务存在且属于请求者
+    let mut service = find_owned_service(service_repo, input.service_id, input.requester_id).await?;
+
+    // 2. 只有可用状态的服务才能修改，避免影响进行中的交易
+    if !service.is_available() {
+        return Err(AppError::validation("只有可用状态的服务才能修改"));
+    }
+
+    // 3. 更新服务信息
+    service.update(input.title, input.description, input.estimated_hours)?;
+
+    // 4. 保存服务
+    service_repo.save(&service).await?;
+
+    info!(service_id = %service.id, total_isu = %service.total_isu, "服务更新成功");
+    Ok(service)
+}
+
+/// 取消服务用例
+///
+/// 只有可用状态且没有未结束交易的服务才能取消，避免托管中的 ISU 失去对应的服务。
+#[instrument(
+    name = "cancel_service",
+    skip(uow_factory),
+    fields(service_id = %service_id, requester_id = %requester_id)
+)]
+pub async fn cancel_service(
+    uow_factory: &dyn UnitOfWorkFactory,
+    service_id: ServiceId,
+    requester_id: MemberId,
+) -> Result<Service> {
+    info!("开始取消服务");
+
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证服务存在且属于请求者
+    let mut service = find_owned_service(uow.services(), service_id, requester_id).await?;
+
+    // 2. 存在未结束的交易时不能取消
+    let open_transactions = uow
+        .transactions()
+        .count_open_by_item(&TransactionItemType::Service(service_id))
+        .await?;
+    if open_transactions > 0 {
+        return Err(AppError::validation(format!(
+            "服务还有 {} 笔未结束的交易，无法取消",
+            open_transactions
+        )));
+    }
+
+    // 3. 取消服务
+    service.cancel()?;
+
+    // 4. 保存服务
+    uow.services().save(&service).await?;
+    uow.commit().await?;
+
+    info!(service_id = %service.id, "服务取消成功");
+    Ok(service)
+}
+
+// 辅助函数：获取服务并检查提供者权限
+async fn find_owned_service(
+    service_repo: &dyn ServiceRepository,
+    service_id: ServiceId,
+    requester_id: MemberId,
+) -> Result<Service> {
+    let service = service_repo
+        .find_by_id(&service_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("服务不存在"))?;
+
+    if !service.is_owned_by(&requester_id) {
+        return Err(AppError::forbidden("只有服务提供者可以执行此操作"));
+    }
+
+    Ok(service)
+}
@@ -1,8 +1,13 @@
 //! 服务相关用例
 
 pub mod list_services;
+pub mod manage_service;
 pub mod publish_service;
 
 // 重导出
-pub use list_services::{list_services, search_services, ListServicesInput, ListServicesOutput};
-pub use publish_service::{execute as publish_service, PublishServiceInput, PublishServiceOutput};
\ No newline at end of file
+pub use list_services::{
+    get_service, list_services, list_services_by_provider, search_services, ListServicesInput,
+    ListServicesOutput,
+};
+pub use manage_service::{cancel_service, update_service, UpdateServiceInput};
+pub use publish_service::{execute as publish_service, PublishServiceInput, PublishServiceOutput};
@@ -2,7 +2,9 @@
 
 use domain::{
     member::{MemberRepository, MemberId},
-    profession::ProfessionType,
+    profession::{
+        PricingService, ProfessionCatalogRepository, ProfessionStandardRepository, ProfessionType,
+    },
     service::{Service, ServiceRepository},
 };
 use rust_decimal::Decimal;
@@ -29,7 +31,7 @@ pub struct PublishServiceOutput {
 /// 发布服务用例
 #[instrument(
     name = "publish_service",
-    skip(member_repo, service_repo),
+    skip(member_repo, service_repo, catalog_repo, profession_repo),
     fields(
         provider_id = %input.provider_id,
         profession_type = %input.profession_type,
@@ -37,8 +39,10 @@ pub struct PublishServiceOutput {
     )
 )]
 pub async fn execute(
-    member_repo: &impl MemberRepository,
-    service_repo: &impl ServiceRepository,
+    member_repo: &dyn MemberRepository,
+    service_repo: &dyn ServiceRepository,
+    catalog_repo: &dyn ProfessionCatalogRepository,
+    profession_repo: &dyn ProfessionStandardRepository,
     input: PublishServiceInput,
 ) -> Result<PublishServiceOutput> {
     info!("开始发布服务");
@@ -53,23 +57,30 @@ pub async fn execute(
         return Err(AppError::validation("提供者账户未激活"));
     }
 
-    // 2. 创建服务实体
+    // 2. 按职业目录和职业标准获取当前费率（职业必须存在且处于启用状态）
+    let isu_rate = PricingService::new(catalog_repo, profession_repo)
+        .current_rate(&input.profession_type)
+        .await?;
+
+    // 3. 创建服务实体（费率作为快照保存）
     let service = Service::new(
         input.provider_id,
         input.profession_type,
         input.title,
         input.description,
         input.estimated_hours,
+        isu_rate,
     )?;
 
     let service_id = service.id;
     let total_isu = service.total_isu;
 
-    // 3. 保存服务
+    // 4. 保存服务
     service_repo.save(&service).await?;
 
     info!(
         service_id = %service_id,
+        isu_rate = %isu_rate,
         total_isu = %total_isu,
         "服务发布成功"
     );
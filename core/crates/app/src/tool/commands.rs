@@ -1,12 +1,19 @@
 //! 工具命令（写操作）
 
 use domain::{
+    audit::{AuditAction, AuditContext, AuditEvent, AuditTargetType},
+    isu::ISU,
     member::MemberId,
-    tool::{Currency, Money, Tool, ToolId, ToolRepository},
+    tool::{Currency, Money, ReservationKind, Tool, ToolId, ToolRepository},
+    transaction::TransactionItemType,
+    unit_of_work::UnitOfWorkFactory,
 };
+use rust_decimal::Decimal;
 use shared::{AppError, Result};
 use tracing::instrument;
 
+use crate::audit;
+
 /// 创建工具输入
 pub struct CreateToolInput {
     pub owner_id: MemberId,
@@ -15,6 +22,8 @@ pub struct CreateToolInput {
     pub category: String,
     pub price_amount: i64,
     pub price_currency: String,
+    pub daily_rate: Option<Decimal>, // 每日租金（ISU），为空表示不出租
+    pub deposit: Option<Decimal>,    // 可退还押金（ISU），默认无需押金
 }
 
 /// 创建工具
@@ -34,7 +43,7 @@ pub async fn create_tool(repo: &dyn ToolRepository, input: CreateToolInput) -> R
     let price = Money::new(input.price_amount, currency)?;
 
     // 创建工具
-    let tool = Tool::new(
+    let mut tool = Tool::new(
         input.owner_id,
         input.name,
         input.description,
@@ -42,6 +51,11 @@ pub async fn create_tool(repo: &dyn ToolRepository, input: CreateToolInput) -> R
         price,
     );
 
+    // 设置租用条款
+    if input.daily_rate.is_some() || input.deposit.is_some() {
+        apply_rental_terms(&mut tool, input.daily_rate, input.deposit)?;
+    }
+
     // 保存到仓储
     repo.save(&tool).await?;
 
@@ -58,6 +72,8 @@ pub struct UpdateToolInput {
     pub category: Option<String>,
     pub price_amount: Option<i64>,
     pub price_currency: Option<String>,
+    pub daily_rate: Option<Decimal>,
+    pub deposit: Option<Decimal>,
 }
 
 /// 更新工具
@@ -80,7 +96,7 @@ pub async fn update_tool(repo: &dyn ToolRepository, input: UpdateToolInput) -> R
 
     // 检查权限：只有所有者可以更新
     if tool.owner_id != input.requester_id {
-        return Err(AppError::Forbidden);
+        return Err(AppError::forbidden("只有工具所有者可以执行此操作"));
     }
 
     // 构建价格值对象（如果提供）
@@ -94,6 +110,11 @@ pub async fn update_tool(repo: &dyn ToolRepository, input: UpdateToolInput) -> R
     // 更新工具
     tool.update(input.name, input.description, input.category, price);
 
+    // 更新租用条款（未提供的部分保持不变，已创建的租用按下单时的快照结算）
+    if input.daily_rate.is_some() || input.deposit.is_some() {
+        apply_rental_terms(&mut tool, input.daily_rate, input.deposit)?;
+    }
+
     // 保存更新
     repo.update(&tool).await?;
 
@@ -101,44 +122,89 @@ pub async fn update_tool(repo: &dyn ToolRepository, input: UpdateToolInput) -> R
     Ok(tool)
 }
 
-/// 删除工具
+/// 删除工具（软删除，删除前的工具信息记入审计日志）
+///
+/// 存在未结束的租用交易时拒绝删除，所有者的封锁预订随之释放。
 #[instrument(
     name = "delete_tool",
-    skip(repo),
+    skip(uow_factory, context),
     fields(tool_id = %tool_id, requester_id = %requester_id)
 )]
 pub async fn delete_tool(
-    repo: &dyn ToolRepository,
+    uow_factory: &dyn UnitOfWorkFactory,
     tool_id: ToolId,
     requester_id: MemberId,
+    context: &AuditContext,
 ) -> Result<()> {
     tracing::info!("开始删除工具");
 
-    // 检查权限并获取工具
-    let _tool = check_owner(repo, tool_id, requester_id).await?;
-
-    // 删除工具
-    repo.delete(tool_id).await?;
+    let uow = uow_factory.begin().await?;
 
-    tracing::info!(tool_id = %tool_id, "工具删除成功");
-    Ok(())
-}
-
-// 辅助函数：检查工具所有者权限
-async fn check_owner(
-    repo: &dyn ToolRepository,
-    tool_id: ToolId,
-    requester_id: MemberId,
-) -> Result<Tool> {
-    let tool = repo
-        .find_by_id(tool_id)
+    // 1. 锁定工具并检查权限（与预订、租用串行化）
+    let tool = uow
+        .tools()
+        .find_by_id_for_update(tool_id)
         .await?
         .ok_or_else(|| AppError::not_found("工具不存在"))?;
 
     if tool.owner_id != requester_id {
-        return Err(AppError::Forbidden);
+        return Err(AppError::forbidden("只有工具所有者可以执行此操作"));
     }
 
-    Ok(tool)
+    // 2. 存在未结束的租用时不能删除（押金和租金仍在托管中）
+    let open_rentals = uow
+        .transactions()
+        .count_open_by_item(&TransactionItemType::Tool(tool_id))
+        .await?;
+    if open_rentals > 0 {
+        return Err(AppError::validation(format!(
+            "工具还有 {} 笔未结束的租用交易，无法删除",
+            open_rentals
+        )));
+    }
+
+    // 3. 释放所有者的封锁预订；仍有租用预订时不能删除
+    for mut reservation in uow.tool_reservations().find_active_by_tool(tool_id).await? {
+        if reservation.kind == ReservationKind::Rental {
+            return Err(AppError::validation("工具还有未释放的租用预订，无法删除"));
+        }
+        reservation.release()?;
+        uow.tool_reservations().update(&reservation).await?;
+    }
+
+    // 4. 软删除工具并记录审计事件
+    uow.tools().delete(tool_id).await?;
+    audit::record(
+        uow.as_ref(),
+        AuditEvent::new(
+            context,
+            requester_id,
+            AuditAction::ToolDeleted,
+            AuditTargetType::Tool,
+            tool.id,
+        )
+        .with_before(&tool)?,
+    )
+    .await?;
+    uow.commit().await?;
+
+    tracing::info!(tool_id = %tool_id, "工具删除成功");
+    Ok(())
 }
 
+// 辅助函数：应用租用条款，未提供的部分沿用工具现有条款
+fn apply_rental_terms(
+    tool: &mut Tool,
+    daily_rate: Option<Decimal>,
+    deposit: Option<Decimal>,
+) -> Result<()> {
+    let daily_rate = match daily_rate {
+        Some(rate) => Some(ISU::new(rate)?),
+        None => tool.daily_rate,
+    };
+    let deposit = match deposit {
+        Some(deposit) => ISU::new(deposit)?,
+        None => tool.deposit,
+    };
+    tool.set_rental_terms(daily_rate, deposit)
+}
@@ -2,6 +2,7 @@
 
 pub mod commands;
 pub mod queries;
+pub mod reservations;
 
 // 导出命令
 pub use commands::{
@@ -9,4 +10,9 @@ pub use commands::{
 };
 
 // 导出查询
-pub use queries::{count_tools, get_tool, list_available_tools, list_tools_by_owner};
\ No newline at end of file
+pub use queries::{count_tools, get_tool, list_available_tools, list_tools_by_owner};
+
+// 导出预订
+pub use reservations::{
+    block_tool_dates, get_tool_availability, unblock_tool_dates, BlockToolDatesInput,
+};
@@ -0,0 +1,134 @@
+//! 工具预订（可用性日历）用例
+
+use chrono::{DateTime, Duration, Utc};
+use domain::{
+    member::MemberId,
+    tool::{
+        ReservationId, ReservationKind, ReservationWindow, ToolAvailability, ToolId,
+        ToolRepository, ToolReservation, ToolReservationRepository, MAX_AVAILABILITY_DAYS,
+    },
+};
+use shared::{AppError, Result};
+use tracing::instrument;
+
+/// 未指定结束时间时，可用性查询默认跨度（天）
+const DEFAULT_AVAILABILITY_DAYS: i64 = 30;
+
+/// 封锁日期输入
+pub struct BlockToolDatesInput {
+    pub tool_id: ToolId,
+    pub owner_id: MemberId,
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+    pub note: Option<String>,
+}
+
+/// 所有者封锁日期（封锁期间不接受租用预订）
+#[instrument(
+    name = "block_tool_dates",
+    skip(tool_repo, reservation_repo, input),
+    fields(
+        tool_id = %input.tool_id,
+        owner_id = %input.owner_id,
+        starts_at = %input.starts_at,
+        ends_at = %input.ends_at
+    )
+)]
+pub async fn block_tool_dates(
+    tool_repo: &dyn ToolRepository,
+    reservation_repo: &dyn ToolReservationRepository,
+    input: BlockToolDatesInput,
+) -> Result<ToolReservation> {
+    tracing::info!("开始封锁工具日期");
+
+    let window = ReservationWindow::new(input.starts_at, input.ends_at)?;
+    if window.ends_at <= Utc::now() {
+        return Err(AppError::validation("不能封锁已经过去的时间段"));
+    }
+
+    let tool = tool_repo
+        .find_by_id(input.tool_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("工具不存在"))?;
+
+    if !tool.is_owned_by(&input.owner_id) {
+        return Err(AppError::forbidden("只有工具所有者可以封锁日期"));
+    }
+
+    // 与已有预订或封锁重叠时由数据库排他约束拒绝
+    let reservation = ToolReservation::block(tool.id, input.owner_id, window, input.note);
+    reservation_repo.save(&reservation).await?;
+
+    tracing::info!(reservation_id = %reservation.id, "工具日期已封锁");
+    Ok(reservation)
+}
+
+/// 所有者取消封锁
+#[instrument(
+    name = "unblock_tool_dates",
+    skip(reservation_repo),
+    fields(tool_id = %tool_id, reservation_id = %reservation_id, owner_id = %owner_id)
+)]
+pub async fn unblock_tool_dates(
+    reservation_repo: &dyn ToolReservationRepository,
+    tool_id: ToolId,
+    reservation_id: ReservationId,
+    owner_id: MemberId,
+) -> Result<()> {
+    tracing::info!("开始取消封锁");
+
+    let mut reservation = reservation_repo
+        .find_by_id(reservation_id)
+        .await?
+        .filter(|r| r.tool_id == tool_id && r.kind == ReservationKind::Block)
+        .ok_or_else(|| AppError::not_found("封锁记录不存在"))?;
+
+    if reservation.created_by != owner_id {
+        return Err(AppError::forbidden("只有工具所有者可以取消封锁"));
+    }
+
+    reservation.release()?;
+    reservation_repo.update(&reservation).await?;
+
+    tracing::info!("封锁已取消");
+    Ok(())
+}
+
+/// 查询工具在时间窗内的可用性
+///
+/// 未指定开始时间时从当前时间开始，未指定结束时间时查询之后 30 天。
+#[instrument(
+    name = "get_tool_availability",
+    skip(tool_repo, reservation_repo),
+    fields(tool_id = %tool_id)
+)]
+pub async fn get_tool_availability(
+    tool_repo: &dyn ToolRepository,
+    reservation_repo: &dyn ToolReservationRepository,
+    tool_id: ToolId,
+    from: Option<DateTime<Utc>>,
+    to: Option<DateTime<Utc>>,
+) -> Result<ToolAvailability> {
+    tracing::info!("查询工具可用性");
+
+    let starts_at = from.unwrap_or_else(Utc::now);
+    let ends_at = to.unwrap_or(starts_at + Duration::days(DEFAULT_AVAILABILITY_DAYS));
+    let window = ReservationWindow::new(starts_at, ends_at)?;
+    if window.duration() > Duration::days(MAX_AVAILABILITY_DAYS) {
+        return Err(AppError::validation(format!(
+            "单次查询跨度不能超过{}天",
+            MAX_AVAILABILITY_DAYS
+        )));
+    }
+
+    let tool = tool_repo
+        .find_by_id(tool_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("工具不存在"))?;
+
+    let reservations = reservation_repo
+        .find_active_overlapping(tool.id, &window)
+        .await?;
+
+    Ok(ToolAvailability::new(tool.id, window, reservations))
+}
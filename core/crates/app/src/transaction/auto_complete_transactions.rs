@@ -0,0 +1,85 @@
+//! 进行中交易自动完成用例
+
+use chrono::{DateTime, Utc};
+use domain::{
+    transaction::{Transaction, TransactionId},
+    unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+};
+use shared::Result;
+use tracing::{info, instrument, warn};
+
+use super::complete_transaction::settle_completion;
+use crate::member::reputation::recalculate_participants_reputation;
+
+/// 交易自动完成输入
+#[derive(Debug)]
+pub struct AutoCompleteTransactionsInput {
+    /// 开始时间早于此时间仍在进行中的服务交易将自动完成
+    pub cutoff: DateTime<Utc>,
+    /// 单次运行最多处理的交易数
+    pub batch_size: usize,
+}
+
+/// 交易自动完成输出
+#[derive(Debug, Default)]
+pub struct AutoCompleteTransactionsOutput {
+    pub completed: usize,
+    pub failed: usize,
+}
+
+/// 进行中交易自动完成用例
+///
+/// 买家在宽限期内既未完成也未发起争议时，视为服务已完成，托管款项放款给卖家。
+/// 争议中的交易不处理；工具租用需由所有者登记归还，也不自动完成。
+/// 认领与幂等方式同交易过期任务。
+#[instrument(name = "auto_complete_transactions", skip(uow_factory), fields(cutoff = %input.cutoff))]
+pub async fn execute(
+    uow_factory: &dyn UnitOfWorkFactory,
+    input: AutoCompleteTransactionsInput,
+) -> Result<AutoCompleteTransactionsOutput> {
+    let mut output = AutoCompleteTransactionsOutput::default();
+    // 本次运行中处理失败的交易，不再重复认领
+    let mut failed: Vec<TransactionId> = Vec::new();
+
+    while output.completed + output.failed < input.batch_size {
+        // 1. 认领一笔超过宽限期的进行中服务交易
+        let uow = uow_factory.begin().await?;
+        let Some(mut transaction) = uow
+            .transactions()
+            .claim_in_progress_services_started_before(input.cutoff, &failed)
+            .await?
+        else {
+            break;
+        };
+
+        // 2. 结算并提交，失败时回滚并跳过该交易
+        let result = match complete(uow.as_ref(), &mut transaction).await {
+            Ok(()) => uow.commit().await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(()) => {
+                output.completed += 1;
+                info!(transaction_id = %transaction.id, "交易超过宽限期，已自动完成");
+            }
+            Err(e) => {
+                output.failed += 1;
+                failed.push(transaction.id);
+                warn!(transaction_id = %transaction.id, error = %e, "交易自动完成失败");
+            }
+        }
+    }
+
+    if output.completed > 0 || output.failed > 0 {
+        info!(completed = output.completed, failed = output.failed, "交易自动完成任务完成");
+    }
+
+    Ok(output)
+}
+
+async fn complete(uow: &dyn UnitOfWork, transaction: &mut Transaction) -> Result<()> {
+    settle_completion(uow, transaction).await?;
+    uow.transactions().update(transaction).await?;
+    recalculate_participants_reputation(uow, transaction).await
+}
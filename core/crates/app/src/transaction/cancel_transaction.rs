@@ -0,0 +1,189 @@
+//! 取消交易用例
+
+use chrono::Utc;
+use domain::{
+    isu::{ISUTransactionType, ISU},
+    member::MemberId,
+    tool::{DepositStatus, ReservationWindow},
+    transaction::{CancellationPolicy, CancellingParty, Transaction, TransactionId},
+    unit_of_work::UnitOfWorkFactory,
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+use crate::member::reputation::recalculate_participants_reputation;
+
+/// 取消交易输入
+#[derive(Debug)]
+pub struct CancelTransactionInput {
+    pub transaction_id: TransactionId,
+    pub requester_id: MemberId, // 买家或卖家都可以取消
+}
+
+/// 取消交易输出
+#[derive(Debug)]
+pub struct CancelTransactionOutput {
+    pub transaction: Transaction,
+    /// 退还买家的交易款项
+    pub refund: ISU,
+    /// 收取的取消费（转给卖家）
+    pub cancellation_fee: ISU,
+    pub message: String,
+}
+
+/// 取消交易用例
+///
+/// 只有待确认和已确认（尚未开始）的交易可以取消。已冻结的交易款项按取消费政策结算：
+/// 取消费转给卖家，其余退还买家，各自产生独立的ISU流水；押金全额退还。
+/// 服务在交易开始时才进入进行中，取消尚未开始的交易后服务仍保持可用。
+#[instrument(
+    name = "cancel_transaction",
+    skip(uow_factory, policy),
+    fields(
+        transaction_id = %input.transaction_id,
+        requester_id = %input.requester_id
+    )
+)]
+pub async fn execute(
+    uow_factory: &dyn UnitOfWorkFactory,
+    policy: &CancellationPolicy,
+    input: CancelTransactionInput,
+) -> Result<CancelTransactionOutput> {
+    info!("开始取消交易");
+
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证交易存在（锁定交易行）
+    let mut transaction = uow
+        .transactions()
+        .find_by_id_for_update(&input.transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    // 2. 验证操作者是交易参与者
+    if !transaction.is_participant(&input.requester_id) {
+        return Err(AppError::forbidden("只有交易参与者可以取消交易"));
+    }
+
+    // 3. 取消交易
+    transaction.cancel(input.requester_id)?;
+
+    // 4. 按取消方和取消时间计算取消费（工具租用以租用期开始为约定开始时间）
+    let mut rental = uow.tool_rentals().find_by_transaction_id(&transaction.id).await?;
+    let party = if transaction.is_buyer(&input.requester_id) {
+        CancellingParty::Buyer
+    } else {
+        CancellingParty::Seller
+    };
+    let scheduled_start = rental
+        .as_ref()
+        .map(|rental| ReservationWindow::from_rental_period(&rental.period).starts_at);
+    let settlement = policy.assess(&transaction, party, scheduled_start, Utc::now())?;
+    transaction.take_escrow();
+
+    // 5. 结算冻结的款项，退还押金
+    let deposit_refund = match rental.as_mut() {
+        Some(rental) if rental.deposit_status == DepositStatus::Held => {
+            rental.refund_deposit()?;
+            Some(rental.deposit)
+        }
+        _ => None,
+    };
+
+    let buyer_isu_account = uow
+        .isu_accounts()
+        .find_by_owner_id(&transaction.buyer_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("买家ISU账户不存在"))?;
+
+    if !settlement.refund.value().is_zero() {
+        uow.isu_accounts()
+            .release(
+                &buyer_isu_account.id,
+                &buyer_isu_account.id,
+                &settlement.refund,
+                ISUTransactionType::CancellationRefund,
+                Some(format!("交易取消退款 - {}", transaction.id.value())),
+            )
+            .await?;
+
+        info!(refund = %settlement.refund, "冻结款项已退还买家");
+    }
+
+    if !settlement.fee.value().is_zero() {
+        let seller_isu_account = uow
+            .isu_accounts()
+            .find_by_owner_id(&transaction.seller_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("卖家ISU账户不存在"))?;
+
+        uow.isu_accounts()
+            .release(
+                &buyer_isu_account.id,
+                &seller_isu_account.id,
+                &settlement.fee,
+                ISUTransactionType::CancellationFee,
+                Some(format!("交易取消费 - {}", transaction.id.value())),
+            )
+            .await?;
+
+        info!(fee = %settlement.fee, fee_rate = %settlement.fee_rate, "取消费已转给卖家");
+    }
+
+    if let Some(deposit) = deposit_refund {
+        uow.isu_accounts()
+            .release(
+                &buyer_isu_account.id,
+                &buyer_isu_account.id,
+                &deposit,
+                ISUTransactionType::DepositRefund,
+                Some(format!("租用取消押金退还 - {}", transaction.id.value())),
+            )
+            .await?;
+
+        info!(deposit = %deposit, "冻结押金已退还");
+    }
+
+    if let Some(rental) = &rental {
+        uow.tool_rentals().update(rental).await?;
+    }
+
+    // 6. 工具租用释放预订的租用期
+    if let Some(mut reservation) = uow
+        .tool_reservations()
+        .find_by_transaction_id(&transaction.id)
+        .await?
+    {
+        if reservation.is_active() {
+            reservation.release()?;
+            uow.tool_reservations().update(&reservation).await?;
+        }
+    }
+
+    // 7. 保存，重新计算双方信誉分并提交
+    uow.transactions().update(&transaction).await?;
+    recalculate_participants_reputation(uow.as_ref(), &transaction).await?;
+    uow.commit().await?;
+
+    info!(
+        transaction_id = %input.transaction_id,
+        cancelled_by = %input.requester_id,
+        "交易已取消"
+    );
+
+    let message = if settlement.fee.value().is_zero() {
+        "交易已取消，冻结款项已全额退还".to_string()
+    } else {
+        format!(
+            "交易已取消，收取取消费 {}，其余 {} 已退还",
+            settlement.fee, settlement.refund
+        )
+    };
+
+    Ok(CancelTransactionOutput {
+        transaction,
+        refund: settlement.refund,
+        cancellation_fee: settlement.fee,
+        message,
+    })
+}
@@ -1,13 +1,16 @@
 //! 完成交易用例
 
 use domain::{
-    member::{MemberRepository, MemberId},
-    service::ServiceRepository,
-    transaction::{TransactionRepository, TransactionId},
+    isu::ISUTransactionType,
+    member::MemberId,
+    transaction::{Transaction, TransactionId, TransactionItemType},
+    unit_of_work::{UnitOfWork, UnitOfWorkFactory},
 };
 use shared::{AppError, Result};
 use tracing::{info, instrument};
 
+use crate::member::reputation::recalculate_participants_reputation;
+
 /// 完成交易输入
 #[derive(Debug)]
 pub struct CompleteTransactionInput {
@@ -25,23 +28,24 @@ pub struct CompleteTransactionOutput {
 /// 完成交易用例
 #[instrument(
     name = "complete_transaction",
-    skip(member_repo, service_repo, transaction_repo),
+    skip(uow_factory),
     fields(
         transaction_id = %input.transaction_id,
         requester_id = %input.requester_id
     )
 )]
 pub async fn execute(
-    member_repo: &impl MemberRepository,
-    service_repo: &impl ServiceRepository,
-    transaction_repo: &impl TransactionRepository,
+    uow_factory: &dyn UnitOfWorkFactory,
     input: CompleteTransactionInput,
 ) -> Result<CompleteTransactionOutput> {
     info!("开始完成交易");
 
-    // 1. 验证交易存在
-    let mut transaction = transaction_repo
-        .find_by_id(&input.transaction_id)
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证交易存在（锁定交易行）
+    let mut transaction = uow
+        .transactions()
+        .find_by_id_for_update(&input.transaction_id)
         .await?
         .ok_or_else(|| AppError::not_found("交易不存在"))?;
 
@@ -51,7 +55,8 @@ pub async fn execute(
     }
 
     // 3. 验证操作者存在且状态正常
-    let requester = member_repo
+    let requester = uow
+        .members()
         .find_by_id(input.requester_id)
         .await?
         .ok_or_else(|| AppError::not_found("操作者不存在"))?;
@@ -60,20 +65,20 @@ pub async fn execute(
         return Err(AppError::validation("操作者账户未激活"));
     }
 
-    // 4. 完成交易
-    transaction.complete()?;
-
-    // 5. 更新相关服务状态为已完成
-    if let domain::transaction::TransactionItemType::Service(service_id) = &transaction.item_type {
-        if let Some(mut service) = service_repo.find_by_id(service_id).await? {
-            service.complete()?;
-            service_repo.save(&service).await?;
-            info!(service_id = %service_id, "服务状态已更新为完成");
-        }
+    // 4. 工具租用需由所有者登记归还后完成
+    if let TransactionItemType::Tool(_) = &transaction.item_type {
+        return Err(AppError::validation("工具租用请由工具所有者登记归还"));
     }
 
-    // 6. 保存交易状态更新
-    transaction_repo.update(&transaction).await?;
+    // 5. 完成交易：托管款项放款给卖家，服务标记为已完成
+    settle_completion(uow.as_ref(), &mut transaction).await?;
+
+    // 6. 保存交易状态更新，重新计算双方信誉分
+    uow.transactions().update(&transaction).await?;
+    recalculate_participants_reputation(uow.as_ref(), &transaction).await?;
+
+    // 7. 提交工作单元
+    uow.commit().await?;
 
     info!(
         transaction_id = %input.transaction_id,
@@ -92,4 +97,53 @@ pub async fn execute(
         transaction_id: input.transaction_id,
         message,
     })
-}
\ No newline at end of file
+}
+
+/// 完成服务交易：交易标记为完成，托管款项放款给卖家，服务标记为已完成
+///
+/// 调用方负责锁定并保存交易。手动完成和后台自动完成共用此结算流程。
+pub(crate) async fn settle_completion(uow: &dyn UnitOfWork, transaction: &mut Transaction) -> Result<()> {
+    transaction.complete()?;
+
+    let escrowed = transaction.take_escrow();
+    if !escrowed.value().is_zero() {
+        let buyer_isu_account = uow
+            .isu_accounts()
+            .find_by_owner_id(&transaction.buyer_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("买家ISU账户不存在"))?;
+
+        let seller_isu_account = uow
+            .isu_accounts()
+            .find_by_owner_id(&transaction.seller_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("卖家ISU账户不存在"))?;
+
+        let payment = uow
+            .isu_accounts()
+            .release(
+                &buyer_isu_account.id,
+                &seller_isu_account.id,
+                &escrowed,
+                ISUTransactionType::ServicePayment,
+                Some(format!("服务交易完成放款 - {}", transaction.id.value())),
+            )
+            .await?;
+
+        info!(
+            isu_transaction_id = payment.id,
+            isu_amount = %escrowed,
+            "托管款项已放款给卖家"
+        );
+    }
+
+    if let TransactionItemType::Service(service_id) = &transaction.item_type {
+        if let Some(mut service) = uow.services().find_by_id(service_id).await? {
+            service.complete()?;
+            uow.services().save(&service).await?;
+            info!(service_id = %service_id, "服务状态已更新为完成");
+        }
+    }
+
+    Ok(())
+}
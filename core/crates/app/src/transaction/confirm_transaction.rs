@@ -1,10 +1,10 @@
 //! 确认交易用例
 
 use domain::{
-    isu::{ISUAccountRepository, ISUTransactionType},
-    member::{MemberRepository, MemberId},
-    service::ServiceRepository,
-    transaction::{TransactionRepository, TransactionId},
+    isu::ISUTransactionType,
+    member::MemberId,
+    transaction::{TransactionId, TransactionItemType},
+    unit_of_work::UnitOfWorkFactory,
 };
 use shared::{AppError, Result};
 use tracing::{info, instrument};
@@ -23,27 +23,27 @@ pub struct ConfirmTransactionOutput {
     pub message: String,
 }
 
-/// 确认交易用例（卖家确认 + ISU转移）
+/// 确认交易用例（卖家接单 + 冻结买家款项）
 #[instrument(
     name = "confirm_transaction",
-    skip(member_repo, service_repo, isu_repo, transaction_repo),
+    skip(uow_factory),
     fields(
         transaction_id = %input.transaction_id,
         seller_id = %input.seller_id
     )
 )]
 pub async fn execute(
-    member_repo: &impl MemberRepository,
-    service_repo: &impl ServiceRepository,
-    isu_repo: &impl ISUAccountRepository,
-    transaction_repo: &impl TransactionRepository,
+    uow_factory: &dyn UnitOfWorkFactory,
     input: ConfirmTransactionInput,
 ) -> Result<ConfirmTransactionOutput> {
     info!("开始确认交易");
 
-    // 1. 验证交易存在
-    let mut transaction = transaction_repo
-        .find_by_id(&input.transaction_id)
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证交易存在（锁定交易行，防止重复确认）
+    let mut transaction = uow
+        .transactions()
+        .find_by_id_for_update(&input.transaction_id)
         .await?
         .ok_or_else(|| AppError::not_found("交易不存在"))?;
 
@@ -53,7 +53,8 @@ pub async fn execute(
     }
 
     // 3. 验证卖家存在且状态正常
-    let seller = member_repo
+    let seller = uow
+        .members()
         .find_by_id(input.seller_id)
         .await?
         .ok_or_else(|| AppError::not_found("卖家不存在"))?;
@@ -65,52 +66,75 @@ pub async fn execute(
     // 4. 确认交易状态
     transaction.confirm()?;
 
-    // 5. 执行ISU转移（买家 → 卖家）
-    let buyer_isu_account = isu_repo
+    // 5. 检查买家余额（工具租用还需覆盖押金）
+    let buyer_isu_account = uow
+        .isu_accounts()
         .find_by_owner_id(&transaction.buyer_id)
         .await?
         .ok_or_else(|| AppError::not_found("买家ISU账户不存在"))?;
 
-    let seller_isu_account = isu_repo
-        .find_by_owner_id(&transaction.seller_id)
-        .await?
-        .ok_or_else(|| AppError::not_found("卖家ISU账户不存在"))?;
-
-    // 检查买家余额（再次确认）
-    if !buyer_isu_account.has_sufficient_balance(&transaction.isu_amount) {
+    let mut rental = match &transaction.item_type {
+        TransactionItemType::Service(_) => None,
+        TransactionItemType::Tool(_) => Some(
+            uow.tool_rentals()
+                .find_by_transaction_id(&transaction.id)
+                .await?
+                .ok_or_else(|| AppError::not_found("租用记录不存在"))?,
+        ),
+    };
+
+    let required = match &rental {
+        Some(rental) => rental.total_due()?,
+        None => transaction.isu_amount,
+    };
+
+    if !buyer_isu_account.has_sufficient_balance(&required) {
         return Err(AppError::validation("买家ISU余额不足"));
     }
 
-    // 执行ISU转移
-    let isu_transaction = isu_repo
-        .transfer(
-            &buyer_isu_account.id,
-            &seller_isu_account.id,
-            &transaction.isu_amount,
-            ISUTransactionType::ServicePayment,
-            Some(format!("服务交易确认 - {}", transaction.id.value())),
-        )
-        .await?;
-
-    info!(
-        isu_transaction_id = isu_transaction.id,
-        isu_amount = %transaction.isu_amount,
-        "ISU转移成功"
-    );
-
-    // 6. 自动开始交易
-    transaction.start()?;
+    // 6. 冻结交易款项：买家ISU转入平台托管账户，交易完成后放款给卖家
+    if !transaction.isu_amount.value().is_zero() {
+        let hold = uow
+            .isu_accounts()
+            .hold(
+                &buyer_isu_account.id,
+                &transaction.isu_amount,
+                ISUTransactionType::EscrowHold,
+                Some(format!("交易款项冻结 - {}", transaction.id.value())),
+            )
+            .await?;
+        transaction.hold_escrow(transaction.isu_amount)?;
+
+        info!(
+            isu_transaction_id = hold.id,
+            isu_amount = %transaction.isu_amount,
+            "交易款项已冻结"
+        );
+    }
 
-    // 7. 更新相关服务状态为进行中
-    if let domain::transaction::TransactionItemType::Service(service_id) = &transaction.item_type {
-        if let Some(mut service) = service_repo.find_by_id(service_id).await? {
-            service.start()?;
-            service_repo.save(&service).await?;
+    // 7. 工具租用同时冻结押金
+    if let Some(rental) = rental.as_mut() {
+        if rental.requires_deposit() {
+            uow.isu_accounts()
+                .hold(
+                    &buyer_isu_account.id,
+                    &rental.deposit,
+                    ISUTransactionType::DepositHold,
+                    Some(format!("工具押金冻结 - {}", transaction.id.value())),
+                )
+                .await?;
+            rental.hold_deposit()?;
+            uow.tool_rentals().update(rental).await?;
+
+            info!(deposit = %rental.deposit, "押金已冻结");
         }
     }
 
     // 8. 保存交易状态更新
-    transaction_repo.update(&transaction).await?;
+    uow.transactions().update(&transaction).await?;
+
+    // 9. 提交工作单元
+    uow.commit().await?;
 
     info!(
         transaction_id = %input.transaction_id,
@@ -120,6 +144,6 @@ pub async fn execute(
 
     Ok(ConfirmTransactionOutput {
         transaction_id: input.transaction_id,
-        message: "交易已确认并开始进行，ISU已转移".to_string(),
+        message: "交易已确认，买家款项已冻结，等待卖家开始".to_string(),
     })
 }
\ No newline at end of file
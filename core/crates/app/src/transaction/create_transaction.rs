@@ -1,10 +1,11 @@
 //! 创建交易用例
 
 use domain::{
-    isu::{ISU, ISUAccountRepository},
-    member::{MemberRepository, MemberId},
-    service::{ServiceRepository, ServiceId},
-    transaction::{Transaction, TransactionItemType, TransactionRepository},
+    isu::ISU,
+    member::MemberId,
+    service::ServiceId,
+    transaction::{Transaction, TransactionItemType},
+    unit_of_work::UnitOfWorkFactory,
 };
 use shared::{AppError, Result};
 use tracing::{info, instrument, warn};
@@ -28,23 +29,23 @@ pub struct CreateTransactionOutput {
 /// 创建交易用例
 #[instrument(
     name = "create_transaction",
-    skip(member_repo, service_repo, isu_repo, transaction_repo),
+    skip(uow_factory),
     fields(
         buyer_id = %input.buyer_id,
         service_id = %input.service_id
     )
 )]
 pub async fn execute(
-    member_repo: &impl MemberRepository,
-    service_repo: &impl ServiceRepository,
-    isu_repo: &impl ISUAccountRepository,
-    transaction_repo: &impl TransactionRepository,
+    uow_factory: &dyn UnitOfWorkFactory,
     input: CreateTransactionInput,
 ) -> Result<CreateTransactionOutput> {
     info!("开始创建交易");
 
+    let uow = uow_factory.begin().await?;
+
     // 1. 验证买家存在且状态正常
-    let buyer = member_repo
+    let buyer = uow
+        .members()
         .find_by_id(input.buyer_id)
         .await?
         .ok_or_else(|| AppError::not_found("买家不存在"))?;
@@ -54,7 +55,8 @@ pub async fn execute(
     }
 
     // 2. 验证服务存在且可用
-    let service = service_repo
+    let service = uow
+        .services()
         .find_by_id(&input.service_id)
         .await?
         .ok_or_else(|| AppError::not_found("服务不存在"))?;
@@ -69,7 +71,8 @@ pub async fn execute(
     }
 
     // 4. 验证卖家存在且状态正常
-    let seller = member_repo
+    let seller = uow
+        .members()
         .find_by_id(service.provider_id)
         .await?
         .ok_or_else(|| AppError::not_found("服务提供者不存在"))?;
@@ -79,7 +82,8 @@ pub async fn execute(
     }
 
     // 5. 检查买家ISU余额
-    let buyer_isu_account = isu_repo
+    let buyer_isu_account = uow
+        .isu_accounts()
         .find_by_owner_id(&input.buyer_id)
         .await?
         .ok_or_else(|| AppError::not_found("买家ISU账户不存在"))?;
@@ -107,7 +111,8 @@ pub async fn execute(
     let seller_id = transaction.seller_id;
 
     // 7. 保存交易
-    transaction_repo.save(&transaction).await?;
+    uow.transactions().save(&transaction).await?;
+    uow.commit().await?;
 
     info!(
         transaction_id = %transaction_id,
@@ -0,0 +1,113 @@
+//! 交易争议用例
+
+use domain::{
+    dispute::Dispute,
+    member::MemberId,
+    transaction::{Transaction, TransactionId, TransactionItemType},
+    unit_of_work::UnitOfWorkFactory,
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+/// 发起争议输入
+#[derive(Debug)]
+pub struct DisputeTransactionInput {
+    pub transaction_id: TransactionId,
+    pub requester_id: MemberId, // 买家或卖家都可以发起争议
+    pub reason: String,
+}
+
+/// 发起争议输出
+#[derive(Debug)]
+pub struct DisputeTransactionOutput {
+    pub transaction: Transaction,
+    pub dispute: Dispute,
+}
+
+/// 发起争议用例
+///
+/// 争议期间托管中的款项保持冻结，直到争议裁决。服务交易自动分配给管理该服务职业的决策者，
+/// 没有合适的决策者（或工具租用）时等待管理员分配。
+#[instrument(
+    name = "dispute_transaction",
+    skip(uow_factory, input),
+    fields(
+        transaction_id = %input.transaction_id,
+        requester_id = %input.requester_id
+    )
+)]
+pub async fn execute(
+    uow_factory: &dyn UnitOfWorkFactory,
+    input: DisputeTransactionInput,
+) -> Result<DisputeTransactionOutput> {
+    info!("开始发起交易争议");
+
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证交易存在（锁定交易行）
+    let mut transaction = uow
+        .transactions()
+        .find_by_id_for_update(&input.transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    // 2. 验证操作者是交易参与者
+    if !transaction.is_participant(&input.requester_id) {
+        return Err(AppError::forbidden("只有交易参与者可以发起争议"));
+    }
+
+    // 3. 标记为争议
+    transaction.dispute()?;
+
+    // 4. 创建争议，服务交易按职业确定裁决范围
+    let profession_type = match &transaction.item_type {
+        TransactionItemType::Service(service_id) => {
+            let service = uow
+                .services()
+                .find_by_id(service_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("服务不存在"))?;
+            Some(service.profession_type)
+        }
+        TransactionItemType::Tool(_) => None,
+    };
+    let mut dispute = Dispute::open(
+        &transaction,
+        input.requester_id,
+        input.reason,
+        profession_type.clone(),
+    )?;
+
+    // 5. 分配给管理该职业的决策者（交易参与者回避）
+    if let Some(profession) = &profession_type {
+        let decider = uow
+            .members()
+            .find_deciders_by_profession(profession)
+            .await?
+            .into_iter()
+            .find(|decider| !transaction.is_participant(&decider.id));
+
+        if let Some(decider) = decider {
+            dispute.assign(&decider)?;
+        }
+    }
+
+    // 6. 保存并提交
+    uow.transactions().update(&transaction).await?;
+    uow.disputes().save(&dispute).await?;
+    uow.commit().await?;
+
+    info!(
+        transaction_id = %input.transaction_id,
+        dispute_id = %dispute.id,
+        disputed_by = %input.requester_id,
+        decider_id = ?dispute.decider_id,
+        "交易已进入争议"
+    );
+
+    Ok(DisputeTransactionOutput {
+        transaction,
+        dispute,
+    })
+}
+
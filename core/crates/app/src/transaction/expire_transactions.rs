@@ -0,0 +1,94 @@
+//! 待确认交易自动过期用例
+
+use chrono::{DateTime, Utc};
+use domain::{
+    transaction::{Transaction, TransactionId},
+    unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+};
+use shared::Result;
+use tracing::{info, instrument, warn};
+
+/// 交易过期输入
+#[derive(Debug)]
+pub struct ExpireTransactionsInput {
+    /// 创建时间早于此时间仍未确认的交易将过期
+    pub cutoff: DateTime<Utc>,
+    /// 单次运行最多处理的交易数
+    pub batch_size: usize,
+}
+
+/// 交易过期输出
+#[derive(Debug, Default)]
+pub struct ExpireTransactionsOutput {
+    pub expired: usize,
+    pub failed: usize,
+}
+
+/// 待确认交易自动过期用例
+///
+/// 每笔交易在独立的工作单元中逐条认领（`FOR UPDATE SKIP LOCKED`）并处理，
+/// 并发运行的工作进程不会重复处理同一交易；已过期的交易不再满足条件，重复运行是幂等的。
+/// 待确认的交易尚未冻结款项，过期时只需释放工具租用预订的租用期。
+#[instrument(name = "expire_transactions", skip(uow_factory), fields(cutoff = %input.cutoff))]
+pub async fn execute(
+    uow_factory: &dyn UnitOfWorkFactory,
+    input: ExpireTransactionsInput,
+) -> Result<ExpireTransactionsOutput> {
+    let mut output = ExpireTransactionsOutput::default();
+    // 本次运行中处理失败的交易，不再重复认领
+    let mut failed: Vec<TransactionId> = Vec::new();
+
+    while output.expired + output.failed < input.batch_size {
+        // 1. 认领一笔超时未确认的交易
+        let uow = uow_factory.begin().await?;
+        let Some(mut transaction) = uow
+            .transactions()
+            .claim_pending_created_before(input.cutoff, &failed)
+            .await?
+        else {
+            break;
+        };
+
+        // 2. 过期并提交，失败时回滚并跳过该交易
+        let result = match expire(uow.as_ref(), &mut transaction).await {
+            Ok(()) => uow.commit().await,
+            Err(e) => Err(e),
+        };
+
+        match result {
+            Ok(()) => {
+                output.expired += 1;
+                info!(transaction_id = %transaction.id, "交易超时未确认，已过期");
+            }
+            Err(e) => {
+                output.failed += 1;
+                failed.push(transaction.id);
+                warn!(transaction_id = %transaction.id, error = %e, "交易过期处理失败");
+            }
+        }
+    }
+
+    if output.expired > 0 || output.failed > 0 {
+        info!(expired = output.expired, failed = output.failed, "交易过期任务完成");
+    }
+
+    Ok(output)
+}
+
+async fn expire(uow: &dyn UnitOfWork, transaction: &mut Transaction) -> Result<()> {
+    transaction.expire()?;
+
+    // 工具租用释放预订的租用期
+    if let Some(mut reservation) = uow
+        .tool_reservations()
+        .find_by_transaction_id(&transaction.id)
+        .await?
+    {
+        if reservation.is_active() {
+            reservation.release()?;
+            uow.tool_reservations().update(&reservation).await?;
+        }
+    }
+
+    uow.transactions().update(transaction).await
+}
@@ -0,0 +1,76 @@
+//! 交易查询用例
+
+use domain::{
+    member::MemberId,
+    tool::{ToolRental, ToolRentalRepository},
+    transaction::{Transaction, TransactionId, TransactionRepository},
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+/// 获取交易详情（仅交易参与者可见）
+#[instrument(
+    name = "get_transaction",
+    skip(transaction_repo),
+    fields(transaction_id = %transaction_id, requester_id = %requester_id)
+)]
+pub async fn get_transaction(
+    transaction_repo: &dyn TransactionRepository,
+    transaction_id: TransactionId,
+    requester_id: MemberId,
+) -> Result<Transaction> {
+    info!("获取交易详情");
+
+    let transaction = transaction_repo
+        .find_by_id(&transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    if !transaction.is_participant(&requester_id) {
+        return Err(AppError::forbidden("只有交易参与者可以查看交易"));
+    }
+
+    Ok(transaction)
+}
+
+/// 获取工具租用详情（仅交易参与者可见）
+#[instrument(
+    name = "get_tool_rental",
+    skip(transaction_repo, tool_rental_repo),
+    fields(transaction_id = %transaction_id, requester_id = %requester_id)
+)]
+pub async fn get_tool_rental(
+    transaction_repo: &dyn TransactionRepository,
+    tool_rental_repo: &dyn ToolRentalRepository,
+    transaction_id: TransactionId,
+    requester_id: MemberId,
+) -> Result<ToolRental> {
+    info!("获取工具租用详情");
+
+    get_transaction(transaction_repo, transaction_id, requester_id).await?;
+
+    tool_rental_repo
+        .find_by_transaction_id(&transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("该交易不是工具租用"))
+}
+
+/// 我的购买：作为买家的交易
+#[instrument(name = "list_purchases", skip(transaction_repo), fields(buyer_id = %buyer_id))]
+pub async fn list_purchases(
+    transaction_repo: &dyn TransactionRepository,
+    buyer_id: MemberId,
+) -> Result<Vec<Transaction>> {
+    info!("列出我的购买");
+    transaction_repo.find_by_buyer_id(&buyer_id).await
+}
+
+/// 我的销售：作为卖家的交易
+#[instrument(name = "list_sales", skip(transaction_repo), fields(seller_id = %seller_id))]
+pub async fn list_sales(
+    transaction_repo: &dyn TransactionRepository,
+    seller_id: MemberId,
+) -> Result<Vec<Transaction>> {
+    info!("列出我的销售");
+    transaction_repo.find_by_seller_id(&seller_id).await
+}
@@ -1,10 +1,25 @@
 //! 交易相关用例
 
+pub mod auto_complete_transactions;
+pub mod cancel_transaction;
 pub mod complete_transaction;
 pub mod confirm_transaction;
 pub mod create_transaction;
+pub mod dispute_transaction;
+pub mod expire_transactions;
+pub mod list_transactions;
+pub mod rent_tool;
+pub mod return_tool;
+pub mod start_transaction;
 
 // 重导出
+pub use auto_complete_transactions::{
+    execute as auto_complete_transactions, AutoCompleteTransactionsInput,
+    AutoCompleteTransactionsOutput,
+};
+pub use cancel_transaction::{
+    execute as cancel_transaction, CancelTransactionInput, CancelTransactionOutput,
+};
 pub use complete_transaction::{
     execute as complete_transaction, CompleteTransactionInput, CompleteTransactionOutput,
 };
@@ -13,4 +28,16 @@ pub use confirm_transaction::{
 };
 pub use create_transaction::{
     execute as create_transaction, CreateTransactionInput, CreateTransactionOutput,
-};
\ No newline at end of file
+};
+pub use dispute_transaction::{
+    execute as dispute_transaction, DisputeTransactionInput, DisputeTransactionOutput,
+};
+pub use expire_transactions::{
+    execute as expire_transactions, ExpireTransactionsInput, ExpireTransactionsOutput,
+};
+pub use list_transactions::{get_tool_rental, get_transaction, list_purchases, list_sales};
+pub use rent_tool::{execute as rent_tool, RentToolInput, RentToolOutput};
+pub use return_tool::{execute as return_tool, ReturnToolInput, ReturnToolOutput};
+pub use start_transaction::{
+    execute as start_transaction, StartTransactionInput, StartTransactionOutput,
+};
@@ -0,0 +1,157 @@
+//! 租用工具用例
+
+use chrono::{NaiveDate, Utc};
+use domain::{
+    member::MemberId,
+    tool::{ReservationKind, ReservationWindow, RentalPeriod, ToolId, ToolRental, ToolReservation},
+    transaction::{Transaction, TransactionItemType},
+    unit_of_work::UnitOfWorkFactory,
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument, warn};
+
+/// 租用工具输入
+#[derive(Debug)]
+pub struct RentToolInput {
+    pub renter_id: MemberId,
+    pub tool_id: ToolId,
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub description: Option<String>,
+}
+
+/// 租用工具输出
+#[derive(Debug)]
+pub struct RentToolOutput {
+    pub transaction: Transaction,
+    pub rental: ToolRental,
+}
+
+/// 租用工具用例
+///
+/// 创建待确认的工具类交易，交易金额为租金；租金和押金在工具所有者确认时冻结。
+#[instrument(
+    name = "rent_tool",
+    skip(uow_factory),
+    fields(
+        renter_id = %input.renter_id,
+        tool_id = %input.tool_id,
+        start_date = %input.start_date,
+        end_date = %input.end_date
+    )
+)]
+pub async fn execute(uow_factory: &dyn UnitOfWorkFactory, input: RentToolInput) -> Result<RentToolOutput> {
+    info!("开始创建工具租用");
+
+    // 1. 验证租用期
+    let period = RentalPeriod::new(input.start_date, input.end_date)?;
+    if period.start_date < Utc::now().date_naive() {
+        return Err(AppError::validation("开始日期不能早于今天"));
+    }
+
+    let uow = uow_factory.begin().await?;
+
+    // 2. 验证租用者存在且状态正常
+    let renter = uow
+        .members()
+        .find_by_id(input.renter_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("租用者不存在"))?;
+
+    if !renter.is_active() {
+        return Err(AppError::validation("租用者账户未激活"));
+    }
+
+    // 3. 验证工具存在且可租用（锁定工具行）
+    let tool = uow
+        .tools()
+        .find_by_id_for_update(input.tool_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("工具不存在"))?;
+
+    if tool.is_owned_by(&input.renter_id) {
+        return Err(AppError::validation("不能租用自己的工具"));
+    }
+
+    if !tool.is_rentable() {
+        return Err(AppError::validation("工具当前不可租用"));
+    }
+
+    // 4. 验证工具所有者存在且状态正常
+    let owner = uow
+        .members()
+        .find_by_id(tool.owner_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("工具所有者不存在"))?;
+
+    if !owner.is_active() {
+        return Err(AppError::validation("工具所有者账户未激活"));
+    }
+
+    // 5. 拒绝与已有预订或所有者封锁重叠的租用期（数据库排他约束兜底并发预订）
+    let window = ReservationWindow::from_rental_period(&period);
+    let overlapping = uow.tool_reservations().find_active_overlapping(tool.id, &window).await?;
+    if let Some(existing) = overlapping.first() {
+        warn!(
+            reservation_id = %existing.id,
+            kind = %existing.kind,
+            existing_start = %existing.window.starts_at,
+            existing_end = %existing.window.ends_at,
+            "工具租用期与已有预订重叠"
+        );
+        let reason = match existing.kind {
+            ReservationKind::Block => "暂停出租",
+            ReservationKind::Rental => "已被预订",
+        };
+        return Err(AppError::validation(format!(
+            "工具在 {} 至 {} (UTC) {}",
+            existing.window.starts_at.format("%Y-%m-%d %H:%M"),
+            existing.window.ends_at.format("%Y-%m-%d %H:%M"),
+            reason
+        )));
+    }
+
+    // 6. 创建交易和租用记录（租金和押金按当前租用条款计算）
+    let transaction = Transaction::new(
+        input.renter_id,
+        tool.owner_id,
+        TransactionItemType::Tool(tool.id),
+        tool.rental_fee(&period)?,
+        input.description,
+    )?;
+    let rental = ToolRental::new(transaction.id, &tool, input.renter_id, period)?;
+    let reservation = ToolReservation::rental(tool.id, input.renter_id, transaction.id, &period);
+
+    // 7. 检查租用者余额是否足够支付租金和押金
+    let renter_isu_account = uow
+        .isu_accounts()
+        .find_by_owner_id(&input.renter_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("租用者ISU账户不存在"))?;
+
+    let total_due = rental.total_due()?;
+    if !renter_isu_account.has_sufficient_balance(&total_due) {
+        warn!(
+            renter_balance = %renter_isu_account.get_balance(),
+            required_isu = %total_due,
+            "租用者ISU余额不足"
+        );
+        return Err(AppError::validation("ISU余额不足以支付租金和押金"));
+    }
+
+    // 8. 保存
+    uow.transactions().save(&transaction).await?;
+    uow.tool_rentals().save(&rental).await?;
+    uow.tool_reservations().save(&reservation).await?;
+    uow.commit().await?;
+
+    info!(
+        transaction_id = %transaction.id,
+        days = rental.period.days(),
+        rental_fee = %rental.rental_fee,
+        deposit = %rental.deposit,
+        "工具租用创建成功"
+    );
+
+    Ok(RentToolOutput { transaction, rental })
+}
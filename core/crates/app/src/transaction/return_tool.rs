@@ -0,0 +1,181 @@
+//! 归还工具用例
+
+use domain::{
+    isu::ISUTransactionType,
+    member::MemberId,
+    tool::{DepositStatus, ToolId},
+    transaction::{Transaction, TransactionId, TransactionItemType},
+    unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+use crate::member::reputation::recalculate_participants_reputation;
+
+/// 归还工具输入
+#[derive(Debug)]
+pub struct ReturnToolInput {
+    pub transaction_id: TransactionId,
+    pub owner_id: MemberId, // 只有工具所有者可以登记归还
+}
+
+/// 归还工具输出
+#[derive(Debug)]
+pub struct ReturnToolOutput {
+    pub transaction_id: TransactionId,
+    pub message: String,
+}
+
+/// 归还工具用例（完成交易 + 租金放款 + 退还押金 + 工具恢复可用 + 释放预订）
+#[instrument(
+    name = "return_tool",
+    skip(uow_factory),
+    fields(
+        transaction_id = %input.transaction_id,
+        owner_id = %input.owner_id
+    )
+)]
+pub async fn execute(uow_factory: &dyn UnitOfWorkFactory, input: ReturnToolInput) -> Result<ReturnToolOutput> {
+    info!("开始登记工具归还");
+
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证交易存在（锁定交易行）
+    let mut transaction = uow
+        .transactions()
+        .find_by_id_for_update(&input.transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    let tool_id = match &transaction.item_type {
+        TransactionItemType::Tool(tool_id) => *tool_id,
+        TransactionItemType::Service(_) => {
+            return Err(AppError::validation("该交易不是工具租用"));
+        }
+    };
+
+    // 2. 验证操作者是工具所有者
+    if !transaction.is_seller(&input.owner_id) {
+        return Err(AppError::forbidden("只有工具所有者可以登记归还"));
+    }
+
+    // 3. 完成交易（只有进行中的租用可以归还）
+    transaction.complete()?;
+
+    // 4. 托管中的租金放款给工具所有者
+    let escrowed = transaction.take_escrow();
+    if !escrowed.value().is_zero() {
+        let renter_isu_account = uow
+            .isu_accounts()
+            .find_by_owner_id(&transaction.buyer_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("租用者ISU账户不存在"))?;
+        let owner_isu_account = uow
+            .isu_accounts()
+            .find_by_owner_id(&transaction.seller_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("工具所有者ISU账户不存在"))?;
+
+        uow.isu_accounts()
+            .release(
+                &renter_isu_account.id,
+                &owner_isu_account.id,
+                &escrowed,
+                ISUTransactionType::ToolRental,
+                Some(format!("工具租金放款 - {}", transaction.id.value())),
+            )
+            .await?;
+
+        info!(rental_fee = %escrowed, "租金已放款给工具所有者");
+    }
+
+    // 5. 登记归还：退还押金、工具恢复可用、释放预订
+    let deposit_refunded = close_rental(uow.as_ref(), &transaction, tool_id).await?;
+
+    // 6. 保存，重新计算双方信誉分
+    uow.transactions().update(&transaction).await?;
+    recalculate_participants_reputation(uow.as_ref(), &transaction).await?;
+    uow.commit().await?;
+
+    info!(
+        transaction_id = %input.transaction_id,
+        tool_id = %tool_id,
+        "工具已归还"
+    );
+
+    let message = if deposit_refunded {
+        "工具已归还，租金已放款，押金已退还给租用者"
+    } else {
+        "工具已归还，租金已放款"
+    };
+
+    Ok(ReturnToolOutput {
+        transaction_id: input.transaction_id,
+        message: message.to_string(),
+    })
+}
+
+/// 结束租用：登记归还、退还托管中的押金、工具恢复可用并释放预订
+///
+/// 归还工具和争议裁决共用，交易状态和租金结算由调用方处理。返回押金是否已退还。
+pub(crate) async fn close_rental(
+    uow: &dyn UnitOfWork,
+    transaction: &Transaction,
+    tool_id: ToolId,
+) -> Result<bool> {
+    // 1. 登记归还，托管中的押金退还租用者
+    let mut rental = uow
+        .tool_rentals()
+        .find_by_transaction_id(&transaction.id)
+        .await?
+        .ok_or_else(|| AppError::not_found("租用记录不存在"))?;
+
+    let refund_deposit = rental.deposit_status == DepositStatus::Held;
+    rental.mark_returned()?;
+
+    if refund_deposit {
+        let renter_isu_account = uow
+            .isu_accounts()
+            .find_by_owner_id(&rental.renter_id)
+            .await?
+            .ok_or_else(|| AppError::not_found("租用者ISU账户不存在"))?;
+
+        uow.isu_accounts()
+            .release(
+                &renter_isu_account.id,
+                &renter_isu_account.id,
+                &rental.deposit,
+                ISUTransactionType::DepositRefund,
+                Some(format!("工具押金退还 - {}", transaction.id.value())),
+            )
+            .await?;
+
+        info!(deposit = %rental.deposit, "押金已退还");
+    }
+
+    // 2. 工具恢复可用
+    let mut tool = uow
+        .tools()
+        .find_by_id_for_update(tool_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("工具不存在"))?;
+    tool.make_available();
+
+    // 3. 释放租用预订（提前归还时剩余日期可再被预订）
+    if let Some(mut reservation) = uow
+        .tool_reservations()
+        .find_by_transaction_id(&transaction.id)
+        .await?
+    {
+        if reservation.is_active() {
+            reservation.release()?;
+            uow.tool_reservations().update(&reservation).await?;
+        }
+    }
+
+    uow.tools().update(&tool).await?;
+    uow.tool_rentals().update(&rental).await?;
+
+    info!(returned_at = ?rental.returned_at, "租用已结束");
+    Ok(refund_deposit)
+}
@@ -0,0 +1,105 @@
+//! 开始交易用例
+
+use domain::{
+    member::MemberId,
+    transaction::{TransactionId, TransactionItemType},
+    unit_of_work::UnitOfWorkFactory,
+};
+use shared::{AppError, Result};
+use tracing::{info, instrument};
+
+/// 开始交易输入
+#[derive(Debug)]
+pub struct StartTransactionInput {
+    pub transaction_id: TransactionId,
+    pub seller_id: MemberId, // 只有卖家可以开始交易
+}
+
+/// 开始交易输出
+#[derive(Debug)]
+pub struct StartTransactionOutput {
+    pub transaction_id: TransactionId,
+    pub message: String,
+}
+
+/// 开始交易用例（服务进入进行中 / 工具交付租用者）
+#[instrument(
+    name = "start_transaction",
+    skip(uow_factory),
+    fields(
+        transaction_id = %input.transaction_id,
+        seller_id = %input.seller_id
+    )
+)]
+pub async fn execute(
+    uow_factory: &dyn UnitOfWorkFactory,
+    input: StartTransactionInput,
+) -> Result<StartTransactionOutput> {
+    info!("开始执行交易");
+
+    // 所有写操作在同一工作单元内提交，任一步失败都会整体回滚
+    let uow = uow_factory.begin().await?;
+
+    // 1. 验证交易存在（锁定交易行）
+    let mut transaction = uow
+        .transactions()
+        .find_by_id_for_update(&input.transaction_id)
+        .await?
+        .ok_or_else(|| AppError::not_found("交易不存在"))?;
+
+    // 2. 验证操作者是卖家
+    if !transaction.is_seller(&input.seller_id) {
+        return Err(AppError::forbidden("只有卖家可以开始交易"));
+    }
+
+    // 3. 更新交易状态
+    transaction.start()?;
+
+    // 4. 更新交易标的状态（款项已在确认时冻结，完成后放款）
+    match &transaction.item_type {
+        TransactionItemType::Service(service_id) => {
+            // 服务进入进行中
+            if let Some(mut service) = uow.services().find_by_id(service_id).await? {
+                service.start()?;
+                uow.services().save(&service).await?;
+            }
+        }
+        TransactionItemType::Tool(tool_id) => {
+            // 工具交付租用者：上一位租用者必须已归还
+            let mut tool = uow
+                .tools()
+                .find_by_id_for_update(*tool_id)
+                .await?
+                .ok_or_else(|| AppError::not_found("工具不存在"))?;
+
+            if !tool.is_available() {
+                return Err(AppError::validation("工具当前未处于可交付状态"));
+            }
+
+            tool.rent();
+            uow.tools().update(&tool).await?;
+        }
+    }
+
+    // 5. 保存交易状态更新
+    uow.transactions().update(&transaction).await?;
+
+    // 6. 提交工作单元
+    uow.commit().await?;
+
+    info!(
+        transaction_id = %input.transaction_id,
+        new_status = %transaction.status,
+        "交易已开始"
+    );
+
+    let message = match &transaction.item_type {
+        TransactionItemType::Service(_) => "交易已开始进行，款项将在完成后放款",
+        TransactionItemType::Tool(_) => "租用已开始，租金将在归还后放款",
+    };
+
+    Ok(StartTransactionOutput {
+        transaction_id: input.transaction_id,
+        message: message.to_string(),
+    })
+}
@@ -0,0 +1,107 @@
+//! AuditEvent 实体
+
+use super::{AuditAction, AuditContext, AuditEventId, AuditTargetType};
+use crate::member::{Member, MemberId, MemberStatus, UserRole};
+use crate::profession::ProfessionType;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use shared::{AppError, Result};
+
+/// 审计事件（只追加，写入后不可修改或删除）
+///
+/// `before`/`after` 保存操作前后对象的 JSON 快照，只包含与该操作相关的字段。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEvent {
+    pub id: AuditEventId,
+    pub actor_id: MemberId,
+    pub action: AuditAction,
+    pub target_type: AuditTargetType,
+    pub target_id: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+    pub reason: Option<String>,
+    pub request_id: Option<String>,
+    pub ip_address: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl AuditEvent {
+    /// 创建审计事件
+    pub fn new(
+        context: &AuditContext,
+        actor_id: MemberId,
+        action: AuditAction,
+        target_type: AuditTargetType,
+        target_id: impl ToString,
+    ) -> Self {
+        Self {
+            id: AuditEventId::new(),
+            actor_id,
+            action,
+            target_type,
+            target_id: target_id.to_string(),
+            before: None,
+            after: None,
+            reason: None,
+            request_id: context.request_id.clone(),
+            ip_address: context.ip_address.map(|ip| ip.to_string()),
+            created_at: Utc::now(),
+        }
+    }
+
+    /// 会员授权信息（状态、角色、管理的职业）变更事件
+    pub fn member_access_changed(
+        context: &AuditContext,
+        actor_id: MemberId,
+        action: AuditAction,
+        before: &Member,
+        after: &Member,
+    ) -> Result<Self> {
+        Self::new(context, actor_id, action, AuditTargetType::Member, after.id)
+            .with_before(&MemberAccessSnapshot::from(before))?
+            .with_after(&MemberAccessSnapshot::from(after))
+    }
+
+    /// 设置操作前快照
+    pub fn with_before(mut self, before: &impl Serialize) -> Result<Self> {
+        self.before = Some(snapshot(before)?);
+        Ok(self)
+    }
+
+    /// 设置操作后快照
+    pub fn with_after(mut self, after: &impl Serialize) -> Result<Self> {
+        self.after = Some(snapshot(after)?);
+        Ok(self)
+    }
+
+    /// 设置操作原因
+    pub fn with_reason(mut self, reason: Option<String>) -> Self {
+        self.reason = reason.filter(|r| !r.trim().is_empty());
+        self
+    }
+}
+
+/// 序列化快照；失败时返回错误，不能写入缺少快照的审计事件
+fn snapshot(value: &impl Serialize) -> Result<Value> {
+    serde_json::to_value(value)
+        .map_err(|e| AppError::internal(format!("序列化审计快照失败: {}", e)))
+}
+
+/// 会员授权信息快照（不含密码哈希等敏感字段）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MemberAccessSnapshot {
+    pub status: MemberStatus,
+    pub role: UserRole,
+    pub managed_professions: Vec<ProfessionType>,
+}
+
+impl From<&Member> for MemberAccessSnapshot {
+    fn from(member: &Member) -> Self {
+        Self {
+            status: member.status,
+            role: member.role,
+            managed_professions: member.managed_professions.clone(),
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! Audit模块 - 特权操作审计日志
+
+pub mod entity;
+pub mod repository;
+pub mod value_objects;
+
+// 重导出
+pub use entity::{AuditEvent, MemberAccessSnapshot};
+pub use repository::{AuditEventCriteria, AuditEventRepository};
+pub use value_objects::{AuditAction, AuditContext, AuditTargetType};
+
+// ID类型定义
+use shared::Id;
+pub type AuditEventId = Id<AuditEvent>;
@@ -0,0 +1,38 @@
+//! AuditEvent Repository接口
+
+use super::{AuditAction, AuditEvent, AuditTargetType};
+use crate::member::MemberId;
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use shared::Result;
+
+/// 审计事件查询条件（管理后台使用，未设置的条件不过滤）
+#[derive(Debug, Clone, Default)]
+pub struct AuditEventCriteria {
+    pub actor_id: Option<MemberId>,
+    pub action: Option<AuditAction>,
+    pub target_type: Option<AuditTargetType>,
+    pub target_id: Option<String>,
+    /// 起始时间（含）
+    pub from: Option<DateTime<Utc>>,
+    /// 截止时间（不含）
+    pub to: Option<DateTime<Utc>>,
+}
+
+/// 审计事件Repository trait（只追加）
+#[async_trait]
+pub trait AuditEventRepository: Send + Sync {
+    /// 追加审计事件
+    async fn append(&self, event: &AuditEvent) -> Result<()>;
+
+    /// 按条件查询审计事件（按时间倒序分页）
+    async fn search(
+        &self,
+        criteria: &AuditEventCriteria,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<AuditEvent>>;
+
+    /// 统计符合条件的审计事件数量
+    async fn count(&self, criteria: &AuditEventCriteria) -> Result<u64>;
+}
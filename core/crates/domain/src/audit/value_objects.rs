@@ -0,0 +1,103 @@
+//! Audit 值对象
+
+use serde::{Deserialize, Serialize};
+use std::net::IpAddr;
+use shared::{AppError, Result};
+
+/// 审计的特权操作
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditAction {
+    MemberStatusChanged, // 会员状态变更（激活、停用、封禁）
+    MemberRoleChanged,   // 会员角色变更（提升为管理员、降级为普通用户）
+    DeciderAssigned,     // 分配决策者职业
+    DeciderRevoked,      // 撤销决策者权限
+    IsuBalanceAdjusted,  // 管理员调整ISU余额
+    ToolDeleted,         // 删除工具
+    DisputeResolved,     // 裁决争议
+}
+
+impl AuditAction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::MemberStatusChanged => "member_status_changed",
+            Self::MemberRoleChanged => "member_role_changed",
+            Self::DeciderAssigned => "decider_assigned",
+            Self::DeciderRevoked => "decider_revoked",
+            Self::IsuBalanceAdjusted => "isu_balance_adjusted",
+            Self::ToolDeleted => "tool_deleted",
+            Self::DisputeResolved => "dispute_resolved",
+        }
+    }
+}
+
+impl std::fmt::Display for AuditAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for AuditAction {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "member_status_changed" => Ok(Self::MemberStatusChanged),
+            "member_role_changed" => Ok(Self::MemberRoleChanged),
+            "decider_assigned" => Ok(Self::DeciderAssigned),
+            "decider_revoked" => Ok(Self::DeciderRevoked),
+            "isu_balance_adjusted" => Ok(Self::IsuBalanceAdjusted),
+            "tool_deleted" => Ok(Self::ToolDeleted),
+            "dispute_resolved" => Ok(Self::DisputeResolved),
+            _ => Err(AppError::validation(format!("无效的审计操作: {}", s))),
+        }
+    }
+}
+
+/// 审计对象类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditTargetType {
+    Member,
+    IsuAccount,
+    Tool,
+    Dispute,
+}
+
+impl AuditTargetType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Member => "member",
+            Self::IsuAccount => "isu_account",
+            Self::Tool => "tool",
+            Self::Dispute => "dispute",
+        }
+    }
+}
+
+impl std::fmt::Display for AuditTargetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl std::str::FromStr for AuditTargetType {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "member" => Ok(Self::Member),
+            "isu_account" => Ok(Self::IsuAccount),
+            "tool" => Ok(Self::Tool),
+            "dispute" => Ok(Self::Dispute),
+            _ => Err(AppError::validation(format!("无效的审计对象类型: {}", s))),
+        }
+    }
+}
+
+/// 发起操作的请求信息（由 API 层从请求中提取，随用例输入传入）
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AuditContext {
+    pub request_id: Option<String>,
+    pub ip_address: Option<IpAddr>,
+}
@@ -0,0 +1,65 @@
+//! RefreshToken 实体
+
+use super::{RefreshTokenFamilyId, RefreshTokenId};
+use crate::member::MemberId;
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// 刷新令牌（只保存令牌哈希，明文只在签发时返回给客户端）
+///
+/// 每次刷新都会轮换：旧令牌标记为已使用，签发同一家族的新令牌。
+/// 已使用或已吊销的令牌再次出现即视为泄露，整个家族随之吊销。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RefreshToken {
+    pub id: RefreshTokenId,
+    pub member_id: MemberId,
+    pub family_id: RefreshTokenFamilyId,
+    pub token_hash: String,
+    pub expires_at: DateTime<Utc>,
+    /// 轮换时间（已换发新令牌）
+    pub used_at: Option<DateTime<Utc>>,
+    /// 吊销时间（登出、检测到重用或会员被停用）
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl RefreshToken {
+    /// 登录时签发新家族的刷新令牌
+    pub fn issue(member_id: MemberId, token_hash: String, ttl: Duration) -> Self {
+        Self::new(member_id, RefreshTokenFamilyId::new(), token_hash, ttl)
+    }
+
+    /// 轮换：签发同一家族的下一个刷新令牌
+    pub fn rotate(&self, token_hash: String, ttl: Duration) -> Self {
+        Self::new(self.member_id, self.family_id, token_hash, ttl)
+    }
+
+    fn new(
+        member_id: MemberId,
+        family_id: RefreshTokenFamilyId,
+        token_hash: String,
+        ttl: Duration,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: RefreshTokenId::new(),
+            member_id,
+            family_id,
+            token_hash,
+            expires_at: now + ttl,
+            used_at: None,
+            revoked_at: None,
+            created_at: now,
+        }
+    }
+
+    /// 检查是否已过期
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+
+    /// 检查是否已被使用或吊销（再次出现即为重用）
+    pub fn is_spent(&self) -> bool {
+        self.used_at.is_some() || self.revoked_at.is_some()
+    }
+}
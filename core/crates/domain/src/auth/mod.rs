@@ -0,0 +1,17 @@
+//! Auth模块 - 刷新令牌与登录会话
+
+pub mod entity;
+pub mod repository;
+
+// 重导出
+pub use entity::RefreshToken;
+pub use repository::RefreshTokenRepository;
+
+// ID类型定义
+use shared::Id;
+pub type RefreshTokenId = Id<RefreshToken>;
+
+/// 令牌家族：同一次登录轮换出的刷新令牌共享一个家族
+#[derive(Debug)]
+pub struct RefreshTokenFamily;
+pub type RefreshTokenFamilyId = Id<RefreshTokenFamily>;
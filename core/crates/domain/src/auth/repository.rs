@@ -0,0 +1,28 @@
+//! RefreshToken Repository接口
+
+use super::{RefreshToken, RefreshTokenFamilyId, RefreshTokenId};
+use crate::member::MemberId;
+use async_trait::async_trait;
+use shared::Result;
+
+/// 刷新令牌Repository trait
+#[async_trait]
+pub trait RefreshTokenRepository: Send + Sync {
+    /// 保存刷新令牌
+    async fn save(&self, token: &RefreshToken) -> Result<()>;
+
+    /// 按令牌哈希查找
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>>;
+
+    /// 按令牌哈希查找并锁定（在工作单元中使用，串行化同一令牌的并发轮换）
+    async fn find_by_hash_for_update(&self, token_hash: &str) -> Result<Option<RefreshToken>>;
+
+    /// 标记令牌已使用（仅当未使用且未吊销时成功，并发刷新只有一个请求能拿到令牌）
+    async fn mark_used(&self, id: &RefreshTokenId) -> Result<bool>;
+
+    /// 吊销整个令牌家族，返回吊销的令牌数
+    async fn revoke_family(&self, family_id: &RefreshTokenFamilyId) -> Result<u64>;
+
+    /// 吊销会员的全部刷新令牌，返回吊销的令牌数
+    async fn revoke_all_for_member(&self, member_id: &MemberId) -> Result<u64>;
+}
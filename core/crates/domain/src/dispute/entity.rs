@@ -0,0 +1,328 @@
+//! Dispute实体
+
+use super::{DisputeId, EvidenceId};
+use crate::isu::ISU;
+use crate::member::{Member, MemberId};
+use crate::profession::ProfessionType;
+use crate::transaction::{Transaction, TransactionId, TransactionStatus};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::{AppError, Result};
+
+/// 争议理由最大长度
+const MAX_REASON_LENGTH: usize = 2000;
+
+/// 证据留言最大长度
+const MAX_EVIDENCE_LENGTH: usize = 5000;
+
+/// 争议状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DisputeStatus {
+    #[default]
+    Open,     // 处理中
+    Resolved, // 已裁决
+}
+
+impl std::fmt::Display for DisputeStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Open => write!(f, "open"),
+            Self::Resolved => write!(f, "resolved"),
+        }
+    }
+}
+
+impl std::str::FromStr for DisputeStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "open" => Ok(Self::Open),
+            "resolved" => Ok(Self::Resolved),
+            _ => Err(AppError::validation(format!("无效的争议状态: {}", s))),
+        }
+    }
+}
+
+/// 裁决结果
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RulingOutcome {
+    FullRefund,      // 全额退还买家
+    Split,           // 按比例拆分
+    ReleaseToSeller, // 全额放款给卖家
+}
+
+impl RulingOutcome {
+    /// 计算托管款项的拆分：返回 (退还买家, 放款卖家)
+    ///
+    /// 只有拆分裁决需要指定退还买家的金额，且必须介于 0 和托管金额之间（不含两端），最多两位小数。
+    pub fn settle(&self, escrowed: &ISU, buyer_refund: Option<ISU>) -> Result<(ISU, ISU)> {
+        match self {
+            Self::FullRefund => Ok((*escrowed, ISU::default())),
+            Self::ReleaseToSeller => Ok((ISU::default(), *escrowed)),
+            Self::Split => {
+                let refund = buyer_refund
+                    .ok_or_else(|| AppError::validation("拆分裁决需要指定退还买家的金额"))?;
+                let refund = ISU::from_input(refund.value())?;
+                if refund.value().is_zero() || refund.value() >= escrowed.value() {
+                    return Err(AppError::validation(format!(
+                        "退还买家的金额必须大于0且小于托管金额 {}",
+                        escrowed
+                    )));
+                }
+                Ok((refund, escrowed.subtract(&refund)?))
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for RulingOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::FullRefund => write!(f, "full_refund"),
+            Self::Split => write!(f, "split"),
+            Self::ReleaseToSeller => write!(f, "release_to_seller"),
+        }
+    }
+}
+
+impl std::str::FromStr for RulingOutcome {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "full_refund" => Ok(Self::FullRefund),
+            "split" => Ok(Self::Split),
+            "release_to_seller" => Ok(Self::ReleaseToSeller),
+            _ => Err(AppError::validation(format!("无效的裁决结果: {}", s))),
+        }
+    }
+}
+
+/// 裁决记录（与结算产生的ISU流水关联，供审计）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisputeRuling {
+    pub outcome: RulingOutcome,
+    pub buyer_refund: ISU,
+    pub seller_payout: ISU,
+    pub note: Option<String>,
+    pub decided_by: MemberId,
+    pub decided_at: DateTime<Utc>,
+    pub refund_isu_transaction_id: Option<String>,
+    pub payout_isu_transaction_id: Option<String>,
+}
+
+/// 争议聚合根（与进入争议的交易一一对应）
+///
+/// 服务交易由管理该服务职业的决策者裁决；工具租用没有对应职业，只能由管理员裁决。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dispute {
+    pub id: DisputeId,
+    pub transaction_id: TransactionId,
+    pub opened_by: MemberId,
+    pub reason: String,
+    pub profession_type: Option<ProfessionType>,
+    pub decider_id: Option<MemberId>,
+    pub status: DisputeStatus,
+    pub ruling: Option<DisputeRuling>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Dispute {
+    /// 为进入争议的交易创建争议
+    pub fn open(
+        transaction: &Transaction,
+        opened_by: MemberId,
+        reason: String,
+        profession_type: Option<ProfessionType>,
+    ) -> Result<Self> {
+        if transaction.status != TransactionStatus::Disputed {
+            return Err(AppError::validation("交易未处于争议状态"));
+        }
+
+        let reason = reason.trim().to_string();
+        if reason.is_empty() {
+            return Err(AppError::validation("争议理由不能为空"));
+        }
+        if reason.chars().count() > MAX_REASON_LENGTH {
+            return Err(AppError::validation(format!(
+                "争议理由不能超过{}个字符",
+                MAX_REASON_LENGTH
+            )));
+        }
+
+        let now = Utc::now();
+        Ok(Self {
+            id: DisputeId::new(),
+            transaction_id: transaction.id,
+            opened_by,
+            reason,
+            profession_type,
+            decider_id: None,
+            status: DisputeStatus::default(),
+            ruling: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 是否处理中
+    pub fn is_open(&self) -> bool {
+        self.status == DisputeStatus::Open
+    }
+
+    /// 检查会员是否有权裁决该争议
+    pub fn can_be_arbitrated_by(&self, member: &Member) -> bool {
+        match &self.profession_type {
+            Some(profession) => member.can_manage_profession(profession),
+            None => member.is_admin(),
+        }
+    }
+
+    /// 检查是否分配给指定决策者
+    pub fn is_assigned_to(&self, member_id: &MemberId) -> bool {
+        self.decider_id.as_ref() == Some(member_id)
+    }
+
+    /// 分配决策者
+    pub fn assign(&mut self, decider: &Member) -> Result<()> {
+        if !self.is_open() {
+            return Err(AppError::validation("争议已裁决，不能重新分配"));
+        }
+        if !self.can_be_arbitrated_by(decider) {
+            return Err(AppError::validation("该会员无权裁决此争议"));
+        }
+
+        self.decider_id = Some(decider.id);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 记录裁决
+    pub fn resolve(&mut self, ruling: DisputeRuling) -> Result<()> {
+        if !self.is_open() {
+            return Err(AppError::validation("争议已裁决"));
+        }
+
+        self.status = DisputeStatus::Resolved;
+        self.ruling = Some(ruling);
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+}
+
+/// 争议证据（双方及决策者的留言）
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DisputeEvidence {
+    pub id: EvidenceId,
+    pub dispute_id: DisputeId,
+    pub author_id: MemberId,
+    pub message: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl DisputeEvidence {
+    /// 创建证据留言
+    pub fn new(dispute: &Dispute, author_id: MemberId, message: String) -> Result<Self> {
+        if !dispute.is_open() {
+            return Err(AppError::validation("争议已裁决，不能再提交证据"));
+        }
+
+        let message = message.trim().to_string();
+        if message.is_empty() {
+            return Err(AppError::validation("证据内容不能为空"));
+        }
+        if message.chars().count() > MAX_EVIDENCE_LENGTH {
+            return Err(AppError::validation(format!(
+                "证据内容不能超过{}个字符",
+                MAX_EVIDENCE_LENGTH
+            )));
+        }
+
+        Ok(Self {
+            id: EvidenceId::new(),
+            dispute_id: dispute.id,
+            author_id,
+            message,
+            created_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+
+    fn isu(amount: i64, scale: u32) -> ISU {
+        ISU::new(Decimal::new(amount, scale)).unwrap()
+    }
+
+    #[test]
+    fn full_refund_returns_the_whole_escrow_to_the_buyer() {
+        let escrowed = isu(100, 0);
+
+        let (refund, payout) = RulingOutcome::FullRefund.settle(&escrowed, None).unwrap();
+
+        assert_eq!(refund, escrowed);
+        assert_eq!(payout, ISU::default());
+    }
+
+    #[test]
+    fn release_to_seller_pays_out_the_whole_escrow() {
+        let escrowed = isu(100, 0);
+
+        let (refund, payout) = RulingOutcome::ReleaseToSeller.settle(&escrowed, None).unwrap();
+
+        assert_eq!(refund, ISU::default());
+        assert_eq!(payout, escrowed);
+    }
+
+    #[test]
+    fn buyer_refund_is_ignored_unless_split() {
+        let escrowed = isu(100, 0);
+
+        let full = RulingOutcome::FullRefund.settle(&escrowed, Some(isu(30, 0))).unwrap();
+        let release = RulingOutcome::ReleaseToSeller.settle(&escrowed, Some(isu(30, 0))).unwrap();
+
+        assert_eq!(full, (escrowed, ISU::default()));
+        assert_eq!(release, (ISU::default(), escrowed));
+    }
+
+    #[test]
+    fn split_pays_the_remainder_to_the_seller() {
+        let escrowed = isu(100, 0);
+
+        let (refund, payout) = RulingOutcome::Split.settle(&escrowed, Some(isu(3333, 2))).unwrap();
+
+        assert_eq!(refund, isu(3333, 2));
+        assert_eq!(payout, isu(6667, 2));
+        assert_eq!(refund.add(&payout).unwrap(), escrowed);
+    }
+
+    #[test]
+    fn split_requires_a_buyer_refund() {
+        assert!(RulingOutcome::Split.settle(&isu(100, 0), None).is_err());
+    }
+
+    #[test]
+    fn split_refund_must_be_strictly_between_zero_and_the_escrow() {
+        let escrowed = isu(100, 0);
+
+        assert!(RulingOutcome::Split.settle(&escrowed, Some(ISU::default())).is_err());
+        assert!(RulingOutcome::Split.settle(&escrowed, Some(escrowed)).is_err());
+        assert!(RulingOutcome::Split.settle(&escrowed, Some(isu(101, 0))).is_err());
+        assert!(RulingOutcome::Split.settle(&escrowed, Some(isu(1, 2))).is_ok());
+        assert!(RulingOutcome::Split.settle(&escrowed, Some(isu(9999, 2))).is_ok());
+    }
+
+    #[test]
+    fn split_rejects_refunds_with_more_than_two_decimal_places() {
+        let result = RulingOutcome::Split.settle(&isu(100, 0), Some(isu(33335, 3)));
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+    }
+}
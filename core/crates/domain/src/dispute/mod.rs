@@ -0,0 +1,13 @@
+//! Dispute模块 - 交易争议与仲裁
+
+pub mod entity;
+pub mod repository;
+
+// 重导出
+pub use entity::{Dispute, DisputeEvidence, DisputeRuling, DisputeStatus, RulingOutcome};
+pub use repository::DisputeRepository;
+
+// ID类型定义
+use shared::Id;
+pub type DisputeId = Id<Dispute>;
+pub type EvidenceId = Id<DisputeEvidence>;
@@ -0,0 +1,38 @@
+//! Dispute Repository接口
+
+use super::{Dispute, DisputeEvidence, DisputeId};
+use crate::member::MemberId;
+use crate::transaction::TransactionId;
+use async_trait::async_trait;
+use shared::Result;
+
+/// 争议Repository trait
+#[async_trait]
+pub trait DisputeRepository: Send + Sync {
+    /// 保存争议
+    async fn save(&self, dispute: &Dispute) -> Result<()>;
+
+    /// 根据ID查找争议
+    async fn find_by_id(&self, id: &DisputeId) -> Result<Option<Dispute>>;
+
+    /// 根据ID查找并锁定争议（在工作单元中使用，防止重复裁决）
+    async fn find_by_id_for_update(&self, id: &DisputeId) -> Result<Option<Dispute>>;
+
+    /// 根据交易ID查找争议
+    async fn find_by_transaction_id(&self, transaction_id: &TransactionId) -> Result<Option<Dispute>>;
+
+    /// 查找分配给决策者的处理中争议
+    async fn find_open_by_decider(&self, decider_id: &MemberId) -> Result<Vec<Dispute>>;
+
+    /// 更新争议（分配、裁决）
+    async fn update(&self, dispute: &Dispute) -> Result<()>;
+
+    /// 保存证据
+    async fn add_evidence(&self, evidence: &DisputeEvidence) -> Result<()>;
+
+    /// 按提交时间列出争议证据
+    async fn list_evidence(&self, dispute_id: &DisputeId) -> Result<Vec<DisputeEvidence>>;
+
+    /// 统计会员败诉的争议数量（卖家被裁决全额退款，或买家被裁决全额放款给卖家）
+    async fn count_lost_by(&self, member_id: &MemberId) -> Result<u64>;
+}
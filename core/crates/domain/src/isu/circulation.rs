@@ -0,0 +1,126 @@
+//! ISU流通量
+
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::{AppError, Result};
+
+/// ISU流通量快照
+///
+/// 所有 ISU 都由平台金库发出，因此在任意时刻：
+/// - 会员账户余额之和等于金库净流出（累计流出 - 累计流入）；
+/// - 金库余额加上会员账户余额之和等于创世供应量；
+/// - 托管账户余额等于各账户托管中余额之和。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ISUCirculation {
+    /// 创世供应量
+    pub total_supply: Decimal,
+    /// 金库当前余额
+    pub treasury_balance: Decimal,
+    /// 金库以外所有账户余额之和（流通量，含托管账户）
+    pub circulating: Decimal,
+    /// 托管账户余额
+    pub escrow_balance: Decimal,
+    /// 各账户托管中余额之和
+    pub held_total: Decimal,
+    /// 金库累计流出
+    pub issued: Decimal,
+    /// 累计流回金库
+    pub returned: Decimal,
+}
+
+impl ISUCirculation {
+    /// 净发行量
+    pub fn net_issuance(&self) -> Decimal {
+        self.issued - self.returned
+    }
+
+    /// 检查流通量是否平衡
+    pub fn is_balanced(&self) -> bool {
+        self.circulating == self.net_issuance()
+            && self.treasury_balance + self.circulating == self.total_supply
+            && self.escrow_balance == self.held_total
+    }
+
+    /// 校验流通量不变式
+    pub fn check_invariant(&self) -> Result<()> {
+        if self.circulating != self.net_issuance() {
+            return Err(AppError::internal(format!(
+                "ISU流通量与净发行量不一致: 流通量 {}, 净发行量 {}",
+                self.circulating,
+                self.net_issuance()
+            )));
+        }
+
+        if self.treasury_balance + self.circulating != self.total_supply {
+            return Err(AppError::internal(format!(
+                "ISU总量与创世供应量不一致: 金库 {} + 流通 {} != 供应量 {}",
+                self.treasury_balance, self.circulating, self.total_supply
+            )));
+        }
+
+        if self.escrow_balance != self.held_total {
+            return Err(AppError::internal(format!(
+                "托管账户余额与托管中余额不一致: 托管账户 {}, 托管中合计 {}",
+                self.escrow_balance, self.held_total
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 金库发出 300（流回 50），其中 100 在托管中的平衡快照
+    fn balanced() -> ISUCirculation {
+        ISUCirculation {
+            total_supply: Decimal::new(1000, 0),
+            treasury_balance: Decimal::new(750, 0),
+            circulating: Decimal::new(250, 0),
+            escrow_balance: Decimal::new(100, 0),
+            held_total: Decimal::new(100, 0),
+            issued: Decimal::new(300, 0),
+            returned: Decimal::new(50, 0),
+        }
+    }
+
+    #[test]
+    fn balanced_snapshot_passes() {
+        let circulation = balanced();
+        assert_eq!(circulation.net_issuance(), Decimal::new(250, 0));
+        assert!(circulation.is_balanced());
+        assert!(circulation.check_invariant().is_ok());
+    }
+
+    #[test]
+    fn circulating_must_match_net_issuance() {
+        let circulation = ISUCirculation {
+            returned: Decimal::new(60, 0),
+            ..balanced()
+        };
+        assert!(!circulation.is_balanced());
+        assert!(circulation.check_invariant().is_err());
+    }
+
+    #[test]
+    fn treasury_and_circulating_must_add_up_to_supply() {
+        let circulation = ISUCirculation {
+            treasury_balance: Decimal::new(740, 0),
+            ..balanced()
+        };
+        assert!(!circulation.is_balanced());
+        assert!(circulation.check_invariant().is_err());
+    }
+
+    #[test]
+    fn escrow_balance_must_match_held_total() {
+        let circulation = ISUCirculation {
+            held_total: Decimal::new(90, 0),
+            ..balanced()
+        };
+        assert!(!circulation.is_balanced());
+        assert!(circulation.check_invariant().is_err());
+    }
+}
@@ -5,13 +5,25 @@ use crate::member::MemberId;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use shared::{AppError, Result};
+use uuid::Uuid;
+
+/// 平台金库账户ID（由迁移创建），所有 ISU 的发行方
+pub const TREASURY_ACCOUNT_UUID: Uuid = Uuid::from_u128(1);
+
+/// 平台托管账户ID（由迁移创建），代为保管交易款项和押金等尚未结算的 ISU
+pub const ESCROW_ACCOUNT_UUID: Uuid = Uuid::from_u128(2);
 
 /// ISU账户聚合根
+///
+/// `balance` 是可用余额；卖家确认交易时买家的 ISU 转入平台托管账户，
+/// 同时计入买家的 `held_balance`，交易完成后放款给卖家，取消或争议裁决后退还买家。
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ISUAccount {
     pub id: ISUAccountId,
     pub owner_id: MemberId,
     pub balance: ISU,
+    /// 托管中（已冻结、尚未结算）的 ISU
+    pub held_balance: ISU,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -24,11 +36,37 @@ impl ISUAccount {
             id: ISUAccountId::new(),
             owner_id,
             balance: initial_balance,
+            held_balance: ISU::default(),
             created_at: now,
             updated_at: now,
         }
     }
 
+    /// 平台金库账户ID
+    pub fn treasury_id() -> ISUAccountId {
+        ISUAccountId::from_uuid(TREASURY_ACCOUNT_UUID)
+    }
+
+    /// 检查是否为平台金库账户
+    pub fn is_treasury(&self) -> bool {
+        self.id.value() == TREASURY_ACCOUNT_UUID
+    }
+
+    /// 平台托管账户ID
+    pub fn escrow_id() -> ISUAccountId {
+        ISUAccountId::from_uuid(ESCROW_ACCOUNT_UUID)
+    }
+
+    /// 检查是否为平台托管账户
+    pub fn is_escrow(&self) -> bool {
+        self.id.value() == ESCROW_ACCOUNT_UUID
+    }
+
+    /// 检查是否为平台系统账户（金库或托管），系统账户不属于任何会员，不能被直接调整
+    pub fn is_system_account(&self) -> bool {
+        self.is_treasury() || self.is_escrow()
+    }
+
     /// 存入ISU
     pub fn deposit(&mut self, amount: &ISU) -> Result<()> {
         self.balance = self.balance.add(amount)?;
@@ -52,6 +90,11 @@ impl ISUAccount {
     pub fn get_balance(&self) -> ISU {
         self.balance
     }
+
+    /// 可用余额与托管中余额之和
+    pub fn total_balance(&self) -> Result<ISU> {
+        self.balance.add(&self.held_balance)
+    }
 }
 
 /// ISU交易记录
@@ -66,12 +109,106 @@ pub struct ISUTransaction {
     pub created_at: DateTime<Utc>,
 }
 
+impl ISUTransaction {
+    /// 校验转账双方是否符合流水类型
+    ///
+    /// 初始余额只能由金库发出，管理员调整必须一端是金库；
+    /// 冻结（交易款项、押金）只能由会员转入托管账户，放款和退还只能由托管账户转给会员。
+    pub fn validate_parties(
+        from_account_id: &ISUAccountId,
+        to_account_id: &ISUAccountId,
+        transaction_type: ISUTransactionType,
+    ) -> Result<()> {
+        let treasury_id = ISUAccount::treasury_id();
+        let escrow_id = ISUAccount::escrow_id();
+        let from_treasury = from_account_id == &treasury_id;
+        let to_treasury = to_account_id == &treasury_id;
+        let from_escrow = from_account_id == &escrow_id;
+        let to_escrow = to_account_id == &escrow_id;
+
+        match transaction_type {
+            ISUTransactionType::InitialBalance if !from_treasury => {
+                Err(AppError::validation("初始余额只能由平台金库发放"))
+            }
+            ISUTransactionType::AdminAdjustment if !from_treasury && !to_treasury => {
+                Err(AppError::validation("管理员调整必须通过平台金库"))
+            }
+            ISUTransactionType::EscrowHold | ISUTransactionType::DepositHold
+                if !to_escrow || from_treasury =>
+            {
+                Err(AppError::validation("冻结的ISU只能由会员转入平台托管账户"))
+            }
+            ISUTransactionType::ServicePayment
+            | ISUTransactionType::ToolRental
+            | ISUTransactionType::EscrowRefund
+            | ISUTransactionType::DepositRefund
+            | ISUTransactionType::CancellationRefund
+            | ISUTransactionType::CancellationFee
+                if !from_escrow || to_treasury || to_escrow =>
+            {
+                Err(AppError::validation("托管中的ISU只能由平台托管账户转给会员"))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
 /// ISU交易类型
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ISUTransactionType {
-    ServicePayment,    // 服务支付
-    ToolRental,       // 工具租用
+    ServicePayment,    // 服务支付（托管放款给卖家）
+    ToolRental,       // 工具租用（托管放款给工具所有者）
     InitialBalance,   // 初始余额
     AdminAdjustment,  // 管理员调整
+    DepositHold,      // 押金托管
+    DepositRefund,    // 押金退还
+    EscrowHold,       // 交易款项冻结
+    EscrowRefund,     // 交易款项退还（争议裁决）
+    CancellationRefund, // 取消交易退款
+    CancellationFee,  // 取消费（托管转给卖家）
+}
+
+impl ISUTransactionType {
+    /// 是否经由平台托管账户流转（只能通过冻结/释放操作记账）
+    pub fn is_escrow_movement(&self) -> bool {
+        !matches!(self, Self::InitialBalance | Self::AdminAdjustment)
+    }
+}
+
+impl std::fmt::Display for ISUTransactionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ServicePayment => write!(f, "service_payment"),
+            Self::ToolRental => write!(f, "tool_rental"),
+            Self::InitialBalance => write!(f, "initial_balance"),
+            Self::AdminAdjustment => write!(f, "admin_adjustment"),
+            Self::DepositHold => write!(f, "deposit_hold"),
+            Self::DepositRefund => write!(f, "deposit_refund"),
+            Self::EscrowHold => write!(f, "escrow_hold"),
+            Self::EscrowRefund => write!(f, "escrow_refund"),
+            Self::CancellationRefund => write!(f, "cancellation_refund"),
+            Self::CancellationFee => write!(f, "cancellation_fee"),
+        }
+    }
+}
+
+impl std::str::FromStr for ISUTransactionType {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "service_payment" => Ok(Self::ServicePayment),
+            "tool_rental" => Ok(Self::ToolRental),
+            "initial_balance" => Ok(Self::InitialBalance),
+            "admin_adjustment" => Ok(Self::AdminAdjustment),
+            "deposit_hold" => Ok(Self::DepositHold),
+            "deposit_refund" => Ok(Self::DepositRefund),
+            "escrow_hold" => Ok(Self::EscrowHold),
+            "escrow_refund" => Ok(Self::EscrowRefund),
+            "cancellation_refund" => Ok(Self::CancellationRefund),
+            "cancellation_fee" => Ok(Self::CancellationFee),
+            _ => Err(AppError::validation(format!("无效的ISU交易类型: {}", s))),
+        }
+    }
 }
\ No newline at end of file
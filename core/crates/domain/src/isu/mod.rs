@@ -1,12 +1,16 @@
 //! ISU模块 - 国际标准单位系统
 
+pub mod circulation;
 pub mod entity;
 pub mod repository;
 pub mod value_objects;
 
 // 重导出
-pub use entity::{ISUAccount, ISUTransaction, ISUTransactionType};
-pub use repository::ISUAccountRepository;
+pub use circulation::ISUCirculation;
+pub use entity::{
+    ISUAccount, ISUTransaction, ISUTransactionType, ESCROW_ACCOUNT_UUID, TREASURY_ACCOUNT_UUID,
+};
+pub use repository::{ISUAccountRepository, ISUStatementQuery};
 pub use value_objects::{ISU, ISURate};
 
 // ID类型定义
@@ -1,9 +1,20 @@
 //! ISU账户Repository接口
 
-use super::{ISU, ISUAccount, ISUAccountId, ISUTransaction, ISUTransactionType};
+use super::{ISU, ISUAccount, ISUCirculation, ISUAccountId, ISUTransaction, ISUTransactionType};
 use crate::member::MemberId;
 use async_trait::async_trait;
 use shared::Result;
+use uuid::Uuid;
+
+/// 账户流水查询条件（游标分页，按时间倒序）
+#[derive(Debug, Clone, Default)]
+pub struct ISUStatementQuery {
+    /// 只返回这些类型的流水，为空表示不过滤
+    pub transaction_types: Vec<ISUTransactionType>,
+    /// 游标：上一页最后一条流水的ID，返回比它更早的记录
+    pub before: Option<Uuid>,
+    pub limit: u32,
+}
 
 /// ISU账户Repository trait
 #[async_trait]
@@ -17,7 +28,7 @@ pub trait ISUAccountRepository: Send + Sync {
     /// 根据所有者ID查找账户
     async fn find_by_owner_id(&self, owner_id: &MemberId) -> Result<Option<ISUAccount>>;
 
-    /// 执行ISU转账（原子操作）
+    /// 执行ISU转账（原子操作，不能用于托管资金的流转）
     async fn transfer(
         &self,
         from_account_id: &ISUAccountId,
@@ -27,6 +38,26 @@ pub trait ISUAccountRepository: Send + Sync {
         description: Option<String>,
     ) -> Result<ISUTransaction>;
 
+    /// 冻结ISU：从账户转入平台托管账户，并计入该账户的托管中余额（原子操作）
+    async fn hold(
+        &self,
+        account_id: &ISUAccountId,
+        amount: &ISU,
+        transaction_type: ISUTransactionType,
+        description: Option<String>,
+    ) -> Result<ISUTransaction>;
+
+    /// 释放 `holder_account_id` 托管中的ISU：从平台托管账户转给 `to_account_id`
+    /// （放款给卖家或退还给持有人本人），并扣减持有人的托管中余额（原子操作）
+    async fn release(
+        &self,
+        holder_account_id: &ISUAccountId,
+        to_account_id: &ISUAccountId,
+        amount: &ISU,
+        transaction_type: ISUTransactionType,
+        description: Option<String>,
+    ) -> Result<ISUTransaction>;
+
     /// 获取账户交易历史
     async fn get_transaction_history(
         &self,
@@ -34,6 +65,13 @@ pub trait ISUAccountRepository: Send + Sync {
         limit: Option<u32>,
     ) -> Result<Vec<ISUTransaction>>;
 
-    /// 更新账户余额（管理员操作）
-    async fn update_balance(&self, account_id: &ISUAccountId, new_balance: &ISU) -> Result<()>;
+    /// 按游标分页获取账户流水
+    async fn get_statement(
+        &self,
+        account_id: &ISUAccountId,
+        query: &ISUStatementQuery,
+    ) -> Result<Vec<ISUTransaction>>;
+
+    /// 获取ISU流通量快照
+    async fn get_circulation(&self) -> Result<ISUCirculation>;
 }
\ No newline at end of file
@@ -4,6 +4,9 @@ use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use shared::{AppError, Result};
 
+/// ISU金额的小数位数（与数据库 DECIMAL(20, 2) 一致）
+pub const ISU_DECIMAL_PLACES: u32 = 2;
+
 /// 国际标准单位（International Standard Unit）
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub struct ISU(Decimal);
@@ -17,6 +20,19 @@ impl ISU {
         Ok(Self(amount))
     }
 
+    /// 从外部输入创建ISU，确保非负且最多两位小数
+    ///
+    /// 超出精度的金额入库时会被四舍五入，拆分或调整后账目将无法对平。
+    pub fn from_input(amount: Decimal) -> Result<Self> {
+        if amount.normalize().scale() > ISU_DECIMAL_PLACES {
+            return Err(AppError::validation(format!(
+                "ISU金额最多保留{}位小数",
+                ISU_DECIMAL_PLACES
+            )));
+        }
+        Self::new(amount)
+    }
+
     /// 从浮点数创建ISU
     pub fn from_f64(amount: f64) -> Result<Self> {
         let decimal = Decimal::try_from(amount)
@@ -103,4 +119,27 @@ impl std::fmt::Display for ISURate {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} ISU/hour", self.0)
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_input_accepts_up_to_two_decimal_places() {
+        assert_eq!(ISU::from_input(Decimal::new(3333, 2)).unwrap().value(), Decimal::new(3333, 2));
+        assert!(ISU::from_input(Decimal::new(5, 0)).is_ok());
+        // 末尾的零不计入精度
+        assert!(ISU::from_input(Decimal::new(12300, 4)).is_ok());
+    }
+
+    #[test]
+    fn from_input_rejects_more_than_two_decimal_places() {
+        assert!(ISU::from_input(Decimal::new(33335, 3)).is_err());
+        assert!(ISU::from_input(Decimal::new(4, 3)).is_err());
+    }
+
+    #[test]
+    fn from_input_rejects_negative_amounts() {
+        assert!(ISU::from_input(Decimal::new(-1, 0)).is_err());
+    }
+}
@@ -1,12 +1,17 @@
 //! 领域层
 //! 包含核心业务逻辑和领域模型
 
+pub mod audit;
+pub mod auth;
+pub mod dispute;
 pub mod isu;
 pub mod member;
 pub mod profession;
+pub mod review;
 pub mod service;
 pub mod tool;
 pub mod transaction;
+pub mod unit_of_work;
 
 // 重导出共享类型
 pub use shared::{AppError, Result};
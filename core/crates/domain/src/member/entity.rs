@@ -1,8 +1,11 @@
 //! Member 实体
 
-use super::{Email, MemberId, MemberStatus, Username, UserRole};
+use super::{Email, MemberId, MemberStatus, Username, UserRole, INITIAL_REPUTATION};
+use crate::isu::{ESCROW_ACCOUNT_UUID, TREASURY_ACCOUNT_UUID};
 use crate::profession::ProfessionType;
+use crate::review::RatingSummary;
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 
 /// 会员聚合根
@@ -15,6 +18,12 @@ pub struct Member {
     pub status: MemberStatus,
     pub role: UserRole,
     pub managed_professions: Vec<ProfessionType>, // 决策者管理的职业
+    /// 收到的评价汇总（由评价写入时同步累加，会员更新不会修改）
+    pub rating: RatingSummary,
+    /// 信誉分（交易事件后重新计算，会员更新不会修改）
+    pub reputation: Decimal,
+    /// 令牌版本（角色、状态或管理的职业变化时递增，签发的令牌携带该版本）
+    pub token_version: i32,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -31,6 +40,9 @@ impl Member {
             status: MemberStatus::default(),
             role: UserRole::default(), // 默认为普通用户
             managed_professions: Vec::new(), // 初始为空
+            rating: RatingSummary::default(),
+            reputation: Decimal::from(INITIAL_REPUTATION),
+            token_version: 0,
             created_at: now,
             updated_at: now,
         }
@@ -39,6 +51,12 @@ impl Member {
     /// 修改状态（私有方法）
     fn change_status(&mut self, new_status: MemberStatus) {
         self.status = new_status;
+        self.touch_authorization();
+    }
+
+    /// 授权信息变化（私有方法）：递增令牌版本
+    fn touch_authorization(&mut self) {
+        self.token_version += 1;
         self.updated_at = Utc::now();
     }
 
@@ -67,6 +85,12 @@ impl Member {
         self.role == UserRole::Decider
     }
 
+    /// 检查是否为平台系统会员（金库、托管账户的持有者，由迁移创建，不能登录或被管理）
+    pub fn is_system_member(&self) -> bool {
+        let id = self.id.value();
+        id == TREASURY_ACCOUNT_UUID || id == ESCROW_ACCOUNT_UUID
+    }
+
     /// 检查是否是管理员
     pub fn is_admin(&self) -> bool {
         self.role == UserRole::Admin
@@ -74,14 +98,14 @@ impl Member {
 
     /// 检查是否可以管理指定职业
     pub fn can_manage_profession(&self, profession: &ProfessionType) -> bool {
-        self.is_admin() || (self.is_decider() && self.managed_professions.contains(profession))
+        self.role.can_manage_profession(&self.managed_professions, profession)
     }
 
     /// 添加管理的职业（只有管理员可以操作）
     pub fn add_managed_profession(&mut self, profession: ProfessionType) {
         if !self.managed_professions.contains(&profession) {
             self.managed_professions.push(profession);
-            self.updated_at = Utc::now();
+            self.touch_authorization();
         }
     }
 
@@ -89,7 +113,7 @@ impl Member {
     pub fn remove_managed_profession(&mut self, profession: &ProfessionType) {
         if let Some(pos) = self.managed_professions.iter().position(|p| p == profession) {
             self.managed_professions.remove(pos);
-            self.updated_at = Utc::now();
+            self.touch_authorization();
         }
     }
 
@@ -97,20 +121,20 @@ impl Member {
     pub fn promote_to_decider(&mut self, managed_professions: Vec<ProfessionType>) {
         self.role = UserRole::Decider;
         self.managed_professions = managed_professions;
-        self.updated_at = Utc::now();
+        self.touch_authorization();
     }
 
     /// 提升为管理员
     pub fn promote_to_admin(&mut self) {
         self.role = UserRole::Admin;
         self.managed_professions.clear(); // 管理员可以管理所有职业
-        self.updated_at = Utc::now();
+        self.touch_authorization();
     }
 
     /// 降级为普通用户
     pub fn demote_to_regular(&mut self) {
         self.role = UserRole::Regular;
         self.managed_professions.clear();
-        self.updated_at = Utc::now();
+        self.touch_authorization();
     }
 }
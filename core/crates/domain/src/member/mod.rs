@@ -1,11 +1,13 @@
 //! 会员聚合根
 
 mod entity;
+mod reputation;
 mod repository;
 mod value_objects;
 
 pub use entity::Member;
-pub use repository::MemberRepository;
+pub use repository::{MemberRepository, MemberSearchCriteria};
+pub use reputation::{ReputationStats, INITIAL_REPUTATION};
 pub use value_objects::{Email, MemberStatus, Password, Username, UserRole};
 
 // 类型别名
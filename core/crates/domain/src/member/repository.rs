@@ -1,9 +1,20 @@
 //! Member Repository trait
 
-use super::{Email, Member, MemberId, Username};
+use super::{Email, Member, MemberId, MemberStatus, Username, UserRole};
+use crate::profession::ProfessionType;
 use async_trait::async_trait;
+use rust_decimal::Decimal;
 use shared::Result;
 
+/// 会员搜索条件（管理后台使用）
+#[derive(Debug, Clone, Default)]
+pub struct MemberSearchCriteria {
+    /// 按邮箱或用户名模糊匹配，为空时不过滤
+    pub keyword: String,
+    pub status: Option<MemberStatus>,
+    pub role: Option<UserRole>,
+}
+
 /// 会员仓储接口
 #[async_trait]
 pub trait MemberRepository: Send + Sync {
@@ -19,9 +30,26 @@ pub trait MemberRepository: Send + Sync {
     /// 根据用户名查找
     async fn find_by_username(&self, username: &Username) -> Result<Option<Member>>;
 
+    /// 查找管理指定职业的已激活决策者（按注册时间排序）
+    async fn find_deciders_by_profession(&self, profession: &ProfessionType) -> Result<Vec<Member>>;
+
+    /// 按条件搜索会员（按注册时间倒序分页）
+    async fn search_members(
+        &self,
+        criteria: &MemberSearchCriteria,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<Member>>;
+
+    /// 统计符合条件的会员数量
+    async fn count_members(&self, criteria: &MemberSearchCriteria) -> Result<u64>;
+
     /// 更新会员
     async fn update(&self, member: &Member) -> Result<()>;
 
+    /// 更新信誉分
+    async fn update_reputation(&self, id: MemberId, score: Decimal) -> Result<()>;
+
     /// 删除会员
     async fn delete(&self, id: MemberId) -> Result<()>;
 }
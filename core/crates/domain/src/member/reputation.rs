@@ -0,0 +1,120 @@
+//! 会员信誉分
+
+use crate::review::RatingSummary;
+use rust_decimal::Decimal;
+
+/// 新会员的初始信誉分
+pub const INITIAL_REPUTATION: i64 = 50;
+
+/// 每笔已完成交易加分，最多计入 `MAX_COMPLETED_COUNTED` 笔
+const COMPLETED_BONUS: i64 = 1;
+const MAX_COMPLETED_COUNTED: u64 = 30;
+
+/// 平均评分每高于（低于）3 星一星加（减）分
+const RATING_WEIGHT: i64 = 10;
+
+/// 每次争议败诉扣分
+const DISPUTE_LOSS_PENALTY: i64 = 10;
+
+/// 每次取消已确认的交易扣分
+const CANCELLATION_PENALTY: i64 = 3;
+
+/// 信誉统计（计算信誉分的输入）
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ReputationStats {
+    /// 作为买家或卖家完成的交易数
+    pub completed_transactions: u64,
+    /// 收到的评价汇总
+    pub rating: RatingSummary,
+    /// 争议败诉次数（卖家被裁决全额退款，或买家被裁决全额放款）
+    pub disputes_lost: u64,
+    /// 自己取消的已确认交易数
+    pub cancellations: u64,
+}
+
+impl ReputationStats {
+    /// 计算信誉分（0-100，保留两位小数）
+    ///
+    /// 以 50 分为基础：每笔完成交易 +1（最多 +30），平均评分相对 3 星每星 ±10，
+    /// 每次争议败诉 -10，每次取消已确认的交易 -3。
+    pub fn score(&self) -> Decimal {
+        let completed = Decimal::from(self.completed_transactions.min(MAX_COMPLETED_COUNTED));
+        let rating = self
+            .rating
+            .average()
+            .map(|average| (average - Decimal::from(3)) * Decimal::from(RATING_WEIGHT))
+            .unwrap_or_default();
+
+        let score = Decimal::from(INITIAL_REPUTATION)
+            + completed * Decimal::from(COMPLETED_BONUS)
+            + rating
+            - Decimal::from(self.disputes_lost) * Decimal::from(DISPUTE_LOSS_PENALTY)
+            - Decimal::from(self.cancellations) * Decimal::from(CANCELLATION_PENALTY);
+
+        score
+            .clamp(Decimal::ZERO, Decimal::ONE_HUNDRED)
+            .round_dp(2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(completed: u64, rating: RatingSummary, disputes_lost: u64, cancellations: u64) -> ReputationStats {
+        ReputationStats {
+            completed_transactions: completed,
+            rating,
+            disputes_lost,
+            cancellations,
+        }
+    }
+
+    fn rating(count: u32, total: u32) -> RatingSummary {
+        RatingSummary { count, total }
+    }
+
+    #[test]
+    fn new_member_starts_at_the_initial_score() {
+        assert_eq!(ReputationStats::default().score(), Decimal::from(INITIAL_REPUTATION));
+    }
+
+    #[test]
+    fn completed_transactions_count_up_to_the_cap() {
+        let score = |completed| stats(completed, RatingSummary::default(), 0, 0).score();
+
+        assert_eq!(score(1), Decimal::from(51));
+        assert_eq!(score(30), Decimal::from(80));
+        assert_eq!(score(31), Decimal::from(80));
+        assert_eq!(score(1000), Decimal::from(80));
+    }
+
+    #[test]
+    fn rating_is_weighted_around_three_stars() {
+        let score = |rating| stats(0, rating, 0, 0).score();
+
+        assert_eq!(score(rating(2, 6)), Decimal::from(50));
+        assert_eq!(score(rating(1, 5)), Decimal::from(70));
+        assert_eq!(score(rating(1, 1)), Decimal::from(30));
+        // 平均 4.33 星（保留两位小数）
+        assert_eq!(score(rating(3, 13)), Decimal::new(6330, 2));
+    }
+
+    #[test]
+    fn no_reviews_means_no_rating_adjustment() {
+        assert_eq!(stats(10, RatingSummary::default(), 0, 0).score(), Decimal::from(60));
+    }
+
+    #[test]
+    fn disputes_lost_and_cancellations_are_penalised() {
+        assert_eq!(stats(0, RatingSummary::default(), 1, 0).score(), Decimal::from(40));
+        assert_eq!(stats(0, RatingSummary::default(), 0, 1).score(), Decimal::from(47));
+        assert_eq!(stats(10, rating(1, 4), 1, 2).score(), Decimal::from(54));
+    }
+
+    #[test]
+    fn score_is_clamped_between_zero_and_one_hundred() {
+        assert_eq!(stats(30, rating(10, 50), 0, 0).score(), Decimal::ONE_HUNDRED);
+        assert_eq!(stats(0, rating(1, 1), 10, 10).score(), Decimal::ZERO);
+    }
+}
@@ -1,5 +1,6 @@
 //! 会员值对象
 
+use crate::profession::ProfessionType;
 use serde::{Deserialize, Serialize};
 use shared::{AppError, Result};
 
@@ -125,6 +126,22 @@ impl Default for UserRole {
     }
 }
 
+impl UserRole {
+    /// 检查该角色在给定的管理职业下是否可以管理指定职业
+    /// （管理员可以管理所有职业，决策者只能管理分配给自己的职业）
+    pub fn can_manage_profession(
+        &self,
+        managed_professions: &[ProfessionType],
+        profession: &ProfessionType,
+    ) -> bool {
+        match self {
+            Self::Admin => true,
+            Self::Decider => managed_professions.contains(profession),
+            Self::Regular => false,
+        }
+    }
+}
+
 impl std::fmt::Display for UserRole {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
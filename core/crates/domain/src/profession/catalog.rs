@@ -0,0 +1,191 @@
+//! 职业目录
+//!
+//! 职业及其分类以数据形式维护，管理员可以在运行时新增、重命名和本地化职业。
+
+use super::value_objects::validate_code;
+use super::ProfessionType;
+use crate::isu::ISURate;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::{AppError, Result};
+use std::collections::BTreeMap;
+
+/// 显示名称最大长度
+const MAX_DISPLAY_NAME_LENGTH: usize = 100;
+
+/// 多语言显示名称（语言代码 -> 名称，如 `en` -> `Cleaning`）
+pub type LocalizedNames = BTreeMap<String, String>;
+
+/// 校验并规范化显示名称
+fn normalize_display_name(name: &str) -> Result<String> {
+    let name = name.trim();
+    if name.is_empty() || name.chars().count() > MAX_DISPLAY_NAME_LENGTH {
+        return Err(AppError::validation(format!(
+            "显示名称长度必须在1-{}个字符之间",
+            MAX_DISPLAY_NAME_LENGTH
+        )));
+    }
+    Ok(name.to_string())
+}
+
+/// 校验并规范化多语言名称
+fn normalize_localized_names(names: LocalizedNames) -> Result<LocalizedNames> {
+    names
+        .into_iter()
+        .map(|(locale, name)| {
+            let locale = locale.trim().to_ascii_lowercase();
+            if locale.is_empty()
+                || locale.len() > 10
+                || !locale.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            {
+                return Err(AppError::validation(format!("无效的语言代码: {}", locale)));
+            }
+            Ok((locale, normalize_display_name(&name)?))
+        })
+        .collect()
+}
+
+/// 按语言查找名称：先精确匹配（如 `zh-tw`），再匹配主语言（如 `zh`）
+fn lookup_localized<'a>(names: &'a LocalizedNames, locale: &str) -> Option<&'a str> {
+    let locale = locale.trim().to_ascii_lowercase();
+    names
+        .get(&locale)
+        .or_else(|| {
+            locale
+                .split_once('-')
+                .and_then(|(primary, _)| names.get(primary))
+        })
+        .map(String::as_str)
+}
+
+/// 职业分类
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProfessionCategory {
+    pub code: String,
+    pub display_name: String,
+    pub localized_names: LocalizedNames,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ProfessionCategory {
+    /// 创建职业分类
+    pub fn new(code: String, display_name: String, localized_names: LocalizedNames) -> Result<Self> {
+        validate_code(&code, "职业分类")?;
+
+        let now = Utc::now();
+        Ok(Self {
+            code,
+            display_name: normalize_display_name(&display_name)?,
+            localized_names: normalize_localized_names(localized_names)?,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 重命名分类
+    pub fn rename(&mut self, display_name: &str) -> Result<()> {
+        self.display_name = normalize_display_name(display_name)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 设置多语言名称（整体替换）
+    pub fn set_localized_names(&mut self, names: LocalizedNames) -> Result<()> {
+        self.localized_names = normalize_localized_names(names)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 获取指定语言的显示名称，没有对应翻译时返回默认名称
+    pub fn display_name_for(&self, locale: Option<&str>) -> &str {
+        locale
+            .and_then(|locale| lookup_localized(&self.localized_names, locale))
+            .unwrap_or(&self.display_name)
+    }
+}
+
+/// 职业
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Profession {
+    pub code: ProfessionType,
+    pub display_name: String,
+    pub localized_names: LocalizedNames,
+    pub category_code: String,
+    pub default_rate: ISURate,
+    pub is_active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl Profession {
+    /// 创建职业
+    pub fn new(
+        code: ProfessionType,
+        display_name: String,
+        localized_names: LocalizedNames,
+        category_code: String,
+        default_rate: ISURate,
+    ) -> Result<Self> {
+        validate_code(&category_code, "职业分类")?;
+
+        let now = Utc::now();
+        Ok(Self {
+            code,
+            display_name: normalize_display_name(&display_name)?,
+            localized_names: normalize_localized_names(localized_names)?,
+            category_code,
+            default_rate,
+            is_active: true,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 重命名职业
+    pub fn rename(&mut self, display_name: &str) -> Result<()> {
+        self.display_name = normalize_display_name(display_name)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 设置多语言名称（整体替换）
+    pub fn set_localized_names(&mut self, names: LocalizedNames) -> Result<()> {
+        self.localized_names = normalize_localized_names(names)?;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 调整所属分类
+    pub fn move_to_category(&mut self, category_code: String) -> Result<()> {
+        validate_code(&category_code, "职业分类")?;
+        self.category_code = category_code;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 调整默认费率（没有启用的职业标准时使用）
+    pub fn update_default_rate(&mut self, default_rate: ISURate) {
+        self.default_rate = default_rate;
+        self.updated_at = Utc::now();
+    }
+
+    /// 启用职业
+    pub fn activate(&mut self) {
+        self.is_active = true;
+        self.updated_at = Utc::now();
+    }
+
+    /// 停用职业：停用后不能再发布该职业的服务，已发布的服务不受影响
+    pub fn deactivate(&mut self) {
+        self.is_active = false;
+        self.updated_at = Utc::now();
+    }
+
+    /// 获取指定语言的显示名称，没有对应翻译时返回默认名称
+    pub fn display_name_for(&self, locale: Option<&str>) -> &str {
+        locale
+            .and_then(|locale| lookup_localized(&self.localized_names, locale))
+            .unwrap_or(&self.display_name)
+    }
+}
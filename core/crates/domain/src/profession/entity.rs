@@ -1,6 +1,6 @@
 //! 职业标准管理实体
 
-use super::{ProfessionStandardId, ProfessionType};
+use super::{Profession, ProfessionStandardId, ProfessionType};
 use crate::isu::ISURate;
 use crate::member::MemberId;
 use chrono::{DateTime, Utc};
@@ -43,12 +43,11 @@ impl ProfessionStandardEntity {
         }
     }
 
-    /// 创建默认职业标准
-    pub fn new_default(profession_type: ProfessionType, creator_id: MemberId) -> Result<Self> {
-        let isu_rate = profession_type.default_rate()?;
-        let description = format!("{}的默认标准费率", profession_type.display_name());
-        
-        Ok(Self::new(profession_type, isu_rate, description, creator_id))
+    /// 以职业目录中的默认费率创建职业标准
+    pub fn new_default(profession: &Profession, creator_id: MemberId) -> Self {
+        let description = format!("{}的默认标准费率", profession.display_name);
+
+        Self::new(profession.code.clone(), profession.default_rate, description, creator_id)
     }
 
     /// 更新ISU费率
@@ -113,11 +112,36 @@ pub struct ProfessionStandardHistory {
 }
 
 /// 标准变更动作
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum StandardAction {
     Created,     // 创建
     RateUpdated, // 费率更新
     Activated,   // 激活
     Deactivated, // 停用
-}
\ No newline at end of file
+}
+
+impl std::fmt::Display for StandardAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Created => write!(f, "created"),
+            Self::RateUpdated => write!(f, "rate_updated"),
+            Self::Activated => write!(f, "activated"),
+            Self::Deactivated => write!(f, "deactivated"),
+        }
+    }
+}
+
+impl std::str::FromStr for StandardAction {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "created" => Ok(Self::Created),
+            "rate_updated" => Ok(Self::RateUpdated),
+            "activated" => Ok(Self::Activated),
+            "deactivated" => Ok(Self::Deactivated),
+            _ => Err(AppError::validation(format!("无效的标准变更动作: {}", s))),
+        }
+    }
+}
@@ -1,13 +1,17 @@
 //! 职业模块 - 职业分类和标准管理
 
+pub mod catalog;
 pub mod entity;
+pub mod pricing;
 pub mod repository;
 pub mod value_objects;
 
 // 重导出
+pub use catalog::{LocalizedNames, Profession, ProfessionCategory};
 pub use entity::{ProfessionStandardEntity, ProfessionStandardHistory, StandardAction};
-pub use repository::ProfessionStandardRepository;
-pub use value_objects::{ProfessionStandard, ProfessionType};
+pub use pricing::PricingService;
+pub use repository::{ProfessionCatalogRepository, ProfessionStandardRepository};
+pub use value_objects::ProfessionType;
 
 // ID类型定义
 use shared::Id;
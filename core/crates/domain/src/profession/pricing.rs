@@ -0,0 +1,46 @@
+//! 职业定价领域服务
+
+use super::{ProfessionCatalogRepository, ProfessionStandardRepository, ProfessionType};
+use crate::isu::ISURate;
+use shared::{AppError, Result};
+
+/// 职业定价服务
+///
+/// 优先使用决策者维护的、处于启用状态的职业标准费率；
+/// 没有启用的标准时回退到职业目录中的默认费率。
+pub struct PricingService<'a> {
+    catalog: &'a dyn ProfessionCatalogRepository,
+    standards: &'a dyn ProfessionStandardRepository,
+}
+
+impl<'a> PricingService<'a> {
+    pub fn new(
+        catalog: &'a dyn ProfessionCatalogRepository,
+        standards: &'a dyn ProfessionStandardRepository,
+    ) -> Self {
+        Self { catalog, standards }
+    }
+
+    /// 获取职业当前生效的费率
+    ///
+    /// 职业不在目录中或已停用时返回验证错误。
+    pub async fn current_rate(&self, profession_type: &ProfessionType) -> Result<ISURate> {
+        let profession = self
+            .catalog
+            .find_profession(profession_type)
+            .await?
+            .ok_or_else(|| AppError::validation(format!("未知的职业类型: {}", profession_type)))?;
+
+        if !profession.is_active {
+            return Err(AppError::validation(format!(
+                "职业已停用: {}",
+                profession.display_name
+            )));
+        }
+
+        match self.standards.find_active_by_profession(profession_type).await? {
+            Some(standard) if standard.is_available() => Ok(standard.isu_rate),
+            _ => Ok(profession.default_rate),
+        }
+    }
+}
@@ -1,6 +1,9 @@
-//! 职业标准Repository接口
+//! 职业标准与职业目录Repository接口
 
-use super::{ProfessionStandardEntity, ProfessionStandardHistory, ProfessionStandardId, ProfessionType};
+use super::{
+    Profession, ProfessionCategory, ProfessionStandardEntity, ProfessionStandardHistory,
+    ProfessionStandardId, ProfessionType,
+};
 use crate::member::MemberId;
 use async_trait::async_trait;
 use shared::Result;
@@ -15,10 +18,10 @@ pub trait ProfessionStandardRepository: Send + Sync {
     async fn find_by_id(&self, id: &ProfessionStandardId) -> Result<Option<ProfessionStandardEntity>>;
 
     /// 根据职业类型查找当前活跃的标准
-    async fn find_active_by_profession(&self, profession_type: ProfessionType) -> Result<Option<ProfessionStandardEntity>>;
+    async fn find_active_by_profession(&self, profession_type: &ProfessionType) -> Result<Option<ProfessionStandardEntity>>;
 
     /// 根据职业类型查找所有标准（包括历史）
-    async fn find_all_by_profession(&self, profession_type: ProfessionType) -> Result<Vec<ProfessionStandardEntity>>;
+    async fn find_all_by_profession(&self, profession_type: &ProfessionType) -> Result<Vec<ProfessionStandardEntity>>;
 
     /// 查找决策者管理的所有职业标准
     async fn find_by_manager(&self, manager_id: &MemberId) -> Result<Vec<ProfessionStandardEntity>>;
@@ -32,9 +35,51 @@ pub trait ProfessionStandardRepository: Send + Sync {
     /// 保存变更历史
     async fn save_history(&self, history: &ProfessionStandardHistory) -> Result<()>;
 
+    /// 保存新职业标准并记录变更历史（原子操作）
+    async fn save_with_history(
+        &self,
+        standard: &ProfessionStandardEntity,
+        history: &ProfessionStandardHistory,
+    ) -> Result<()>;
+
+    /// 更新职业标准并记录变更历史（原子操作）
+    async fn update_with_history(
+        &self,
+        standard: &ProfessionStandardEntity,
+        history: &ProfessionStandardHistory,
+    ) -> Result<()>;
+
     /// 获取职业标准的变更历史
     async fn get_history(&self, standard_id: &ProfessionStandardId) -> Result<Vec<ProfessionStandardHistory>>;
 
     /// 获取所有活跃的职业标准
     async fn find_all_active(&self) -> Result<Vec<ProfessionStandardEntity>>;
-}
\ No newline at end of file
+}
+
+/// 职业目录Repository trait
+#[async_trait]
+pub trait ProfessionCatalogRepository: Send + Sync {
+    /// 根据编码查找职业
+    async fn find_profession(&self, code: &ProfessionType) -> Result<Option<Profession>>;
+
+    /// 列出职业，`include_inactive` 为 false 时只返回启用的职业
+    async fn list_professions(&self, include_inactive: bool) -> Result<Vec<Profession>>;
+
+    /// 新增职业
+    async fn create_profession(&self, profession: &Profession) -> Result<()>;
+
+    /// 更新职业
+    async fn update_profession(&self, profession: &Profession) -> Result<()>;
+
+    /// 根据编码查找职业分类
+    async fn find_category(&self, code: &str) -> Result<Option<ProfessionCategory>>;
+
+    /// 列出所有职业分类
+    async fn list_categories(&self) -> Result<Vec<ProfessionCategory>>;
+
+    /// 新增职业分类
+    async fn create_category(&self, category: &ProfessionCategory) -> Result<()>;
+
+    /// 更新职业分类
+    async fn update_category(&self, category: &ProfessionCategory) -> Result<()>;
+}
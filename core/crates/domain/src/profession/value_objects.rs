@@ -1,118 +1,76 @@
-//! 职业值对象和常量
+//! 职业值对象
 
-use crate::isu::ISURate;
-use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
 use shared::{AppError, Result};
 
-/// 职业类型（MVP版本硬编码5种）
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
-#[serde(rename_all = "snake_case")]
-pub enum ProfessionType {
-    Cleaning,      // 清洁服务
-    BasicRepair,   // 基础维修
-    HomeTutoring,  // 家庭教学
-    Documentation, // 文档/翻译
-    Cooking,       // 烹饪服务
-}
+/// 职业编码最大长度（与 professions.code 列一致）
+const MAX_CODE_LENGTH: usize = 50;
+
+/// 职业类型
+///
+/// 对应职业目录（`professions` 表）中的职业编码，例如 `cleaning`、`plumber`。
+/// 这里只校验编码格式，职业是否存在、是否启用由职业目录判断。
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct ProfessionType(String);
 
 impl ProfessionType {
-    /// 获取职业的显示名称
-    pub fn display_name(&self) -> &'static str {
-        match self {
-            Self::Cleaning => "清洁服务",
-            Self::BasicRepair => "基础维修",
-            Self::HomeTutoring => "家庭教学",
-            Self::Documentation => "文档/翻译",
-            Self::Cooking => "烹饪服务",
-        }
+    /// 获取职业编码
+    pub fn as_str(&self) -> &str {
+        &self.0
     }
+}
 
-    /// 获取职业的默认ISU费率
-    pub fn default_rate(&self) -> Result<ISURate> {
-        let rate = match self {
-            Self::Cleaning => 1.0,
-            Self::BasicRepair => 1.5,
-            Self::HomeTutoring => 2.0,
-            Self::Documentation => 1.8,
-            Self::Cooking => 1.2,
-        };
-        ISURate::from_f64(rate)
+/// 校验目录编码：小写字母开头，仅包含小写字母、数字和下划线
+pub(crate) fn validate_code(code: &str, kind: &str) -> Result<()> {
+    if code.is_empty() || code.len() > MAX_CODE_LENGTH {
+        return Err(AppError::validation(format!(
+            "{}编码长度必须在1-{}个字符之间",
+            kind, MAX_CODE_LENGTH
+        )));
     }
 
-    /// 获取所有职业类型
-    pub fn all() -> Vec<ProfessionType> {
-        vec![
-            Self::Cleaning,
-            Self::BasicRepair,
-            Self::HomeTutoring,
-            Self::Documentation,
-            Self::Cooking,
-        ]
+    let mut chars = code.chars();
+    let starts_with_letter = chars.next().is_some_and(|c| c.is_ascii_lowercase());
+    if !starts_with_letter
+        || !chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+    {
+        return Err(AppError::validation(format!(
+            "{}编码只能包含小写字母、数字和下划线，且以字母开头: {}",
+            kind, code
+        )));
     }
 
-    /// 从字符串解析职业类型
-    pub fn from_str(s: &str) -> Result<Self> {
-        match s {
-            "cleaning" => Ok(Self::Cleaning),
-            "basic_repair" => Ok(Self::BasicRepair),
-            "home_tutoring" => Ok(Self::HomeTutoring),
-            "documentation" => Ok(Self::Documentation),
-            "cooking" => Ok(Self::Cooking),
-            _ => Err(AppError::validation(format!("未知的职业类型: {}", s))),
-        }
-    }
+    Ok(())
 }
 
-impl std::fmt::Display for ProfessionType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let name = match self {
-            Self::Cleaning => "cleaning",
-            Self::BasicRepair => "basic_repair",
-            Self::HomeTutoring => "home_tutoring",
-            Self::Documentation => "documentation",
-            Self::Cooking => "cooking",
-        };
-        write!(f, "{}", name)
+impl std::str::FromStr for ProfessionType {
+    type Err = AppError;
+
+    /// 从字符串解析职业类型
+    fn from_str(s: &str) -> Result<Self> {
+        validate_code(s, "职业")?;
+        Ok(Self(s.to_string()))
     }
 }
 
-/// 职业标准（将费率与职业绑定）
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct ProfessionStandard {
-    pub profession_type: ProfessionType,
-    pub isu_rate: ISURate,
-    pub description: String,
-}
+impl TryFrom<String> for ProfessionType {
+    type Error = AppError;
 
-impl ProfessionStandard {
-    /// 创建默认的职业标准
-    pub fn new_default(profession_type: ProfessionType) -> Result<Self> {
-        let isu_rate = profession_type.default_rate()?;
-        let description = format!("{}的标准费率", profession_type.display_name());
-        
-        Ok(Self {
-            profession_type,
-            isu_rate,
-            description,
-        })
+    fn try_from(value: String) -> Result<Self> {
+        validate_code(&value, "职业")?;
+        Ok(Self(value))
     }
+}
 
-    /// 创建自定义职业标准
-    pub fn new_custom(
-        profession_type: ProfessionType,
-        isu_rate: ISURate,
-        description: String,
-    ) -> Self {
-        Self {
-            profession_type,
-            isu_rate,
-            description,
-        }
+impl From<ProfessionType> for String {
+    fn from(value: ProfessionType) -> Self {
+        value.0
     }
+}
 
-    /// 根据工时计算总ISU
-    pub fn calculate_total_isu(&self, hours: Decimal) -> Result<crate::isu::ISU> {
-        self.isu_rate.calculate_total(hours)
+impl std::fmt::Display for ProfessionType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,251 @@
+//! Review实体
+
+use super::ReviewId;
+use crate::member::MemberId;
+use crate::service::ServiceId;
+use crate::transaction::{Transaction, TransactionId, TransactionItemType};
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use shared::{AppError, Result};
+
+/// 评价内容最大长度
+const MAX_COMMENT_LENGTH: usize = 2000;
+
+/// 评分（1-5 星）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Rating(u8);
+
+impl Rating {
+    pub const MIN: u8 = 1;
+    pub const MAX: u8 = 5;
+
+    pub fn new(value: u8) -> Result<Self> {
+        if !(Self::MIN..=Self::MAX).contains(&value) {
+            return Err(AppError::validation(format!(
+                "评分必须在{}到{}星之间",
+                Self::MIN,
+                Self::MAX
+            )));
+        }
+        Ok(Self(value))
+    }
+
+    pub fn value(&self) -> u8 {
+        self.0
+    }
+}
+
+/// 评分汇总（评价数与星级总和）
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RatingSummary {
+    pub count: u32,
+    pub total: u32,
+}
+
+impl RatingSummary {
+    /// 平均评分（保留两位小数），没有评价时为 `None`
+    pub fn average(&self) -> Option<Decimal> {
+        if self.count == 0 {
+            return None;
+        }
+        Some((Decimal::from(self.total) / Decimal::from(self.count)).round_dp(2))
+    }
+}
+
+/// 评价聚合根
+///
+/// 交易完成后买卖双方各可评价对方一次。买家对服务交易的评价同时计入该服务的评分。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Review {
+    pub id: ReviewId,
+    pub transaction_id: TransactionId,
+    pub reviewer_id: MemberId,
+    pub reviewee_id: MemberId,
+    pub service_id: Option<ServiceId>,
+    pub rating: Rating,
+    pub comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Review {
+    /// 交易参与者评价交易对方
+    pub fn new(
+        transaction: &Transaction,
+        reviewer_id: MemberId,
+        rating: Rating,
+        comment: Option<String>,
+    ) -> Result<Self> {
+        if !transaction.is_completed() {
+            return Err(AppError::validation("只有已完成的交易才能评价"));
+        }
+
+        let reviewee_id = if transaction.is_buyer(&reviewer_id) {
+            transaction.seller_id
+        } else if transaction.is_seller(&reviewer_id) {
+            transaction.buyer_id
+        } else {
+            return Err(AppError::forbidden("只有交易参与者可以评价"));
+        };
+
+        let comment = comment
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty());
+        if let Some(comment) = &comment {
+            if comment.chars().count() > MAX_COMMENT_LENGTH {
+                return Err(AppError::validation(format!(
+                    "评价内容不能超过{}个字符",
+                    MAX_COMMENT_LENGTH
+                )));
+            }
+        }
+
+        // 只有买家对服务提供者的评价计入服务评分
+        let service_id = match &transaction.item_type {
+            TransactionItemType::Service(service_id) if transaction.is_buyer(&reviewer_id) => {
+                Some(*service_id)
+            }
+            _ => None,
+        };
+
+        Ok(Self {
+            id: ReviewId::new(),
+            transaction_id: transaction.id,
+            reviewer_id,
+            reviewee_id,
+            service_id,
+            rating,
+            comment,
+            created_at: Utc::now(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::ToolId;
+
+    fn service_transaction() -> Transaction {
+        Transaction::new(
+            MemberId::new(),
+            MemberId::new(),
+            TransactionItemType::Service(ServiceId::new()),
+            Default::default(),
+            None,
+        )
+        .unwrap()
+    }
+
+    fn completed(mut transaction: Transaction) -> Transaction {
+        transaction.confirm().unwrap();
+        transaction.start().unwrap();
+        transaction.complete().unwrap();
+        transaction
+    }
+
+    fn rating(value: u8) -> Rating {
+        Rating::new(value).unwrap()
+    }
+
+    #[test]
+    fn rating_must_be_between_one_and_five_stars() {
+        assert!(Rating::new(0).is_err());
+        assert!(Rating::new(6).is_err());
+        for value in Rating::MIN..=Rating::MAX {
+            assert_eq!(Rating::new(value).unwrap().value(), value);
+        }
+    }
+
+    #[test]
+    fn average_is_none_without_reviews() {
+        assert_eq!(RatingSummary::default().average(), None);
+    }
+
+    #[test]
+    fn average_is_rounded_to_two_decimal_places() {
+        assert_eq!(RatingSummary { count: 2, total: 9 }.average(), Some(Decimal::new(45, 1)));
+        assert_eq!(RatingSummary { count: 3, total: 14 }.average(), Some(Decimal::new(467, 2)));
+    }
+
+    #[test]
+    fn only_completed_transactions_can_be_reviewed() {
+        let mut transaction = service_transaction();
+        let buyer = transaction.buyer_id;
+        assert!(Review::new(&transaction, buyer, rating(5), None).is_err());
+
+        transaction.confirm().unwrap();
+        transaction.start().unwrap();
+        assert!(Review::new(&transaction, buyer, rating(5), None).is_err());
+
+        transaction.dispute().unwrap();
+        assert!(Review::new(&transaction, buyer, rating(5), None).is_err());
+    }
+
+    #[test]
+    fn only_participants_can_review() {
+        let transaction = completed(service_transaction());
+
+        let result = Review::new(&transaction, MemberId::new(), rating(5), None);
+
+        assert!(matches!(result, Err(AppError::Forbidden(_))));
+    }
+
+    #[test]
+    fn buyer_reviews_the_seller_and_rates_the_service() {
+        let transaction = completed(service_transaction());
+
+        let review = Review::new(&transaction, transaction.buyer_id, rating(4), None).unwrap();
+
+        assert_eq!(review.reviewee_id, transaction.seller_id);
+        assert_eq!(review.transaction_id, transaction.id);
+        assert_eq!(review.rating.value(), 4);
+        assert!(matches!(
+            transaction.item_type,
+            TransactionItemType::Service(service_id) if review.service_id == Some(service_id)
+        ));
+    }
+
+    #[test]
+    fn seller_review_of_the_buyer_does_not_rate_the_service() {
+        let transaction = completed(service_transaction());
+
+        let review = Review::new(&transaction, transaction.seller_id, rating(3), None).unwrap();
+
+        assert_eq!(review.reviewee_id, transaction.buyer_id);
+        assert_eq!(review.service_id, None);
+    }
+
+    #[test]
+    fn tool_rental_reviews_have_no_service() {
+        let transaction = completed(
+            Transaction::new(
+                MemberId::new(),
+                MemberId::new(),
+                TransactionItemType::Tool(ToolId::new()),
+                Default::default(),
+                None,
+            )
+            .unwrap(),
+        );
+
+        let review = Review::new(&transaction, transaction.buyer_id, rating(5), None).unwrap();
+
+        assert_eq!(review.service_id, None);
+    }
+
+    #[test]
+    fn comment_is_trimmed_and_limited() {
+        let transaction = completed(service_transaction());
+        let buyer = transaction.buyer_id;
+
+        let review = Review::new(&transaction, buyer, rating(5), Some("  很好  ".to_string())).unwrap();
+        assert_eq!(review.comment.as_deref(), Some("很好"));
+
+        let review = Review::new(&transaction, buyer, rating(5), Some("   ".to_string())).unwrap();
+        assert_eq!(review.comment, None);
+
+        let too_long = "好".repeat(MAX_COMMENT_LENGTH + 1);
+        assert!(Review::new(&transaction, buyer, rating(5), Some(too_long)).is_err());
+    }
+}
@@ -0,0 +1,12 @@
+//! Review模块 - 交易评价与评分
+
+pub mod entity;
+pub mod repository;
+
+// 重导出
+pub use entity::{Rating, RatingSummary, Review};
+pub use repository::ReviewRepository;
+
+// ID类型定义
+use shared::Id;
+pub type ReviewId = Id<Review>;
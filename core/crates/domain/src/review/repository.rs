@@ -0,0 +1,32 @@
+//! Review Repository接口
+
+use super::Review;
+use crate::member::MemberId;
+use crate::transaction::TransactionId;
+use async_trait::async_trait;
+use shared::Result;
+
+/// 评价Repository trait
+#[async_trait]
+pub trait ReviewRepository: Send + Sync {
+    /// 保存评价，并同步累加被评价会员（及服务）的评分汇总
+    async fn save(&self, review: &Review) -> Result<()>;
+
+    /// 查找会员对某笔交易的评价
+    async fn find_by_transaction_and_reviewer(
+        &self,
+        transaction_id: &TransactionId,
+        reviewer_id: &MemberId,
+    ) -> Result<Option<Review>>;
+
+    /// 查找某笔交易的全部评价
+    async fn find_by_transaction_id(&self, transaction_id: &TransactionId) -> Result<Vec<Review>>;
+
+    /// 查找会员收到的评价（按时间倒序分页）
+    async fn find_by_reviewee(
+        &self,
+        reviewee_id: &MemberId,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<Review>>;
+}
@@ -1,9 +1,10 @@
 //! Service实体
 
 use super::ServiceId;
-use crate::isu::ISU;
+use crate::isu::{ISURate, ISU};
 use crate::member::MemberId;
 use crate::profession::ProfessionType;
+use crate::review::RatingSummary;
 use chrono::{DateTime, Utc};
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
@@ -18,8 +19,12 @@ pub struct Service {
     pub title: String,
     pub description: String,
     pub estimated_hours: Decimal,
+    /// 发布时使用的费率快照，后续费率调整不会影响已发布的服务
+    pub isu_rate: ISURate,
     pub total_isu: ISU,
     pub status: ServiceStatus,
+    /// 买家评价汇总（由评价写入时同步累加，服务更新不会修改）
+    pub rating: RatingSummary,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -51,14 +56,31 @@ impl std::fmt::Display for ServiceStatus {
     }
 }
 
+impl std::str::FromStr for ServiceStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "available" => Ok(Self::Available),
+            "in_progress" => Ok(Self::InProgress),
+            "completed" => Ok(Self::Completed),
+            "cancelled" => Ok(Self::Cancelled),
+            _ => Err(AppError::validation(format!("无效的服务状态: {}", s))),
+        }
+    }
+}
+
 impl Service {
     /// 创建新服务
+    ///
+    /// `isu_rate` 由定价服务根据职业标准给出，并作为快照保存在服务上。
     pub fn new(
         provider_id: MemberId,
         profession_type: ProfessionType,
         title: String,
         description: String,
         estimated_hours: Decimal,
+        isu_rate: ISURate,
     ) -> Result<Self> {
         // 验证输入
         if title.trim().is_empty() {
@@ -69,9 +91,8 @@ impl Service {
             return Err(AppError::validation("预估时长必须大于0"));
         }
 
-        // 计算总ISU（使用职业默认费率）
-        let profession_standard = crate::profession::ProfessionStandard::new_default(profession_type)?;
-        let total_isu = profession_standard.calculate_total_isu(estimated_hours)?;
+        // 按费率快照计算总ISU
+        let total_isu = isu_rate.calculate_total(estimated_hours)?;
 
         let now = Utc::now();
         Ok(Self {
@@ -81,8 +102,10 @@ impl Service {
             title: title.trim().to_string(),
             description: description.trim().to_string(),
             estimated_hours,
+            isu_rate,
             total_isu,
             status: ServiceStatus::default(),
+            rating: RatingSummary::default(),
             created_at: now,
             updated_at: now,
         })
@@ -117,9 +140,8 @@ impl Service {
             }
             self.estimated_hours = new_hours;
             
-            // 重新计算总ISU
-            let profession_standard = crate::profession::ProfessionStandard::new_default(self.profession_type)?;
-            self.total_isu = profession_standard.calculate_total_isu(new_hours)?;
+            // 按发布时的费率快照重新计算总ISU
+            self.total_isu = self.isu_rate.calculate_total(new_hours)?;
             updated = true;
         }
 
@@ -154,15 +176,27 @@ impl Service {
         }
     }
 
-    /// 取消服务
+    /// 交易被撤销（争议裁决全额退款）后，服务恢复可用
+    pub fn reopen(&mut self) -> Result<()> {
+        match self.status {
+            ServiceStatus::InProgress => {
+                self.status = ServiceStatus::Available;
+                self.updated_at = Utc::now();
+                Ok(())
+            }
+            _ => Err(AppError::validation("只有进行中的服务才能恢复可用")),
+        }
+    }
+
+    /// 取消服务（进行中的服务有托管中的交易，需先结束交易）
     pub fn cancel(&mut self) -> Result<()> {
         match self.status {
-            ServiceStatus::Available | ServiceStatus::InProgress => {
+            ServiceStatus::Available => {
                 self.status = ServiceStatus::Cancelled;
                 self.updated_at = Utc::now();
                 Ok(())
             }
-            _ => Err(AppError::validation("已完成的服务不能取消")),
+            _ => Err(AppError::validation("只有可用状态的服务才能取消")),
         }
     }
 
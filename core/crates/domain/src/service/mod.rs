@@ -5,7 +5,7 @@ pub mod repository;
 
 // 重导出
 pub use entity::{Service, ServiceStatus};
-pub use repository::ServiceRepository;
+pub use repository::{ServiceRepository, ServiceSearchCriteria, ServiceSort};
 
 // ID类型定义
 use shared::Id;
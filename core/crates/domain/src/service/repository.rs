@@ -4,7 +4,50 @@ use super::{Service, ServiceId};
 use crate::member::MemberId;
 use crate::profession::ProfessionType;
 use async_trait::async_trait;
-use shared::Result;
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+
+/// 服务搜索排序方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ServiceSort {
+    /// 按关键词相关度（没有关键词时按发布时间）
+    #[default]
+    Relevance,
+    /// 按服务提供者信誉分从高到低
+    ProviderReputation,
+}
+
+impl std::fmt::Display for ServiceSort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Relevance => write!(f, "relevance"),
+            Self::ProviderReputation => write!(f, "reputation"),
+        }
+    }
+}
+
+impl std::str::FromStr for ServiceSort {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "relevance" => Ok(Self::Relevance),
+            "reputation" => Ok(Self::ProviderReputation),
+            _ => Err(AppError::validation(format!("无效的排序方式: {}", s))),
+        }
+    }
+}
+
+/// 服务搜索条件
+#[derive(Debug, Clone, Default)]
+pub struct ServiceSearchCriteria {
+    /// 搜索关键词，为空时不按关键词过滤
+    pub keyword: String,
+    pub profession_type: Option<ProfessionType>,
+    /// 服务提供者的最低信誉分
+    pub min_provider_reputation: Option<Decimal>,
+    pub sort: ServiceSort,
+}
 
 /// 服务Repository trait
 #[async_trait]
@@ -21,7 +64,7 @@ pub trait ServiceRepository: Send + Sync {
     /// 根据职业类型查找可用服务
     async fn find_available_by_profession(
         &self,
-        profession_type: ProfessionType,
+        profession_type: &ProfessionType,
         limit: Option<u32>,
         offset: Option<u32>,
     ) -> Result<Vec<Service>>;
@@ -33,11 +76,10 @@ pub trait ServiceRepository: Send + Sync {
         offset: Option<u32>,
     ) -> Result<Vec<Service>>;
 
-    /// 根据关键词搜索服务
+    /// 按关键词、职业和提供者信誉搜索可用服务
     async fn search_services(
         &self,
-        keyword: &str,
-        profession_type: Option<ProfessionType>,
+        criteria: &ServiceSearchCriteria,
         limit: Option<u32>,
         offset: Option<u32>,
     ) -> Result<Vec<Service>>;
@@ -1,9 +1,12 @@
 //! Tool 实体
 
-use super::{Money, ToolId, ToolStatus};
+use super::{Money, RentalPeriod, ToolId, ToolStatus};
+use crate::isu::ISU;
 use crate::member::MemberId;
 use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
+use shared::{AppError, Result};
 
 /// 工具聚合根
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -14,6 +17,10 @@ pub struct Tool {
     pub description: Option<String>,
     pub category: String,
     pub price: Money,
+    /// 每日租金（ISU），未设置时工具不可租用
+    pub daily_rate: Option<ISU>,
+    /// 可退还押金（ISU），为零表示无需押金
+    pub deposit: ISU,
     pub status: ToolStatus,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -36,6 +43,8 @@ impl Tool {
             description,
             category,
             price,
+            daily_rate: None,
+            deposit: ISU::default(),
             status: ToolStatus::default(),
             created_at: now,
             updated_at: now,
@@ -65,6 +74,34 @@ impl Tool {
         self.updated_at = Utc::now();
     }
 
+    /// 设置租用条款
+    pub fn set_rental_terms(&mut self, daily_rate: Option<ISU>, deposit: ISU) -> Result<()> {
+        if daily_rate.is_some_and(|rate| rate.value().is_zero()) {
+            return Err(AppError::validation("每日租金必须大于0"));
+        }
+        self.daily_rate = daily_rate;
+        self.deposit = deposit;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 检查是否接受租用预订（已设置租金且未下架）
+    pub fn is_rentable(&self) -> bool {
+        self.daily_rate.is_some() && self.status != ToolStatus::Unavailable
+    }
+
+    /// 按每日租金计算租用期的租金
+    pub fn rental_fee(&self, period: &RentalPeriod) -> Result<ISU> {
+        self.daily_rate
+            .ok_or_else(|| AppError::validation("工具未设置租金，暂不可租用"))?
+            .multiply(Decimal::from(period.days()))
+    }
+
+    /// 检查是否为工具所有者
+    pub fn is_owned_by(&self, member_id: &MemberId) -> bool {
+        &self.owner_id == member_id
+    }
+
     fn change_status(&mut self, new_status: ToolStatus) {
         self.status = new_status;
         self.updated_at = Utc::now();
@@ -90,3 +127,49 @@ impl Tool {
         self.status == ToolStatus::Available
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tool::Currency;
+    use chrono::NaiveDate;
+
+    fn tool(daily_rate: Option<Decimal>) -> Tool {
+        let mut tool = Tool::new(
+            MemberId::new(),
+            "电钻".to_string(),
+            None,
+            "电动工具".to_string(),
+            Money::new(10000, Currency::CNY).unwrap(),
+        );
+        tool.daily_rate = daily_rate.map(|rate| ISU::new(rate).unwrap());
+        tool
+    }
+
+    fn period(start_day: u32, end_day: u32) -> RentalPeriod {
+        RentalPeriod::new(
+            NaiveDate::from_ymd_opt(2025, 3, start_day).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 3, end_day).unwrap(),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn same_day_rental_charges_one_day() {
+        let fee = tool(Some(Decimal::new(1250, 2))).rental_fee(&period(5, 5)).unwrap();
+        assert_eq!(fee.value(), Decimal::new(1250, 2));
+    }
+
+    #[test]
+    fn multi_day_rental_charges_whole_days() {
+        let fee = tool(Some(Decimal::new(1250, 2))).rental_fee(&period(5, 7)).unwrap();
+        assert_eq!(fee.value(), Decimal::new(3750, 2));
+    }
+
+    #[test]
+    fn tool_without_daily_rate_is_not_rentable() {
+        let tool = tool(None);
+        assert!(!tool.is_rentable());
+        assert!(tool.rental_fee(&period(5, 5)).is_err());
+    }
+}
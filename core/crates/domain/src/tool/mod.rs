@@ -1,12 +1,19 @@
 //! 工具聚合根
 
 mod entity;
+mod rental;
 mod repository;
+mod reservation;
 mod value_objects;
 
 pub use entity::Tool;
-pub use repository::ToolRepository;
+pub use rental::{DepositStatus, RentalPeriod, ToolRental, MAX_RENTAL_DAYS};
+pub use repository::{ToolRentalRepository, ToolRepository, ToolReservationRepository};
+pub use reservation::{
+    ReservationKind, ReservationWindow, ToolAvailability, ToolReservation, MAX_AVAILABILITY_DAYS,
+};
 pub use value_objects::{Currency, Money, ToolStatus};
 
 // 类型别名
 pub type ToolId = shared::Id<Tool>;
+pub type ReservationId = shared::Id<ToolReservation>;
@@ -0,0 +1,223 @@
+//! 工具租用
+
+use super::{Tool, ToolId};
+use crate::isu::ISU;
+use crate::member::MemberId;
+use crate::transaction::TransactionId;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use shared::{AppError, Result};
+
+/// 单次租用最长天数
+pub const MAX_RENTAL_DAYS: i64 = 90;
+
+/// 租用期（按自然日计，首尾两天都包含在内）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RentalPeriod {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+impl RentalPeriod {
+    /// 创建租用期
+    pub fn new(start_date: NaiveDate, end_date: NaiveDate) -> Result<Self> {
+        if end_date < start_date {
+            return Err(AppError::validation("结束日期不能早于开始日期"));
+        }
+
+        let period = Self {
+            start_date,
+            end_date,
+        };
+        if period.days() > MAX_RENTAL_DAYS {
+            return Err(AppError::validation(format!(
+                "单次租用不能超过{}天",
+                MAX_RENTAL_DAYS
+            )));
+        }
+
+        Ok(period)
+    }
+
+    /// 租用天数
+    pub fn days(&self) -> i64 {
+        (self.end_date - self.start_date).num_days() + 1
+    }
+}
+
+/// 押金状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DepositStatus {
+    NotRequired, // 无需押金
+    #[default]
+    Pending,     // 待收取（工具所有者确认时冻结）
+    Held,        // 托管中
+    Refunded,    // 已退还
+}
+
+impl std::fmt::Display for DepositStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotRequired => write!(f, "not_required"),
+            Self::Pending => write!(f, "pending"),
+            Self::Held => write!(f, "held"),
+            Self::Refunded => write!(f, "refunded"),
+        }
+    }
+}
+
+impl std::str::FromStr for DepositStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "not_required" => Ok(Self::NotRequired),
+            "pending" => Ok(Self::Pending),
+            "held" => Ok(Self::Held),
+            "refunded" => Ok(Self::Refunded),
+            _ => Err(AppError::validation(format!("无效的押金状态: {}", s))),
+        }
+    }
+}
+
+/// 工具租用记录（与工具类交易一一对应）
+///
+/// 租金和押金在下单时按工具当时的租用条款计算并保存快照，
+/// 工具所有者确认时冻结，归还时租金放款给所有者、押金退还租用者。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolRental {
+    pub transaction_id: TransactionId,
+    pub tool_id: ToolId,
+    pub renter_id: MemberId,
+    pub period: RentalPeriod,
+    pub daily_rate: ISU,
+    pub rental_fee: ISU,
+    pub deposit: ISU,
+    pub deposit_status: DepositStatus,
+    pub returned_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ToolRental {
+    /// 按工具的租用条款创建租用记录
+    pub fn new(
+        transaction_id: TransactionId,
+        tool: &Tool,
+        renter_id: MemberId,
+        period: RentalPeriod,
+    ) -> Result<Self> {
+        let rental_fee = tool.rental_fee(&period)?;
+        let daily_rate = tool
+            .daily_rate
+            .ok_or_else(|| AppError::validation("工具未设置租金，暂不可租用"))?;
+        let deposit_status = if tool.deposit.value().is_zero() {
+            DepositStatus::NotRequired
+        } else {
+            DepositStatus::Pending
+        };
+
+        let now = Utc::now();
+        Ok(Self {
+            transaction_id,
+            tool_id: tool.id,
+            renter_id,
+            period,
+            daily_rate,
+            rental_fee,
+            deposit: tool.deposit,
+            deposit_status,
+            returned_at: None,
+            created_at: now,
+            updated_at: now,
+        })
+    }
+
+    /// 租金与押金合计（开始租用时需要支付的金额）
+    pub fn total_due(&self) -> Result<ISU> {
+        self.rental_fee.add(&self.deposit)
+    }
+
+    /// 是否需要收取押金
+    pub fn requires_deposit(&self) -> bool {
+        self.deposit_status != DepositStatus::NotRequired
+    }
+
+    /// 押金已冻结
+    pub fn hold_deposit(&mut self) -> Result<()> {
+        match self.deposit_status {
+            DepositStatus::Pending => {
+                self.deposit_status = DepositStatus::Held;
+                self.updated_at = Utc::now();
+                Ok(())
+            }
+            _ => Err(AppError::validation("押金不处于待收取状态")),
+        }
+    }
+
+    /// 租用取消，已冻结的押金退还
+    pub fn refund_deposit(&mut self) -> Result<()> {
+        match self.deposit_status {
+            DepositStatus::Held => {
+                self.deposit_status = DepositStatus::Refunded;
+                self.updated_at = Utc::now();
+                Ok(())
+            }
+            _ => Err(AppError::validation("押金不处于托管状态")),
+        }
+    }
+
+    /// 登记归还，托管中的押金随之退还
+    pub fn mark_returned(&mut self) -> Result<()> {
+        if self.returned_at.is_some() {
+            return Err(AppError::validation("工具已归还"));
+        }
+
+        match self.deposit_status {
+            DepositStatus::Held => self.deposit_status = DepositStatus::Refunded,
+            DepositStatus::NotRequired => {}
+            _ => return Err(AppError::validation("押金状态异常，无法登记归还")),
+        }
+
+        let now = Utc::now();
+        self.returned_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn same_day_period_counts_as_one_day() {
+        let period = RentalPeriod::new(date(2025, 3, 1), date(2025, 3, 1)).unwrap();
+        assert_eq!(period.days(), 1);
+    }
+
+    #[test]
+    fn multi_day_period_includes_both_ends() {
+        let period = RentalPeriod::new(date(2025, 2, 27), date(2025, 3, 2)).unwrap();
+        assert_eq!(period.days(), 4);
+    }
+
+    #[test]
+    fn rejects_end_before_start() {
+        assert!(RentalPeriod::new(date(2025, 3, 2), date(2025, 3, 1)).is_err());
+    }
+
+    #[test]
+    fn rejects_period_longer_than_max_days() {
+        let start = date(2025, 1, 1);
+        let last_allowed = start + chrono::Duration::days(MAX_RENTAL_DAYS - 1);
+
+        assert_eq!(RentalPeriod::new(start, last_allowed).unwrap().days(), MAX_RENTAL_DAYS);
+        assert!(RentalPeriod::new(start, last_allowed + chrono::Duration::days(1)).is_err());
+    }
+}
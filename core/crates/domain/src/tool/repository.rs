@@ -1,7 +1,8 @@
 //! Tool Repository trait
 
-use super::{Tool, ToolId};
+use super::{ReservationId, ReservationWindow, Tool, ToolId, ToolRental, ToolReservation};
 use crate::member::MemberId;
+use crate::transaction::TransactionId;
 use async_trait::async_trait;
 use shared::Result;
 
@@ -14,6 +15,9 @@ pub trait ToolRepository: Send + Sync {
     /// 根据 ID 查找
     async fn find_by_id(&self, id: ToolId) -> Result<Option<Tool>>;
 
+    /// 根据 ID 查找并锁定工具（在工作单元中使用，串行化同一工具的预订）
+    async fn find_by_id_for_update(&self, id: ToolId) -> Result<Option<Tool>>;
+
     /// 根据所有者查找
     async fn find_by_owner(
         &self,
@@ -28,9 +32,44 @@ pub trait ToolRepository: Send + Sync {
     /// 更新工具
     async fn update(&self, tool: &Tool) -> Result<()>;
 
-    /// 删除工具
+    /// 删除工具（软删除，保留租用和预订记录）
     async fn delete(&self, id: ToolId) -> Result<()>;
 
     /// 统计总数
     async fn count(&self) -> Result<i64>;
 }
+
+/// 工具租用仓储接口
+#[async_trait]
+pub trait ToolRentalRepository: Send + Sync {
+    /// 保存租用记录
+    async fn save(&self, rental: &ToolRental) -> Result<()>;
+
+    /// 根据交易ID查找租用记录
+    async fn find_by_transaction_id(&self, transaction_id: &TransactionId) -> Result<Option<ToolRental>>;
+
+    /// 更新租用记录
+    async fn update(&self, rental: &ToolRental) -> Result<()>;
+}
+
+/// 工具预订仓储接口
+#[async_trait]
+pub trait ToolReservationRepository: Send + Sync {
+    /// 保存预订（与未释放的预订重叠时返回验证错误）
+    async fn save(&self, reservation: &ToolReservation) -> Result<()>;
+
+    /// 根据 ID 查找
+    async fn find_by_id(&self, id: ReservationId) -> Result<Option<ToolReservation>>;
+
+    /// 查找交易对应的租用预订
+    async fn find_by_transaction_id(&self, transaction_id: &TransactionId) -> Result<Option<ToolReservation>>;
+
+    /// 查找与时间窗重叠、尚未释放的预订
+    async fn find_active_overlapping(&self, tool_id: ToolId, window: &ReservationWindow) -> Result<Vec<ToolReservation>>;
+
+    /// 查找工具所有未释放的预订
+    async fn find_active_by_tool(&self, tool_id: ToolId) -> Result<Vec<ToolReservation>>;
+
+    /// 更新预订（释放）
+    async fn update(&self, reservation: &ToolReservation) -> Result<()>;
+}
@@ -0,0 +1,301 @@
+//! 工具预订（可用性日历）
+
+use super::{RentalPeriod, ReservationId, ToolId};
+use crate::member::MemberId;
+use crate::transaction::TransactionId;
+use chrono::{DateTime, Duration, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use shared::{AppError, Result};
+
+/// 单次查询可用性的最长跨度（天）
+pub const MAX_AVAILABILITY_DAYS: i64 = 366;
+
+/// 预订时间窗（左闭右开 `[starts_at, ends_at)`，对应数据库中的 tstzrange）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReservationWindow {
+    pub starts_at: DateTime<Utc>,
+    pub ends_at: DateTime<Utc>,
+}
+
+impl ReservationWindow {
+    /// 创建时间窗
+    pub fn new(starts_at: DateTime<Utc>, ends_at: DateTime<Utc>) -> Result<Self> {
+        if ends_at <= starts_at {
+            return Err(AppError::validation("结束时间必须晚于开始时间"));
+        }
+        Ok(Self { starts_at, ends_at })
+    }
+
+    /// 租用期对应的时间窗：从开始日零点（UTC）到结束日次日零点
+    pub fn from_rental_period(period: &RentalPeriod) -> Self {
+        Self {
+            starts_at: period.start_date.and_time(NaiveTime::MIN).and_utc(),
+            ends_at: (period.end_date + Duration::days(1))
+                .and_time(NaiveTime::MIN)
+                .and_utc(),
+        }
+    }
+
+    /// 检查两个时间窗是否重叠（首尾相接不算重叠）
+    pub fn overlaps(&self, other: &ReservationWindow) -> bool {
+        self.starts_at < other.ends_at && other.starts_at < self.ends_at
+    }
+
+    /// 时间窗长度
+    pub fn duration(&self) -> Duration {
+        self.ends_at - self.starts_at
+    }
+}
+
+/// 预订类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReservationKind {
+    Block,  // 所有者自行封锁的日期
+    Rental, // 租用者的预订（对应一笔工具租用交易）
+}
+
+impl std::fmt::Display for ReservationKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Block => write!(f, "block"),
+            Self::Rental => write!(f, "rental"),
+        }
+    }
+}
+
+impl std::str::FromStr for ReservationKind {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "block" => Ok(Self::Block),
+            "rental" => Ok(Self::Rental),
+            _ => Err(AppError::validation(format!("无效的预订类型: {}", s))),
+        }
+    }
+}
+
+/// 工具预订
+///
+/// 同一工具未释放的预订时间窗互不重叠，由数据库排他约束保证。
+/// 预订被释放（取消封锁、租用取消或提前归还）后不再占用时间。
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ToolReservation {
+    pub id: ReservationId,
+    pub tool_id: ToolId,
+    pub kind: ReservationKind,
+    pub created_by: MemberId,
+    pub transaction_id: Option<TransactionId>,
+    pub window: ReservationWindow,
+    pub note: Option<String>,
+    pub released_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ToolReservation {
+    /// 所有者封锁日期
+    pub fn block(
+        tool_id: ToolId,
+        owner_id: MemberId,
+        window: ReservationWindow,
+        note: Option<String>,
+    ) -> Self {
+        Self::new(tool_id, ReservationKind::Block, owner_id, None, window, note)
+    }
+
+    /// 租用者预订租用期
+    pub fn rental(
+        tool_id: ToolId,
+        renter_id: MemberId,
+        transaction_id: TransactionId,
+        period: &RentalPeriod,
+    ) -> Self {
+        Self::new(
+            tool_id,
+            ReservationKind::Rental,
+            renter_id,
+            Some(transaction_id),
+            ReservationWindow::from_rental_period(period),
+            None,
+        )
+    }
+
+    fn new(
+        tool_id: ToolId,
+        kind: ReservationKind,
+        created_by: MemberId,
+        transaction_id: Option<TransactionId>,
+        window: ReservationWindow,
+        note: Option<String>,
+    ) -> Self {
+        let now = Utc::now();
+        Self {
+            id: ReservationId::new(),
+            tool_id,
+            kind,
+            created_by,
+            transaction_id,
+            window,
+            note,
+            released_at: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// 是否仍占用时间
+    pub fn is_active(&self) -> bool {
+        self.released_at.is_none()
+    }
+
+    /// 释放预订
+    pub fn release(&mut self) -> Result<()> {
+        if self.released_at.is_some() {
+            return Err(AppError::validation("预订已释放"));
+        }
+        let now = Utc::now();
+        self.released_at = Some(now);
+        self.updated_at = now;
+        Ok(())
+    }
+}
+
+/// 时间窗内的工具可用性
+#[derive(Debug, Clone)]
+pub struct ToolAvailability {
+    pub tool_id: ToolId,
+    pub window: ReservationWindow,
+    /// 时间窗内仍占用时间的预订（按开始时间排序）
+    pub reservations: Vec<ToolReservation>,
+    /// 时间窗内的空闲时段
+    pub free: Vec<ReservationWindow>,
+}
+
+impl ToolAvailability {
+    /// 根据预订计算时间窗内的空闲时段
+    pub fn new(tool_id: ToolId, window: ReservationWindow, mut reservations: Vec<ToolReservation>) -> Self {
+        reservations.retain(|r| r.is_active() && r.window.overlaps(&window));
+        reservations.sort_by_key(|r| r.window.starts_at);
+
+        let mut free = Vec::new();
+        let mut cursor = window.starts_at;
+        for reservation in &reservations {
+            if reservation.window.starts_at > cursor {
+                free.push(ReservationWindow {
+                    starts_at: cursor,
+                    ends_at: reservation.window.starts_at,
+                });
+            }
+            cursor = cursor.max(reservation.window.ends_at);
+        }
+        if cursor < window.ends_at {
+            free.push(ReservationWindow {
+                starts_at: cursor,
+                ends_at: window.ends_at,
+            });
+        }
+
+        Self {
+            tool_id,
+            window,
+            reservations,
+            free,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{NaiveDate, TimeZone};
+
+    fn at(day: u32, hour: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 3, day, hour, 0, 0).unwrap()
+    }
+
+    fn window(start: DateTime<Utc>, end: DateTime<Utc>) -> ReservationWindow {
+        ReservationWindow::new(start, end).unwrap()
+    }
+
+    fn block(tool_id: ToolId, start: DateTime<Utc>, end: DateTime<Utc>) -> ToolReservation {
+        ToolReservation::block(tool_id, MemberId::new(), window(start, end), None)
+    }
+
+    #[test]
+    fn window_rejects_end_not_after_start() {
+        assert!(ReservationWindow::new(at(2, 0), at(1, 0)).is_err());
+        assert!(ReservationWindow::new(at(1, 0), at(1, 0)).is_err());
+    }
+
+    #[test]
+    fn rental_period_window_covers_whole_days() {
+        let period = RentalPeriod::new(
+            NaiveDate::from_ymd_opt(2025, 3, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2025, 3, 3).unwrap(),
+        )
+        .unwrap();
+
+        let window = ReservationWindow::from_rental_period(&period);
+        assert_eq!(window.starts_at, at(1, 0));
+        assert_eq!(window.ends_at, at(4, 0));
+        assert_eq!(window.duration(), Duration::days(period.days()));
+    }
+
+    #[test]
+    fn availability_without_reservations_is_entirely_free() {
+        let tool_id = ToolId::new();
+        let availability = ToolAvailability::new(tool_id, window(at(1, 0), at(8, 0)), Vec::new());
+
+        assert!(availability.reservations.is_empty());
+        assert_eq!(availability.free, vec![window(at(1, 0), at(8, 0))]);
+    }
+
+    #[test]
+    fn availability_splits_free_time_around_reservations() {
+        let tool_id = ToolId::new();
+        let reservations = vec![
+            block(tool_id, at(5, 0), at(6, 0)),
+            block(tool_id, at(2, 0), at(3, 0)),
+        ];
+
+        let availability = ToolAvailability::new(tool_id, window(at(1, 0), at(8, 0)), reservations);
+
+        assert_eq!(availability.reservations[0].window.starts_at, at(2, 0));
+        assert_eq!(
+            availability.free,
+            vec![
+                window(at(1, 0), at(2, 0)),
+                window(at(3, 0), at(5, 0)),
+                window(at(6, 0), at(8, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn availability_ignores_released_and_outside_reservations() {
+        let tool_id = ToolId::new();
+        let mut released = block(tool_id, at(2, 0), at(3, 0));
+        released.release().unwrap();
+        // 首尾相接不算重叠
+        let adjacent = block(tool_id, at(8, 0), at(9, 0));
+
+        let availability =
+            ToolAvailability::new(tool_id, window(at(1, 0), at(8, 0)), vec![released, adjacent]);
+
+        assert!(availability.reservations.is_empty());
+        assert_eq!(availability.free, vec![window(at(1, 0), at(8, 0))]);
+    }
+
+    #[test]
+    fn availability_clamps_reservations_spanning_the_window() {
+        let tool_id = ToolId::new();
+        let reservations = vec![block(tool_id, at(1, 0), at(4, 0))];
+
+        let availability = ToolAvailability::new(tool_id, window(at(2, 0), at(8, 0)), reservations);
+
+        assert_eq!(availability.reservations.len(), 1);
+        assert_eq!(availability.free, vec![window(at(4, 0), at(8, 0))]);
+    }
+}
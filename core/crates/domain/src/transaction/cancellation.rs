@@ -0,0 +1,277 @@
+//! 取消交易政策
+
+use super::Transaction;
+use crate::isu::ISU;
+use chrono::{DateTime, Duration, Utc};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+
+/// 取消方
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CancellingParty {
+    Buyer,
+    Seller,
+}
+
+/// 取消费政策
+///
+/// 只对已冻结的交易款项收取，押金总是全额退还：
+/// - 卖家取消，或买家在确认后的宽限期内取消：免费
+/// - 买家在距开始时间（工具租用的租用期开始）不足 `late_window` 时取消：按 `late_fee_rate` 收取
+/// - 其余情况买家取消：按 `buyer_fee_rate` 收取
+#[derive(Debug, Clone)]
+pub struct CancellationPolicy {
+    pub grace_period: Duration,
+    pub buyer_fee_rate: Decimal,
+    pub late_fee_rate: Decimal,
+    pub late_window: Duration,
+}
+
+/// 取消结算结果
+#[derive(Debug, Clone, Copy)]
+pub struct CancellationSettlement {
+    pub fee_rate: Decimal,
+    /// 退还买家
+    pub refund: ISU,
+    /// 取消费（转给卖家）
+    pub fee: ISU,
+}
+
+impl CancellationPolicy {
+    /// 创建取消费政策，费率必须在 0 到 1 之间
+    pub fn new(
+        grace_period: Duration,
+        buyer_fee_rate: Decimal,
+        late_fee_rate: Decimal,
+        late_window: Duration,
+    ) -> Result<Self> {
+        let valid_rate = |rate: Decimal| rate >= Decimal::ZERO && rate <= Decimal::ONE;
+        if !valid_rate(buyer_fee_rate) || !valid_rate(late_fee_rate) {
+            return Err(AppError::validation("取消费率必须在0到1之间"));
+        }
+        if grace_period < Duration::zero() || late_window < Duration::zero() {
+            return Err(AppError::validation("取消宽限期和临近开始窗口不能为负数"));
+        }
+
+        Ok(Self {
+            grace_period,
+            buyer_fee_rate,
+            late_fee_rate,
+            late_window,
+        })
+    }
+
+    /// 按取消方和取消时间计算托管款项的退还与取消费
+    ///
+    /// `scheduled_start` 为交易约定的开始时间，服务交易没有约定时间时传 `None`。
+    pub fn assess(
+        &self,
+        transaction: &Transaction,
+        party: CancellingParty,
+        scheduled_start: Option<DateTime<Utc>>,
+        now: DateTime<Utc>,
+    ) -> Result<CancellationSettlement> {
+        let fee_rate = match (transaction.confirmed_at, party) {
+            // 尚未确认时没有冻结款项；卖家取消不向买家收费
+            (None, _) | (_, CancellingParty::Seller) => Decimal::ZERO,
+            (Some(confirmed_at), CancellingParty::Buyer) => {
+                if now - confirmed_at <= self.grace_period {
+                    Decimal::ZERO
+                } else if scheduled_start.is_some_and(|start| now >= start - self.late_window) {
+                    self.late_fee_rate
+                } else {
+                    self.buyer_fee_rate
+                }
+            }
+        };
+
+        let escrowed = transaction.escrowed_amount;
+        let fee = ISU::new((escrowed.value() * fee_rate).round_dp(2))?;
+
+        Ok(CancellationSettlement {
+            fee_rate,
+            refund: escrowed.subtract(&fee)?,
+            fee,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::member::MemberId;
+    use crate::service::ServiceId;
+    use crate::transaction::TransactionItemType;
+
+    fn policy() -> CancellationPolicy {
+        CancellationPolicy::new(
+            Duration::hours(1),
+            Decimal::new(10, 2),
+            Decimal::new(50, 2),
+            Duration::hours(24),
+        )
+        .unwrap()
+    }
+
+    fn isu(amount: i64) -> ISU {
+        ISU::new(Decimal::new(amount, 0)).unwrap()
+    }
+
+    /// 已在 `confirmed_at` 确认并冻结 `escrowed` 的交易
+    fn confirmed(escrowed: ISU, confirmed_at: DateTime<Utc>) -> Transaction {
+        let mut transaction = Transaction::new(
+            MemberId::new(),
+            MemberId::new(),
+            TransactionItemType::Service(ServiceId::new()),
+            escrowed,
+            None,
+        )
+        .unwrap();
+        transaction.confirmed_at = Some(confirmed_at);
+        transaction.escrowed_amount = escrowed;
+        transaction
+    }
+
+    #[test]
+    fn buyer_cancel_within_grace_period_is_free() {
+        let confirmed_at = Utc::now();
+        let transaction = confirmed(isu(100), confirmed_at);
+        let now = confirmed_at + Duration::hours(1);
+
+        let settlement = policy()
+            .assess(&transaction, CancellingParty::Buyer, Some(now), now)
+            .unwrap();
+
+        assert_eq!(settlement.fee_rate, Decimal::ZERO);
+        assert_eq!(settlement.fee, ISU::default());
+        assert_eq!(settlement.refund, isu(100));
+    }
+
+    #[test]
+    fn buyer_cancel_after_grace_period_pays_buyer_fee() {
+        let confirmed_at = Utc::now();
+        let transaction = confirmed(isu(100), confirmed_at);
+        let now = confirmed_at + Duration::hours(1) + Duration::seconds(1);
+
+        let settlement = policy()
+            .assess(&transaction, CancellingParty::Buyer, None, now)
+            .unwrap();
+
+        assert_eq!(settlement.fee_rate, Decimal::new(10, 2));
+        assert_eq!(settlement.fee, isu(10));
+        assert_eq!(settlement.refund, isu(90));
+    }
+
+    #[test]
+    fn buyer_cancel_just_outside_late_window_pays_buyer_fee() {
+        let confirmed_at = Utc::now();
+        let transaction = confirmed(isu(100), confirmed_at);
+        let now = confirmed_at + Duration::hours(2);
+        let start = now + Duration::hours(24) + Duration::seconds(1);
+
+        let settlement = policy()
+            .assess(&transaction, CancellingParty::Buyer, Some(start), now)
+            .unwrap();
+
+        assert_eq!(settlement.fee_rate, Decimal::new(10, 2));
+    }
+
+    #[test]
+    fn buyer_cancel_at_late_window_boundary_pays_late_fee() {
+        let confirmed_at = Utc::now();
+        let transaction = confirmed(isu(100), confirmed_at);
+        let now = confirmed_at + Duration::hours(2);
+        let start = now + Duration::hours(24);
+
+        let settlement = policy()
+            .assess(&transaction, CancellingParty::Buyer, Some(start), now)
+            .unwrap();
+
+        assert_eq!(settlement.fee_rate, Decimal::new(50, 2));
+        assert_eq!(settlement.fee, isu(50));
+        assert_eq!(settlement.refund, isu(50));
+    }
+
+    #[test]
+    fn grace_period_takes_precedence_over_late_window() {
+        let confirmed_at = Utc::now();
+        let transaction = confirmed(isu(100), confirmed_at);
+        let now = confirmed_at + Duration::minutes(30);
+
+        let settlement = policy()
+            .assess(&transaction, CancellingParty::Buyer, Some(now), now)
+            .unwrap();
+
+        assert_eq!(settlement.fee_rate, Decimal::ZERO);
+    }
+
+    #[test]
+    fn seller_cancel_is_always_free() {
+        let confirmed_at = Utc::now();
+        let transaction = confirmed(isu(100), confirmed_at);
+        let now = confirmed_at + Duration::hours(2);
+
+        let settlement = policy()
+            .assess(&transaction, CancellingParty::Seller, Some(now), now)
+            .unwrap();
+
+        assert_eq!(settlement.fee_rate, Decimal::ZERO);
+        assert_eq!(settlement.fee, ISU::default());
+        assert_eq!(settlement.refund, isu(100));
+    }
+
+    #[test]
+    fn unconfirmed_transaction_has_nothing_to_charge() {
+        let mut transaction = confirmed(ISU::default(), Utc::now());
+        transaction.confirmed_at = None;
+        let now = Utc::now() + Duration::hours(2);
+
+        let settlement = policy()
+            .assess(&transaction, CancellingParty::Buyer, Some(now), now)
+            .unwrap();
+
+        assert_eq!(settlement.fee_rate, Decimal::ZERO);
+        assert_eq!(settlement.refund, ISU::default());
+    }
+
+    #[test]
+    fn zero_escrow_yields_zero_fee_and_refund() {
+        let confirmed_at = Utc::now();
+        let transaction = confirmed(ISU::default(), confirmed_at);
+        let now = confirmed_at + Duration::hours(2);
+
+        let settlement = policy()
+            .assess(&transaction, CancellingParty::Buyer, Some(now), now)
+            .unwrap();
+
+        assert_eq!(settlement.fee_rate, Decimal::new(50, 2));
+        assert_eq!(settlement.fee, ISU::default());
+        assert_eq!(settlement.refund, ISU::default());
+    }
+
+    #[test]
+    fn fee_is_rounded_to_two_decimal_places() {
+        let confirmed_at = Utc::now();
+        let escrowed = ISU::new(Decimal::new(3333, 2)).unwrap();
+        let transaction = confirmed(escrowed, confirmed_at);
+        let now = confirmed_at + Duration::hours(2);
+
+        let settlement = policy()
+            .assess(&transaction, CancellingParty::Buyer, None, now)
+            .unwrap();
+
+        assert_eq!(settlement.fee.value(), Decimal::new(333, 2));
+        assert_eq!(settlement.refund.value(), Decimal::new(3000, 2));
+    }
+
+    #[test]
+    fn rejects_out_of_range_rates() {
+        let result = CancellationPolicy::new(
+            Duration::hours(1),
+            Decimal::new(11, 1),
+            Decimal::ZERO,
+            Duration::hours(24),
+        );
+        assert!(result.is_err());
+    }
+}
@@ -16,11 +16,19 @@ pub struct Transaction {
     pub seller_id: MemberId,
     pub item_type: TransactionItemType,
     pub isu_amount: ISU,
+    /// 托管中的交易款项（卖家确认时冻结，完成时放款，取消或争议裁决时退还）
+    pub escrowed_amount: ISU,
     pub status: TransactionStatus,
     pub description: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
+    /// 卖家确认（款项冻结）时间，取消费按此计算宽限期
+    pub confirmed_at: Option<DateTime<Utc>>,
+    /// 交易开始时间，自动完成的宽限期按此计算
+    pub started_at: Option<DateTime<Utc>>,
     pub completed_at: Option<DateTime<Utc>>,
+    /// 取消交易的参与者（争议裁决全额退还时为空）
+    pub cancelled_by: Option<MemberId>,
 }
 
 /// 交易项目类型
@@ -41,6 +49,7 @@ pub enum TransactionStatus {
     Completed,  // 已完成
     Cancelled,  // 已取消
     Disputed,   // 争议中
+    Expired,    // 已过期（超时未确认）
 }
 
 impl Default for TransactionStatus {
@@ -58,6 +67,24 @@ impl std::fmt::Display for TransactionStatus {
             Self::Completed => write!(f, "completed"),
             Self::Cancelled => write!(f, "cancelled"),
             Self::Disputed => write!(f, "disputed"),
+            Self::Expired => write!(f, "expired"),
+        }
+    }
+}
+
+impl std::str::FromStr for TransactionStatus {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "pending" => Ok(Self::Pending),
+            "confirmed" => Ok(Self::Confirmed),
+            "in_progress" => Ok(Self::InProgress),
+            "completed" => Ok(Self::Completed),
+            "cancelled" => Ok(Self::Cancelled),
+            "disputed" => Ok(Self::Disputed),
+            "expired" => Ok(Self::Expired),
+            _ => Err(AppError::validation(format!("无效的交易状态: {}", s))),
         }
     }
 }
@@ -83,11 +110,15 @@ impl Transaction {
             seller_id,
             item_type,
             isu_amount,
+            escrowed_amount: ISU::default(),
             status: TransactionStatus::default(),
             description,
             created_at: now,
             updated_at: now,
+            confirmed_at: None,
+            started_at: None,
             completed_at: None,
+            cancelled_by: None,
         })
     }
 
@@ -95,8 +126,10 @@ impl Transaction {
     pub fn confirm(&mut self) -> Result<()> {
         match self.status {
             TransactionStatus::Pending => {
+                let now = Utc::now();
                 self.status = TransactionStatus::Confirmed;
-                self.updated_at = Utc::now();
+                self.confirmed_at = Some(now);
+                self.updated_at = now;
                 Ok(())
             }
             _ => Err(AppError::validation("只有待确认状态的交易才能确认")),
@@ -107,8 +140,10 @@ impl Transaction {
     pub fn start(&mut self) -> Result<()> {
         match self.status {
             TransactionStatus::Confirmed => {
+                let now = Utc::now();
                 self.status = TransactionStatus::InProgress;
-                self.updated_at = Utc::now();
+                self.started_at = Some(now);
+                self.updated_at = now;
                 Ok(())
             }
             _ => Err(AppError::validation("只有已确认的交易才能开始")),
@@ -128,11 +163,12 @@ impl Transaction {
         }
     }
 
-    /// 取消交易
-    pub fn cancel(&mut self) -> Result<()> {
+    /// 参与者取消交易
+    pub fn cancel(&mut self, cancelled_by: MemberId) -> Result<()> {
         match self.status {
             TransactionStatus::Pending | TransactionStatus::Confirmed => {
                 self.status = TransactionStatus::Cancelled;
+                self.cancelled_by = Some(cancelled_by);
                 self.updated_at = Utc::now();
                 Ok(())
             }
@@ -140,6 +176,18 @@ impl Transaction {
         }
     }
 
+    /// 超时未确认，交易过期
+    pub fn expire(&mut self) -> Result<()> {
+        match self.status {
+            TransactionStatus::Pending => {
+                self.status = TransactionStatus::Expired;
+                self.updated_at = Utc::now();
+                Ok(())
+            }
+            _ => Err(AppError::validation("只有待确认状态的交易才能过期")),
+        }
+    }
+
     /// 标记为争议
     pub fn dispute(&mut self) -> Result<()> {
         match self.status {
@@ -152,6 +200,41 @@ impl Transaction {
         }
     }
 
+    /// 争议裁决后结束交易：全额退还买家视为取消，否则视为完成
+    pub fn resolve_dispute(&mut self, refunded_in_full: bool) -> Result<()> {
+        if self.status != TransactionStatus::Disputed {
+            return Err(AppError::validation("只有争议中的交易才能裁决"));
+        }
+
+        let now = Utc::now();
+        if refunded_in_full {
+            self.status = TransactionStatus::Cancelled;
+        } else {
+            self.status = TransactionStatus::Completed;
+            self.completed_at = Some(now);
+        }
+        self.updated_at = now;
+        Ok(())
+    }
+
+    /// 记录已冻结的交易款项
+    pub fn hold_escrow(&mut self, amount: ISU) -> Result<()> {
+        if !self.escrowed_amount.value().is_zero() {
+            return Err(AppError::validation("交易款项已冻结"));
+        }
+        self.escrowed_amount = amount;
+        self.updated_at = Utc::now();
+        Ok(())
+    }
+
+    /// 取出托管中的交易款项用于放款或退还，托管金额清零
+    pub fn take_escrow(&mut self) -> ISU {
+        let amount = self.escrowed_amount;
+        self.escrowed_amount = ISU::default();
+        self.updated_at = Utc::now();
+        amount
+    }
+
     /// 检查交易是否可以取消
     pub fn can_cancel(&self) -> bool {
         matches!(
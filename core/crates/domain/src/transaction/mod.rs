@@ -1,9 +1,11 @@
 //! Transaction模块 - 交易管理
 
+pub mod cancellation;
 pub mod entity;
 pub mod repository;
 
 // 重导出
+pub use cancellation::{CancellationPolicy, CancellationSettlement, CancellingParty};
 pub use entity::{Transaction, TransactionItemType, TransactionStatus};
 pub use repository::TransactionRepository;
 
@@ -1,8 +1,9 @@
 //! Transaction Repository接口
 
-use super::{Transaction, TransactionId};
+use super::{Transaction, TransactionId, TransactionItemType};
 use crate::member::MemberId;
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use shared::Result;
 
 /// 交易Repository trait
@@ -14,6 +15,9 @@ pub trait TransactionRepository: Send + Sync {
     /// 根据ID查找交易
     async fn find_by_id(&self, id: &TransactionId) -> Result<Option<Transaction>>;
 
+    /// 根据ID查找并锁定交易（在工作单元中使用，防止并发修改同一交易）
+    async fn find_by_id_for_update(&self, id: &TransactionId) -> Result<Option<Transaction>>;
+
     /// 根据买家ID查找交易
     async fn find_by_buyer_id(&self, buyer_id: &MemberId) -> Result<Vec<Transaction>>;
 
@@ -29,6 +33,24 @@ pub trait TransactionRepository: Send + Sync {
     /// 查找进行中的交易
     async fn find_in_progress_by_participant(&self, member_id: &MemberId) -> Result<Vec<Transaction>>;
 
+    /// 认领一条创建时间早于 `cutoff` 的待确认交易并锁定（在工作单元中使用）
+    ///
+    /// 跳过其他工作进程已锁定的行和 `exclude` 中的交易，没有可处理的交易时返回 `None`。
+    async fn claim_pending_created_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        exclude: &[TransactionId],
+    ) -> Result<Option<Transaction>>;
+
+    /// 认领一条开始时间早于 `cutoff` 的进行中服务交易并锁定（在工作单元中使用）
+    ///
+    /// 跳过其他工作进程已锁定的行和 `exclude` 中的交易，没有可处理的交易时返回 `None`。
+    async fn claim_in_progress_services_started_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        exclude: &[TransactionId],
+    ) -> Result<Option<Transaction>>;
+
     /// 更新交易状态
     async fn update(&self, transaction: &Transaction) -> Result<()>;
 
@@ -40,4 +62,10 @@ pub trait TransactionRepository: Send + Sync {
 
     /// 统计已完成交易数量
     async fn count_completed_by_participant(&self, member_id: &MemberId) -> Result<u64>;
-}
\ No newline at end of file
+
+    /// 统计会员自己取消的已确认交易数量
+    async fn count_confirmed_cancelled_by(&self, member_id: &MemberId) -> Result<u64>;
+
+    /// 统计引用该项目且尚未结束的交易数量（待确认、已确认、进行中、争议中）
+    async fn count_open_by_item(&self, item: &TransactionItemType) -> Result<u64>;
+}
@@ -0,0 +1,74 @@
+//! 工作单元
+//!
+//! 跨多个聚合的用例通过工作单元获取仓储，所有写操作在同一个事务中提交或回滚。
+
+use crate::audit::AuditEventRepository;
+use crate::auth::RefreshTokenRepository;
+use crate::dispute::DisputeRepository;
+use crate::isu::ISUAccountRepository;
+use crate::member::MemberRepository;
+use crate::profession::{ProfessionCatalogRepository, ProfessionStandardRepository};
+use crate::review::ReviewRepository;
+use crate::service::ServiceRepository;
+use crate::tool::{ToolRentalRepository, ToolRepository, ToolReservationRepository};
+use crate::transaction::TransactionRepository;
+use async_trait::async_trait;
+use shared::Result;
+
+/// 工作单元：一组共享同一事务的仓储
+///
+/// 未调用 `commit` 就被丢弃时，事务自动回滚。
+#[async_trait]
+pub trait UnitOfWork: Send + Sync {
+    /// 会员仓储
+    fn members(&self) -> &dyn MemberRepository;
+
+    /// ISU账户仓储
+    fn isu_accounts(&self) -> &dyn ISUAccountRepository;
+
+    /// 职业标准仓储
+    fn professions(&self) -> &dyn ProfessionStandardRepository;
+
+    /// 职业目录仓储
+    fn profession_catalog(&self) -> &dyn ProfessionCatalogRepository;
+
+    /// 服务仓储
+    fn services(&self) -> &dyn ServiceRepository;
+
+    /// 工具仓储
+    fn tools(&self) -> &dyn ToolRepository;
+
+    /// 工具租用仓储
+    fn tool_rentals(&self) -> &dyn ToolRentalRepository;
+
+    /// 工具预订仓储
+    fn tool_reservations(&self) -> &dyn ToolReservationRepository;
+
+    /// 交易仓储
+    fn transactions(&self) -> &dyn TransactionRepository;
+
+    /// 争议仓储
+    fn disputes(&self) -> &dyn DisputeRepository;
+
+    /// 审计事件仓储
+    fn audit_events(&self) -> &dyn AuditEventRepository;
+
+    /// 刷新令牌仓储
+    fn refresh_tokens(&self) -> &dyn RefreshTokenRepository;
+
+    /// 评价仓储
+    fn reviews(&self) -> &dyn ReviewRepository;
+
+    /// 提交事务
+    async fn commit(self: Box<Self>) -> Result<()>;
+
+    /// 回滚事务
+    async fn rollback(self: Box<Self>) -> Result<()>;
+}
+
+/// 工作单元工厂
+#[async_trait]
+pub trait UnitOfWorkFactory: Send + Sync {
+    /// 开启新的工作单元
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>>;
+}
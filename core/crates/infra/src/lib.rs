@@ -5,6 +5,13 @@ pub mod persistence;
 pub mod security;
 pub mod tracing_setup;
 
-pub use persistence::postgres::{create_pool, PgPool, PostgresMemberRepository, PostgresToolRepository};
-pub use security::{Argon2PasswordHasher, PasswordHasher};
+pub use persistence::postgres::{
+    create_pool, PgPool, PostgresAuditEventRepository, PostgresDisputeRepository, PostgresISUAccountRepository, PostgresMemberRepository,
+    PostgresProfessionCatalogRepository, PostgresProfessionStandardRepository, PostgresRefreshTokenRepository, PostgresReviewRepository,
+    PostgresServiceRepository, PostgresToolRentalRepository, PostgresToolRepository,
+    PostgresToolReservationRepository,
+    PostgresTransactionRepository,
+    PostgresUnitOfWorkFactory,
+};
+pub use security::{generate_refresh_token, hash_refresh_token, Argon2PasswordHasher, PasswordHasher};
 pub use tracing_setup::init_tracing;
@@ -0,0 +1,217 @@
+//! AuditEvent Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    audit::{AuditEvent, AuditEventCriteria, AuditEventId, AuditEventRepository},
+    member::MemberId,
+};
+use serde_json::Value;
+use shared::{AppError, Result};
+use sqlx::{FromRow, PgPool};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::session::PgSession;
+
+/// PostgreSQL AuditEvent Repository
+pub struct PostgresAuditEventRepository {
+    session: PgSession,
+}
+
+impl PostgresAuditEventRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+}
+
+/// 数据库行结构
+#[derive(Debug, Clone, FromRow)]
+struct AuditEventRow {
+    id: Uuid,
+    actor_id: Uuid,
+    action: String,
+    target_type: String,
+    target_id: String,
+    before: Option<Value>,
+    after: Option<Value>,
+    reason: Option<String>,
+    request_id: Option<String>,
+    /// INET 列以 `host()` 读出，不带掩码
+    ip_address: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+/// Row -> Domain 转换
+impl TryFrom<AuditEventRow> for AuditEvent {
+    type Error = AppError;
+
+    fn try_from(row: AuditEventRow) -> Result<Self> {
+        Ok(AuditEvent {
+            id: AuditEventId::from_uuid(row.id),
+            actor_id: MemberId::from_uuid(row.actor_id),
+            action: row.action.parse()?,
+            target_type: row.target_type.parse()?,
+            target_id: row.target_id,
+            before: row.before,
+            after: row.after,
+            reason: row.reason,
+            request_id: row.request_id,
+            ip_address: row.ip_address,
+            created_at: row.created_at,
+        })
+    }
+}
+
+#[async_trait]
+impl AuditEventRepository for PostgresAuditEventRepository {
+    #[instrument(name = "append_audit_event", skip(self, event), fields(action = %event.action))]
+    async fn append(&self, event: &AuditEvent) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO audit_events (id, actor_id, action, target_type, target_id, before, after, reason, request_id, ip_address, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10::TEXT::INET, $11)
+            "#,
+            event.id.value(),
+            event.actor_id.value(),
+            event.action.as_str(),
+            event.target_type.as_str(),
+            event.target_id,
+            event.before,
+            event.after,
+            event.reason,
+            event.request_id,
+            event.ip_address,
+            event.created_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("记录审计事件失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "search_audit_events", skip(self))]
+    async fn search(
+        &self,
+        criteria: &AuditEventCriteria,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<AuditEvent>> {
+        sqlx::query_as::<_, AuditEventRow>(
+            "SELECT id, actor_id, action, target_type, target_id, before, after, reason, request_id, host(ip_address) AS ip_address, created_at
+             FROM audit_events
+             WHERE ($1::UUID IS NULL OR actor_id = $1)
+               AND ($2::VARCHAR IS NULL OR action = $2)
+               AND ($3::VARCHAR IS NULL OR target_type = $3)
+               AND ($4::VARCHAR IS NULL OR target_id = $4)
+               AND ($5::TIMESTAMPTZ IS NULL OR created_at >= $5)
+               AND ($6::TIMESTAMPTZ IS NULL OR created_at < $6)
+             ORDER BY created_at DESC, id DESC
+             LIMIT $7 OFFSET $8",
+        )
+        .bind(criteria.actor_id.map(|id| id.value()))
+        .bind(criteria.action.map(|action| action.as_str()))
+        .bind(criteria.target_type.map(|target_type| target_type.as_str()))
+        .bind(criteria.target_id.as_deref())
+        .bind(criteria.from)
+        .bind(criteria.to)
+        .bind(i64::from(limit.unwrap_or(20)))
+        .bind(i64::from(offset.unwrap_or(0)))
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(AuditEvent::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "count_audit_events", skip(self))]
+    async fn count(&self, criteria: &AuditEventCriteria) -> Result<u64> {
+        let result = sqlx::query!(
+            "SELECT COUNT(*) as count FROM audit_events
+             WHERE ($1::UUID IS NULL OR actor_id = $1)
+               AND ($2::VARCHAR IS NULL OR action = $2)
+               AND ($3::VARCHAR IS NULL OR target_type = $3)
+               AND ($4::VARCHAR IS NULL OR target_id = $4)
+               AND ($5::TIMESTAMPTZ IS NULL OR created_at >= $5)
+               AND ($6::TIMESTAMPTZ IS NULL OR created_at < $6)",
+            criteria.actor_id.map(|id| id.value()),
+            criteria.action.map(|action| action.as_str()),
+            criteria.target_type.map(|target_type| target_type.as_str()),
+            criteria.target_id.as_deref(),
+            criteria.from,
+            criteria.to
+        )
+        .fetch_one(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("统计失败: {}", e)))?;
+
+        Ok(result.count.unwrap_or(0) as u64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persistence::postgres::PostgresMemberRepository;
+    use domain::{
+        audit::{AuditAction, AuditContext, AuditTargetType},
+        member::{Email, Member, MemberRepository, Username},
+    };
+
+    async fn actor(pool: &PgPool) -> Member {
+        let member = Member::new(
+            Email::new("admin@example.com").unwrap(),
+            Username::new("admin").unwrap(),
+            "hash".to_string(),
+        );
+        PostgresMemberRepository::new(pool.clone())
+            .save(&member)
+            .await
+            .unwrap();
+        member
+    }
+
+    async fn append_with_ip(pool: &PgPool, ip: &str) -> AuditEvent {
+        let actor = actor(pool).await;
+        let context = AuditContext {
+            request_id: Some("req-1".to_string()),
+            ip_address: Some(ip.parse().unwrap()),
+        };
+        let event = AuditEvent::new(
+            &context,
+            actor.id,
+            AuditAction::MemberStatusChanged,
+            AuditTargetType::Member,
+            actor.id,
+        );
+
+        let repo = PostgresAuditEventRepository::new(pool.clone());
+        repo.append(&event).await.unwrap();
+        let mut found = repo
+            .search(&AuditEventCriteria::default(), None, None)
+            .await
+            .unwrap();
+        assert_eq!(found.len(), 1);
+        found.remove(0)
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn ipv4_address_round_trips_without_mask(pool: PgPool) {
+        let event = append_with_ip(&pool, "203.0.113.7").await;
+        assert_eq!(event.ip_address.as_deref(), Some("203.0.113.7"));
+        assert_eq!(event.request_id.as_deref(), Some("req-1"));
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn ipv6_address_round_trips_without_mask(pool: PgPool) {
+        let event = append_with_ip(&pool, "2001:db8::1").await;
+        assert_eq!(event.ip_address.as_deref(), Some("2001:db8::1"));
+    }
+}
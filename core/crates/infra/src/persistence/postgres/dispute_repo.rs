@@ -0,0 +1,306 @@
+//! Dispute Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    dispute::{Dispute, DisputeEvidence, DisputeId, DisputeRepository, DisputeRuling, EvidenceId},
+    isu::ISU,
+    member::MemberId,
+    transaction::TransactionId,
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use sqlx::{FromRow, PgPool};
+use std::convert::TryFrom;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::session::PgSession;
+
+/// PostgreSQL Dispute Repository
+pub struct PostgresDisputeRepository {
+    session: PgSession,
+}
+
+impl PostgresDisputeRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+}
+
+/// 数据库行结构
+#[derive(Debug, Clone, FromRow)]
+struct DisputeRow {
+    id: Uuid,
+    transaction_id: Uuid,
+    opened_by: Uuid,
+    reason: String,
+    profession_type: Option<String>,
+    decider_id: Option<Uuid>,
+    status: String,
+    ruling_outcome: Option<String>,
+    buyer_refund: Option<Decimal>,
+    seller_payout: Option<Decimal>,
+    ruling_note: Option<String>,
+    decided_by: Option<Uuid>,
+    decided_at: Option<DateTime<Utc>>,
+    refund_isu_transaction_id: Option<Uuid>,
+    payout_isu_transaction_id: Option<Uuid>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Row -> Domain 转换
+impl TryFrom<DisputeRow> for Dispute {
+    type Error = AppError;
+
+    fn try_from(row: DisputeRow) -> Result<Self> {
+        let isu = |amount: Option<Decimal>| {
+            ISU::new(amount.unwrap_or_default())
+                .map_err(|e| AppError::internal(format!("数据库中的裁决金额无效: {}", e)))
+        };
+
+        let ruling = match (row.ruling_outcome, row.decided_by, row.decided_at) {
+            (Some(outcome), Some(decided_by), Some(decided_at)) => Some(DisputeRuling {
+                outcome: outcome.parse()?,
+                buyer_refund: isu(row.buyer_refund)?,
+                seller_payout: isu(row.seller_payout)?,
+                note: row.ruling_note,
+                decided_by: MemberId::from_uuid(decided_by),
+                decided_at,
+                refund_isu_transaction_id: row.refund_isu_transaction_id.map(|id| id.to_string()),
+                payout_isu_transaction_id: row.payout_isu_transaction_id.map(|id| id.to_string()),
+            }),
+            _ => None,
+        };
+
+        Ok(Dispute {
+            id: DisputeId::from_uuid(row.id),
+            transaction_id: TransactionId::from_uuid(row.transaction_id),
+            opened_by: MemberId::from_uuid(row.opened_by),
+            reason: row.reason,
+            profession_type: row.profession_type.map(|p| p.parse()).transpose()?,
+            decider_id: row.decider_id.map(MemberId::from_uuid),
+            status: row.status.parse()?,
+            ruling,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// 证据行结构
+#[derive(Debug, Clone, FromRow)]
+struct DisputeEvidenceRow {
+    id: Uuid,
+    dispute_id: Uuid,
+    author_id: Uuid,
+    message: String,
+    created_at: DateTime<Utc>,
+}
+
+impl From<DisputeEvidenceRow> for DisputeEvidence {
+    fn from(row: DisputeEvidenceRow) -> Self {
+        DisputeEvidence {
+            id: EvidenceId::from_uuid(row.id),
+            dispute_id: DisputeId::from_uuid(row.dispute_id),
+            author_id: MemberId::from_uuid(row.author_id),
+            message: row.message,
+            created_at: row.created_at,
+        }
+    }
+}
+
+/// 解析裁决关联的ISU流水ID
+fn parse_isu_transaction_id(id: Option<&String>) -> Result<Option<Uuid>> {
+    id.map(|id| {
+        Uuid::parse_str(id).map_err(|e| AppError::internal(format!("无效的ISU流水ID: {}", e)))
+    })
+    .transpose()
+}
+
+#[async_trait]
+impl DisputeRepository for PostgresDisputeRepository {
+    #[instrument(name = "save_dispute", skip(self, dispute))]
+    async fn save(&self, dispute: &Dispute) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO disputes (id, transaction_id, opened_by, reason, profession_type, decider_id, status, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+            "#,
+            dispute.id.value(),
+            dispute.transaction_id.value(),
+            dispute.opened_by.value(),
+            dispute.reason,
+            dispute.profession_type.as_ref().map(|p| p.as_str()),
+            dispute.decider_id.map(|id| id.value()),
+            dispute.status.to_string(),
+            dispute.created_at,
+            dispute.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("保存争议失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "find_dispute_by_id", skip(self))]
+    async fn find_by_id(&self, id: &DisputeId) -> Result<Option<Dispute>> {
+        sqlx::query_as::<_, DisputeRow>(
+            "SELECT id, transaction_id, opened_by, reason, profession_type, decider_id, status,
+                    ruling_outcome, buyer_refund, seller_payout, ruling_note, decided_by, decided_at,
+                    refund_isu_transaction_id, payout_isu_transaction_id, created_at, updated_at
+             FROM disputes WHERE id = $1",
+        )
+        .bind(id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Dispute::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_dispute_by_id_for_update", skip(self))]
+    async fn find_by_id_for_update(&self, id: &DisputeId) -> Result<Option<Dispute>> {
+        sqlx::query_as::<_, DisputeRow>(
+            "SELECT id, transaction_id, opened_by, reason, profession_type, decider_id, status,
+                    ruling_outcome, buyer_refund, seller_payout, ruling_note, decided_by, decided_at,
+                    refund_isu_transaction_id, payout_isu_transaction_id, created_at, updated_at
+             FROM disputes WHERE id = $1
+             FOR UPDATE",
+        )
+        .bind(id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Dispute::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_dispute_by_transaction", skip(self))]
+    async fn find_by_transaction_id(&self, transaction_id: &TransactionId) -> Result<Option<Dispute>> {
+        sqlx::query_as::<_, DisputeRow>(
+            "SELECT id, transaction_id, opened_by, reason, profession_type, decider_id, status,
+                    ruling_outcome, buyer_refund, seller_payout, ruling_note, decided_by, decided_at,
+                    refund_isu_transaction_id, payout_isu_transaction_id, created_at, updated_at
+             FROM disputes WHERE transaction_id = $1",
+        )
+        .bind(transaction_id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Dispute::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_open_disputes_by_decider", skip(self))]
+    async fn find_open_by_decider(&self, decider_id: &MemberId) -> Result<Vec<Dispute>> {
+        sqlx::query_as::<_, DisputeRow>(
+            "SELECT id, transaction_id, opened_by, reason, profession_type, decider_id, status,
+                    ruling_outcome, buyer_refund, seller_payout, ruling_note, decided_by, decided_at,
+                    refund_isu_transaction_id, payout_isu_transaction_id, created_at, updated_at
+             FROM disputes WHERE decider_id = $1 AND status = 'open'
+             ORDER BY created_at ASC",
+        )
+        .bind(decider_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Dispute::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "update_dispute", skip(self, dispute))]
+    async fn update(&self, dispute: &Dispute) -> Result<()> {
+        let ruling = dispute.ruling.as_ref();
+        let result = sqlx::query!(
+            r#"
+            UPDATE disputes
+            SET decider_id = $2, status = $3, ruling_outcome = $4, buyer_refund = $5, seller_payout = $6,
+                ruling_note = $7, decided_by = $8, decided_at = $9,
+                refund_isu_transaction_id = $10, payout_isu_transaction_id = $11, updated_at = $12
+            WHERE id = $1
+            "#,
+            dispute.id.value(),
+            dispute.decider_id.map(|id| id.value()),
+            dispute.status.to_string(),
+            ruling.map(|r| r.outcome.to_string()),
+            ruling.map(|r| r.buyer_refund.value()),
+            ruling.map(|r| r.seller_payout.value()),
+            ruling.and_then(|r| r.note.clone()),
+            ruling.map(|r| r.decided_by.value()),
+            ruling.map(|r| r.decided_at),
+            parse_isu_transaction_id(ruling.and_then(|r| r.refund_isu_transaction_id.as_ref()))?,
+            parse_isu_transaction_id(ruling.and_then(|r| r.payout_isu_transaction_id.as_ref()))?,
+            dispute.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("更新争议失败: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("争议不存在"));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(name = "add_dispute_evidence", skip(self, evidence))]
+    async fn add_evidence(&self, evidence: &DisputeEvidence) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO dispute_evidence (id, dispute_id, author_id, message, created_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            evidence.id.value(),
+            evidence.dispute_id.value(),
+            evidence.author_id.value(),
+            evidence.message,
+            evidence.created_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("保存争议证据失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "list_dispute_evidence", skip(self))]
+    async fn list_evidence(&self, dispute_id: &DisputeId) -> Result<Vec<DisputeEvidence>> {
+        let rows = sqlx::query_as::<_, DisputeEvidenceRow>(
+            "SELECT id, dispute_id, author_id, message, created_at
+             FROM dispute_evidence WHERE dispute_id = $1
+             ORDER BY created_at ASC",
+        )
+        .bind(dispute_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?;
+
+        Ok(rows.into_iter().map(DisputeEvidence::from).collect())
+    }
+
+    #[instrument(name = "count_disputes_lost_by_member", skip(self))]
+    async fn count_lost_by(&self, member_id: &MemberId) -> Result<u64> {
+        let result = sqlx::query!(
+            "SELECT COUNT(*) as count
+             FROM disputes d
+             JOIN transactions t ON t.id = d.transaction_id
+             WHERE (d.ruling_outcome = 'full_refund' AND t.seller_id = $1)
+                OR (d.ruling_outcome = 'release_to_seller' AND t.buyer_id = $1)",
+            member_id.value()
+        )
+        .fetch_one(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("统计失败: {}", e)))?;
+
+        Ok(result.count.unwrap_or(0) as u64)
+    }
+}
@@ -0,0 +1,621 @@
+//! ISU Account Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    isu::{
+        ISUAccount, ISUAccountId, ISUAccountRepository, ISUCirculation, ISUStatementQuery,
+        ISUTransaction, ISUTransactionType, ISU,
+    },
+    member::MemberId,
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use sqlx::{Connection, FromRow, PgConnection, PgPool};
+use std::convert::TryFrom;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::session::PgSession;
+
+/// PostgreSQL ISU Account Repository
+pub struct PostgresISUAccountRepository {
+    session: PgSession,
+}
+
+impl PostgresISUAccountRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+
+    /// 按ID顺序锁定账户，避免并发转账时互相等待造成死锁
+    async fn lock_accounts(conn: &mut PgConnection, ids: &[Uuid]) -> Result<Vec<LockedAccountRow>> {
+        sqlx::query_as::<_, LockedAccountRow>(
+            "SELECT id, balance, held_balance FROM isu_accounts
+             WHERE id = ANY($1)
+             ORDER BY id
+             FOR UPDATE",
+        )
+        .bind(ids)
+        .fetch_all(conn)
+        .await
+        .map_err(|e| AppError::internal(format!("锁定ISU账户失败: {}", e)))
+    }
+
+    /// 记账：扣减转出方、增加转入方余额并写入流水（调用方负责校验余额并锁定账户）
+    async fn post_transfer(
+        conn: &mut PgConnection,
+        from_account_id: &ISUAccountId,
+        to_account_id: &ISUAccountId,
+        amount: &ISU,
+        transaction_type: ISUTransactionType,
+        description: Option<String>,
+    ) -> Result<ISUTransaction> {
+        sqlx::query!(
+            "UPDATE isu_accounts SET balance = balance - $2 WHERE id = $1",
+            from_account_id.value(),
+            amount.value()
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| AppError::internal(format!("扣减ISU余额失败: {}", e)))?;
+
+        sqlx::query!(
+            "UPDATE isu_accounts SET balance = balance + $2 WHERE id = $1",
+            to_account_id.value(),
+            amount.value()
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| AppError::internal(format!("增加ISU余额失败: {}", e)))?;
+
+        let transaction_id = Uuid::new_v4();
+        let transaction = ISUTransaction {
+            id: transaction_id.to_string(),
+            from_account_id: *from_account_id,
+            to_account_id: *to_account_id,
+            amount: *amount,
+            transaction_type,
+            description,
+            created_at: Utc::now(),
+        };
+
+        sqlx::query!(
+            r#"
+            INSERT INTO isu_transactions (id, from_account_id, to_account_id, amount, transaction_type, description, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
+            "#,
+            transaction_id,
+            transaction.from_account_id.value(),
+            transaction.to_account_id.value(),
+            transaction.amount.value(),
+            transaction.transaction_type.to_string(),
+            transaction.description,
+            transaction.created_at
+        )
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| AppError::internal(format!("记录ISU交易失败: {}", e)))?;
+
+        Ok(transaction)
+    }
+}
+
+/// 锁定的账户余额
+#[derive(Debug, Clone, FromRow)]
+struct LockedAccountRow {
+    id: Uuid,
+    balance: Decimal,
+    held_balance: Decimal,
+}
+
+/// 账户行结构
+#[derive(Debug, Clone, FromRow)]
+struct ISUAccountRow {
+    id: Uuid,
+    owner_id: Uuid,
+    balance: Decimal,
+    held_balance: Decimal,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// 交易记录行结构
+#[derive(Debug, Clone, FromRow)]
+struct ISUTransactionRow {
+    id: Uuid,
+    from_account_id: Uuid,
+    to_account_id: Uuid,
+    amount: Decimal,
+    transaction_type: String,
+    description: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+/// Row -> Domain 转换
+impl TryFrom<ISUAccountRow> for ISUAccount {
+    type Error = AppError;
+
+    fn try_from(row: ISUAccountRow) -> Result<Self> {
+        Ok(ISUAccount {
+            id: ISUAccountId::from_uuid(row.id),
+            owner_id: MemberId::from_uuid(row.owner_id),
+            balance: ISU::new(row.balance)
+                .map_err(|e| AppError::internal(format!("数据库中的账户余额无效: {}", e)))?,
+            held_balance: ISU::new(row.held_balance)
+                .map_err(|e| AppError::internal(format!("数据库中的托管中余额无效: {}", e)))?,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+impl TryFrom<ISUTransactionRow> for ISUTransaction {
+    type Error = AppError;
+
+    fn try_from(row: ISUTransactionRow) -> Result<Self> {
+        Ok(ISUTransaction {
+            id: row.id.to_string(),
+            from_account_id: ISUAccountId::from_uuid(row.from_account_id),
+            to_account_id: ISUAccountId::from_uuid(row.to_account_id),
+            amount: ISU::new(row.amount)
+                .map_err(|e| AppError::internal(format!("数据库中的交易金额无效: {}", e)))?,
+            transaction_type: row.transaction_type.parse()?,
+            description: row.description,
+            created_at: row.created_at,
+        })
+    }
+}
+
+#[async_trait]
+impl ISUAccountRepository for PostgresISUAccountRepository {
+    #[instrument(name = "save_isu_account", skip(self, account))]
+    async fn save(&self, account: &ISUAccount) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO isu_accounts (id, owner_id, balance, held_balance, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6)
+            "#,
+            account.id.value(),
+            account.owner_id.value(),
+            account.balance.value(),
+            account.held_balance.value(),
+            account.created_at,
+            account.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("保存ISU账户失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "find_isu_account_by_id", skip(self))]
+    async fn find_by_id(&self, id: &ISUAccountId) -> Result<Option<ISUAccount>> {
+        sqlx::query_as::<_, ISUAccountRow>(
+            "SELECT id, owner_id, balance, held_balance, created_at, updated_at
+             FROM isu_accounts WHERE id = $1",
+        )
+        .bind(id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(ISUAccount::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_isu_account_by_owner", skip(self))]
+    async fn find_by_owner_id(&self, owner_id: &MemberId) -> Result<Option<ISUAccount>> {
+        sqlx::query_as::<_, ISUAccountRow>(
+            "SELECT id, owner_id, balance, held_balance, created_at, updated_at
+             FROM isu_accounts WHERE owner_id = $1",
+        )
+        .bind(owner_id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(ISUAccount::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "transfer_isu", skip(self, description))]
+    async fn transfer(
+        &self,
+        from_account_id: &ISUAccountId,
+        to_account_id: &ISUAccountId,
+        amount: &ISU,
+        transaction_type: ISUTransactionType,
+        description: Option<String>,
+    ) -> Result<ISUTransaction> {
+        if from_account_id == to_account_id {
+            return Err(AppError::validation("不能向同一账户转账"));
+        }
+
+        if amount.value() <= Decimal::ZERO {
+            return Err(AppError::validation("转账金额必须大于0"));
+        }
+
+        if transaction_type.is_escrow_movement() {
+            return Err(AppError::validation("托管资金只能通过冻结或释放操作流转"));
+        }
+
+        ISUTransaction::validate_parties(from_account_id, to_account_id, transaction_type)?;
+
+        let mut conn = self.session.acquire().await?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| AppError::internal(format!("开启事务失败: {}", e)))?;
+
+        let locked =
+            Self::lock_accounts(&mut tx, &[from_account_id.value(), to_account_id.value()]).await?;
+
+        let from_balance = locked
+            .iter()
+            .find(|row| row.id == from_account_id.value())
+            .map(|row| row.balance)
+            .ok_or_else(|| AppError::not_found("转出ISU账户不存在"))?;
+
+        if !locked.iter().any(|row| row.id == to_account_id.value()) {
+            return Err(AppError::not_found("转入ISU账户不存在"));
+        }
+
+        if from_balance < amount.value() {
+            return Err(AppError::validation("ISU余额不足"));
+        }
+
+        let transaction = Self::post_transfer(
+            &mut tx,
+            from_account_id,
+            to_account_id,
+            amount,
+            transaction_type,
+            description,
+        )
+        .await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::internal(format!("提交事务失败: {}", e)))?;
+
+        Ok(transaction)
+    }
+
+    #[instrument(name = "hold_isu", skip(self, description))]
+    async fn hold(
+        &self,
+        account_id: &ISUAccountId,
+        amount: &ISU,
+        transaction_type: ISUTransactionType,
+        description: Option<String>,
+    ) -> Result<ISUTransaction> {
+        if amount.value() <= Decimal::ZERO {
+            return Err(AppError::validation("冻结金额必须大于0"));
+        }
+
+        let escrow_id = ISUAccount::escrow_id();
+        ISUTransaction::validate_parties(account_id, &escrow_id, transaction_type)?;
+
+        let mut conn = self.session.acquire().await?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| AppError::internal(format!("开启事务失败: {}", e)))?;
+
+        let locked = Self::lock_accounts(&mut tx, &[account_id.value(), escrow_id.value()]).await?;
+
+        let balance = locked
+            .iter()
+            .find(|row| row.id == account_id.value())
+            .map(|row| row.balance)
+            .ok_or_else(|| AppError::not_found("ISU账户不存在"))?;
+
+        if !locked.iter().any(|row| row.id == escrow_id.value()) {
+            return Err(AppError::internal("平台托管账户不存在"));
+        }
+
+        if balance < amount.value() {
+            return Err(AppError::validation("ISU余额不足"));
+        }
+
+        let transaction =
+            Self::post_transfer(&mut tx, account_id, &escrow_id, amount, transaction_type, description)
+                .await?;
+
+        sqlx::query!(
+            "UPDATE isu_accounts SET held_balance = held_balance + $2 WHERE id = $1",
+            account_id.value(),
+            amount.value()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::internal(format!("增加托管中余额失败: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::internal(format!("提交事务失败: {}", e)))?;
+
+        Ok(transaction)
+    }
+
+    #[instrument(name = "release_isu", skip(self, description))]
+    async fn release(
+        &self,
+        holder_account_id: &ISUAccountId,
+        to_account_id: &ISUAccountId,
+        amount: &ISU,
+        transaction_type: ISUTransactionType,
+        description: Option<String>,
+    ) -> Result<ISUTransaction> {
+        if amount.value() <= Decimal::ZERO {
+            return Err(AppError::validation("释放金额必须大于0"));
+        }
+
+        let escrow_id = ISUAccount::escrow_id();
+        ISUTransaction::validate_parties(&escrow_id, to_account_id, transaction_type)?;
+
+        let mut conn = self.session.acquire().await?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| AppError::internal(format!("开启事务失败: {}", e)))?;
+
+        let locked = Self::lock_accounts(
+            &mut tx,
+            &[holder_account_id.value(), to_account_id.value(), escrow_id.value()],
+        )
+        .await?;
+
+        let held_balance = locked
+            .iter()
+            .find(|row| row.id == holder_account_id.value())
+            .map(|row| row.held_balance)
+            .ok_or_else(|| AppError::not_found("ISU账户不存在"))?;
+
+        if !locked.iter().any(|row| row.id == to_account_id.value()) {
+            return Err(AppError::not_found("转入ISU账户不存在"));
+        }
+
+        if held_balance < amount.value() {
+            return Err(AppError::validation("托管中的ISU不足"));
+        }
+
+        let transaction =
+            Self::post_transfer(&mut tx, &escrow_id, to_account_id, amount, transaction_type, description)
+                .await?;
+
+        sqlx::query!(
+            "UPDATE isu_accounts SET held_balance = held_balance - $2 WHERE id = $1",
+            holder_account_id.value(),
+            amount.value()
+        )
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| AppError::internal(format!("扣减托管中余额失败: {}", e)))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::internal(format!("提交事务失败: {}", e)))?;
+
+        Ok(transaction)
+    }
+
+    #[instrument(name = "get_isu_transaction_history", skip(self))]
+    async fn get_transaction_history(
+        &self,
+        account_id: &ISUAccountId,
+        limit: Option<u32>,
+    ) -> Result<Vec<ISUTransaction>> {
+        let limit = i64::from(limit.unwrap_or(50));
+
+        sqlx::query_as::<_, ISUTransactionRow>(
+            "SELECT id, from_account_id, to_account_id, amount, transaction_type, description, created_at
+             FROM isu_transactions
+             WHERE from_account_id = $1 OR to_account_id = $1
+             ORDER BY created_at DESC
+             LIMIT $2",
+        )
+        .bind(account_id.value())
+        .bind(limit)
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(ISUTransaction::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "get_isu_statement", skip(self))]
+    async fn get_statement(
+        &self,
+        account_id: &ISUAccountId,
+        query: &ISUStatementQuery,
+    ) -> Result<Vec<ISUTransaction>> {
+        let types: Vec<String> = query
+            .transaction_types
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+
+        // 以 (created_at, id) 作为游标，保证同一时间戳的记录也能稳定翻页
+        sqlx::query_as::<_, ISUTransactionRow>(
+            r#"
+            SELECT t.id, t.from_account_id, t.to_account_id, t.amount, t.transaction_type, t.description, t.created_at
+            FROM isu_transactions t
+            WHERE (t.from_account_id = $1 OR t.to_account_id = $1)
+              AND (cardinality($2::TEXT[]) = 0 OR t.transaction_type = ANY($2))
+              AND ($3::UUID IS NULL OR (t.created_at, t.id) < (
+                  SELECT c.created_at, c.id FROM isu_transactions c WHERE c.id = $3
+              ))
+            ORDER BY t.created_at DESC, t.id DESC
+            LIMIT $4
+            "#,
+        )
+        .bind(account_id.value())
+        .bind(types)
+        .bind(query.before)
+        .bind(i64::from(query.limit))
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(ISUTransaction::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "get_isu_circulation", skip(self))]
+    async fn get_circulation(&self) -> Result<ISUCirculation> {
+        // 单条语句内的子查询共享同一快照，保证各项数据一致
+        let row = sqlx::query!(
+            r#"
+            SELECT
+                (SELECT genesis_supply FROM isu_supply) AS "total_supply?",
+                (SELECT balance FROM isu_accounts WHERE id = $1) AS "treasury_balance?",
+                (SELECT COALESCE(SUM(balance), 0) FROM isu_accounts WHERE id <> $1) AS "circulating!",
+                (SELECT balance FROM isu_accounts WHERE id = $2) AS "escrow_balance?",
+                (SELECT COALESCE(SUM(held_balance), 0) FROM isu_accounts) AS "held_total!",
+                (SELECT COALESCE(SUM(amount), 0) FROM isu_transactions WHERE from_account_id = $1) AS "issued!",
+                (SELECT COALESCE(SUM(amount), 0) FROM isu_transactions WHERE to_account_id = $1) AS "returned!"
+            "#,
+            ISUAccount::treasury_id().value(),
+            ISUAccount::escrow_id().value()
+        )
+        .fetch_one(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("统计ISU流通量失败: {}", e)))?;
+
+        Ok(ISUCirculation {
+            total_supply: row
+                .total_supply
+                .ok_or_else(|| AppError::internal("ISU供应量未初始化"))?,
+            treasury_balance: row
+                .treasury_balance
+                .ok_or_else(|| AppError::internal("平台金库账户不存在"))?,
+            circulating: row.circulating,
+            escrow_balance: row
+                .escrow_balance
+                .ok_or_else(|| AppError::internal("平台托管账户不存在"))?,
+            held_total: row.held_total,
+            issued: row.issued,
+            returned: row.returned,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::persistence::postgres::PostgresMemberRepository;
+    use domain::member::{Email, Member, MemberRepository, Username};
+
+    /// 创建会员及其ISU账户，并由金库发放初始余额（为零时不发放）
+    async fn funded_account(pool: &PgPool, name: &str, amount: i64) -> ISUAccount {
+        let member = Member::new(
+            Email::new(format!("{}@example.com", name)).unwrap(),
+            Username::new(name).unwrap(),
+            "hash".to_string(),
+        );
+        PostgresMemberRepository::new(pool.clone())
+            .save(&member)
+            .await
+            .unwrap();
+
+        let repo = PostgresISUAccountRepository::new(pool.clone());
+        let account = ISUAccount::new(member.id, ISU::default());
+        repo.save(&account).await.unwrap();
+        if amount > 0 {
+            repo.transfer(
+                &ISUAccount::treasury_id(),
+                &account.id,
+                &isu(amount),
+                ISUTransactionType::InitialBalance,
+                None,
+            )
+            .await
+            .unwrap();
+        }
+
+        repo.find_by_id(&account.id).await.unwrap().unwrap()
+    }
+
+    fn isu(amount: i64) -> ISU {
+        ISU::new(Decimal::new(amount, 0)).unwrap()
+    }
+
+    async fn account(repo: &PostgresISUAccountRepository, id: &ISUAccountId) -> ISUAccount {
+        repo.find_by_id(id).await.unwrap().unwrap()
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn hold_then_release_pays_seller_and_clears_held_balance(pool: PgPool) {
+        let repo = PostgresISUAccountRepository::new(pool.clone());
+        let buyer = funded_account(&pool, "buyer", 100).await;
+        let seller = funded_account(&pool, "seller", 0).await;
+
+        repo.hold(&buyer.id, &isu(40), ISUTransactionType::EscrowHold, None)
+            .await
+            .unwrap();
+
+        let held = account(&repo, &buyer.id).await;
+        assert_eq!(held.balance, isu(60));
+        assert_eq!(held.held_balance, isu(40));
+        let circulation = repo.get_circulation().await.unwrap();
+        assert_eq!(circulation.escrow_balance, Decimal::new(40, 0));
+        circulation.check_invariant().unwrap();
+
+        repo.release(&buyer.id, &seller.id, &isu(40), ISUTransactionType::ServicePayment, None)
+            .await
+            .unwrap();
+
+        let buyer = account(&repo, &buyer.id).await;
+        assert_eq!(buyer.balance, isu(60));
+        assert_eq!(buyer.held_balance, ISU::default());
+        assert_eq!(account(&repo, &seller.id).await.balance, isu(40));
+        let circulation = repo.get_circulation().await.unwrap();
+        assert_eq!(circulation.escrow_balance, Decimal::ZERO);
+        circulation.check_invariant().unwrap();
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn hold_then_refund_returns_funds_to_buyer(pool: PgPool) {
+        let repo = PostgresISUAccountRepository::new(pool.clone());
+        let buyer = funded_account(&pool, "buyer", 100).await;
+
+        repo.hold(&buyer.id, &isu(40), ISUTransactionType::EscrowHold, None)
+            .await
+            .unwrap();
+        repo.release(&buyer.id, &buyer.id, &isu(40), ISUTransactionType::CancellationRefund, None)
+            .await
+            .unwrap();
+
+        let buyer = account(&repo, &buyer.id).await;
+        assert_eq!(buyer.balance, isu(100));
+        assert_eq!(buyer.held_balance, ISU::default());
+        let circulation = repo.get_circulation().await.unwrap();
+        assert_eq!(circulation.escrow_balance, Decimal::ZERO);
+        assert_eq!(circulation.held_total, Decimal::ZERO);
+        circulation.check_invariant().unwrap();
+    }
+
+    #[sqlx::test(migrations = "../../migrations")]
+    async fn release_cannot_exceed_held_balance(pool: PgPool) {
+        let repo = PostgresISUAccountRepository::new(pool.clone());
+        let buyer = funded_account(&pool, "buyer", 100).await;
+
+        repo.hold(&buyer.id, &isu(40), ISUTransactionType::EscrowHold, None)
+            .await
+            .unwrap();
+        let result = repo
+            .release(&buyer.id, &buyer.id, &isu(50), ISUTransactionType::CancellationRefund, None)
+            .await;
+
+        assert!(matches!(result, Err(AppError::Validation(_))));
+        assert_eq!(account(&repo, &buyer.id).await.held_balance, isu(40));
+        repo.get_circulation().await.unwrap().check_invariant().unwrap();
+    }
+}
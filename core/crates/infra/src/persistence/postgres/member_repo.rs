@@ -2,22 +2,38 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use domain::member::{Email, Member, MemberId, MemberRepository, Username, UserRole};
-use domain::profession::ProfessionType;
+use domain::{
+    isu::{ESCROW_ACCOUNT_UUID, TREASURY_ACCOUNT_UUID},
+    member::{Email, Member, MemberId, MemberRepository, MemberSearchCriteria, Username},
+    profession::ProfessionType,
+};
+use rust_decimal::Decimal;
 use shared::{AppError, Result};
 use sqlx::{FromRow, PgPool};
 use std::convert::TryFrom;
 use tracing::instrument;
 use uuid::Uuid;
 
+use super::review_repo::rating_summary;
+use super::service_repo::escape_like;
+use super::session::PgSession;
+
+/// 平台系统会员（金库、托管账户的持有者），不出现在会员管理列表中
+const SYSTEM_MEMBER_IDS: [Uuid; 2] = [TREASURY_ACCOUNT_UUID, ESCROW_ACCOUNT_UUID];
+
 /// PostgreSQL Member Repository
 pub struct PostgresMemberRepository {
-    pool: PgPool,
+    session: PgSession,
 }
 
 impl PostgresMemberRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
     }
 }
 
@@ -31,6 +47,10 @@ struct MemberRow {
     status: String,
     role: String,
     managed_professions: Option<serde_json::Value>, // JSON
+    rating_count: i32,
+    rating_total: i32,
+    reputation_score: Decimal,
+    token_version: i32,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
 }
@@ -62,6 +82,9 @@ impl TryFrom<MemberRow> for Member {
             status: row.status.parse()?,
             role: row.role.parse()?,
             managed_professions,
+            rating: rating_summary(row.rating_count, row.rating_total)?,
+            reputation: row.reputation_score,
+            token_version: row.token_version,
             created_at: row.created_at,
             updated_at: row.updated_at,
         })
@@ -90,7 +113,7 @@ impl MemberRepository for PostgresMemberRepository {
             member.created_at,
             member.updated_at
         )
-        .execute(&self.pool)
+        .execute(&mut *self.session.acquire().await?)
         .await
         .map_err(|e| AppError::internal(format!("保存会员失败: {}", e)))?;
 
@@ -100,11 +123,11 @@ impl MemberRepository for PostgresMemberRepository {
     #[instrument(name = "find_member_by_id", skip(self))]
     async fn find_by_id(&self, id: MemberId) -> Result<Option<Member>> {
         sqlx::query_as::<_, MemberRow>(
-            "SELECT id, email, username, password_hash, status, role, managed_professions, created_at, updated_at 
+            "SELECT id, email, username, password_hash, status, role, managed_professions, rating_count, rating_total, reputation_score, token_version, created_at, updated_at 
              FROM members WHERE id = $1",
         )
         .bind(id.value())
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.session.acquire().await?)
         .await
         .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
         .map(Member::try_from)
@@ -114,11 +137,11 @@ impl MemberRepository for PostgresMemberRepository {
     #[instrument(name = "find_member_by_email", skip(self))]
     async fn find_by_email(&self, email: &Email) -> Result<Option<Member>> {
         sqlx::query_as::<_, MemberRow>(
-            "SELECT id, email, username, password_hash, status, role, managed_professions, created_at, updated_at 
+            "SELECT id, email, username, password_hash, status, role, managed_professions, rating_count, rating_total, reputation_score, token_version, created_at, updated_at 
              FROM members WHERE email = $1",
         )
         .bind(email.value())
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.session.acquire().await?)
         .await
         .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
         .map(Member::try_from)
@@ -128,17 +151,93 @@ impl MemberRepository for PostgresMemberRepository {
     #[instrument(name = "find_member_by_username", skip(self))]
     async fn find_by_username(&self, username: &Username) -> Result<Option<Member>> {
         sqlx::query_as::<_, MemberRow>(
-            "SELECT id, email, username, password_hash, status, role, managed_professions, created_at, updated_at 
+            "SELECT id, email, username, password_hash, status, role, managed_professions, rating_count, rating_total, reputation_score, token_version, created_at, updated_at 
              FROM members WHERE username = $1",
         )
         .bind(username.value())
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.session.acquire().await?)
         .await
         .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
         .map(Member::try_from)
         .transpose()
     }
 
+    #[instrument(name = "find_deciders_by_profession", skip(self))]
+    async fn find_deciders_by_profession(&self, profession: &ProfessionType) -> Result<Vec<Member>> {
+        sqlx::query_as::<_, MemberRow>(
+            "SELECT id, email, username, password_hash, status, role, managed_professions, rating_count, rating_total, reputation_score, token_version, created_at, updated_at
+             FROM members
+             WHERE role = 'decider' AND status = 'active'
+               AND managed_professions @> jsonb_build_array($1::text)
+             ORDER BY created_at ASC",
+        )
+        .bind(profession.as_str())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Member::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "search_members", skip(self))]
+    async fn search_members(
+        &self,
+        criteria: &MemberSearchCriteria,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<Member>> {
+        let keyword = criteria.keyword.trim();
+
+        sqlx::query_as::<_, MemberRow>(
+            "SELECT id, email, username, password_hash, status, role, managed_professions, rating_count, rating_total, reputation_score, token_version, created_at, updated_at
+             FROM members
+             WHERE ($1 = '' OR email ILIKE $2 OR username ILIKE $2)
+               AND ($3::VARCHAR IS NULL OR status = $3)
+               AND ($4::VARCHAR IS NULL OR role = $4)
+               AND id <> ALL($7)
+             ORDER BY created_at DESC
+             LIMIT $5 OFFSET $6",
+        )
+        .bind(keyword)
+        .bind(format!("%{}%", escape_like(keyword)))
+        .bind(criteria.status.map(|status| status.to_string()))
+        .bind(criteria.role.map(|role| role.to_string()))
+        .bind(i64::from(limit.unwrap_or(20)))
+        .bind(i64::from(offset.unwrap_or(0)))
+        .bind(&SYSTEM_MEMBER_IDS[..])
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Member::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "count_members", skip(self))]
+    async fn count_members(&self, criteria: &MemberSearchCriteria) -> Result<u64> {
+        let keyword = criteria.keyword.trim();
+
+        let pattern = format!("%{}%", escape_like(keyword));
+        let result = sqlx::query!(
+            "SELECT COUNT(*) as count FROM members
+             WHERE ($1 = '' OR email ILIKE $2 OR username ILIKE $2)
+               AND ($3::VARCHAR IS NULL OR status = $3)
+               AND ($4::VARCHAR IS NULL OR role = $4)
+               AND id <> ALL($5)",
+            keyword,
+            pattern,
+            criteria.status.map(|status| status.to_string()),
+            criteria.role.map(|role| role.to_string()),
+            &SYSTEM_MEMBER_IDS[..]
+        )
+        .fetch_one(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("统计失败: {}", e)))?;
+
+        Ok(result.count.unwrap_or(0) as u64)
+    }
+
     #[instrument(name = "update_member", skip(self, member))]
     async fn update(&self, member: &Member) -> Result<()> {
         let managed_professions_json = serde_json::to_value(&member.managed_professions)
@@ -147,7 +246,8 @@ impl MemberRepository for PostgresMemberRepository {
         sqlx::query!(
             r#"
             UPDATE members
-            SET email = $2, username = $3, password_hash = $4, status = $5, role = $6, managed_professions = $7, updated_at = $8
+            SET email = $2, username = $3, password_hash = $4, status = $5, role = $6, managed_professions = $7,
+                token_version = $8, updated_at = $9
             WHERE id = $1
             "#,
             member.id.value(),
@@ -157,19 +257,38 @@ impl MemberRepository for PostgresMemberRepository {
             member.status.to_string(),
             member.role.to_string(),
             managed_professions_json as serde_json::Value,
+            member.token_version,
             member.updated_at
         )
-        .execute(&self.pool)
+        .execute(&mut *self.session.acquire().await?)
         .await
         .map_err(|e| AppError::internal(format!("更新会员失败: {}", e)))?;
 
         Ok(())
     }
 
+    #[instrument(name = "update_member_reputation", skip(self))]
+    async fn update_reputation(&self, id: MemberId, score: Decimal) -> Result<()> {
+        let result = sqlx::query!(
+            "UPDATE members SET reputation_score = $2 WHERE id = $1",
+            id.value(),
+            score
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("更新信誉分失败: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("会员不存在"));
+        }
+
+        Ok(())
+    }
+
     #[instrument(name = "delete_member", skip(self))]
     async fn delete(&self, id: MemberId) -> Result<()> {
         sqlx::query!("DELETE FROM members WHERE id = $1", id.value())
-            .execute(&self.pool)
+            .execute(&mut *self.session.acquire().await?)
             .await
             .map_err(|e| AppError::internal(format!("删除失败: {}", e)))?;
 
@@ -1,9 +1,35 @@
 //! PostgreSQL 实现
 
+mod audit_event_repo;
+mod dispute_repo;
+mod isu_repo;
 mod member_repo;
+mod tool_rental_repo;
 mod tool_repo;
+mod tool_reservation_repo;
 mod pool;
+mod profession_catalog_repo;
+mod profession_repo;
+mod refresh_token_repo;
+mod review_repo;
+mod service_repo;
+mod session;
+mod transaction_repo;
+mod unit_of_work;
 
+pub use audit_event_repo::PostgresAuditEventRepository;
+pub use dispute_repo::PostgresDisputeRepository;
+pub use isu_repo::PostgresISUAccountRepository;
 pub use member_repo::PostgresMemberRepository;
+pub use tool_rental_repo::PostgresToolRentalRepository;
 pub use tool_repo::PostgresToolRepository;
+pub use tool_reservation_repo::PostgresToolReservationRepository;
 pub use pool::{create_pool, PgPool};
+pub use profession_catalog_repo::PostgresProfessionCatalogRepository;
+pub use profession_repo::PostgresProfessionStandardRepository;
+pub use refresh_token_repo::PostgresRefreshTokenRepository;
+pub use review_repo::PostgresReviewRepository;
+pub use service_repo::PostgresServiceRepository;
+pub use session::{PgConnectionGuard, PgSession, SharedTransaction};
+pub use transaction_repo::PostgresTransactionRepository;
+pub use unit_of_work::{PostgresUnitOfWork, PostgresUnitOfWorkFactory};
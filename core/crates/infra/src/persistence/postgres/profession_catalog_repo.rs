@@ -0,0 +1,258 @@
+//! ProfessionCatalog Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    isu::ISURate,
+    profession::{
+        LocalizedNames, Profession, ProfessionCatalogRepository, ProfessionCategory, ProfessionType,
+    },
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use sqlx::{FromRow, PgPool};
+use std::convert::TryFrom;
+use tracing::instrument;
+
+use super::session::PgSession;
+
+/// PostgreSQL ProfessionCatalog Repository
+pub struct PostgresProfessionCatalogRepository {
+    session: PgSession,
+}
+
+impl PostgresProfessionCatalogRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+}
+
+/// 职业行结构
+#[derive(Debug, Clone, FromRow)]
+struct ProfessionRow {
+    code: String,
+    display_name: String,
+    localized_names: serde_json::Value,
+    category_code: String,
+    default_rate: Decimal,
+    is_active: bool,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// 职业分类行结构
+#[derive(Debug, Clone, FromRow)]
+struct ProfessionCategoryRow {
+    code: String,
+    display_name: String,
+    localized_names: serde_json::Value,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// 解析多语言名称 JSON
+fn parse_localized_names(value: serde_json::Value) -> Result<LocalizedNames> {
+    serde_json::from_value(value)
+        .map_err(|e| AppError::internal(format!("解析多语言名称失败: {}", e)))
+}
+
+/// 序列化多语言名称 JSON
+fn localized_names_json(names: &LocalizedNames) -> Result<serde_json::Value> {
+    serde_json::to_value(names)
+        .map_err(|e| AppError::internal(format!("序列化多语言名称失败: {}", e)))
+}
+
+/// Row -> Domain 转换
+impl TryFrom<ProfessionRow> for Profession {
+    type Error = AppError;
+
+    fn try_from(row: ProfessionRow) -> Result<Self> {
+        Ok(Profession {
+            code: row.code.parse()?,
+            display_name: row.display_name,
+            localized_names: parse_localized_names(row.localized_names)?,
+            category_code: row.category_code,
+            default_rate: ISURate::new(row.default_rate)
+                .map_err(|e| AppError::internal(format!("数据库中的默认费率无效: {}", e)))?,
+            is_active: row.is_active,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+impl TryFrom<ProfessionCategoryRow> for ProfessionCategory {
+    type Error = AppError;
+
+    fn try_from(row: ProfessionCategoryRow) -> Result<Self> {
+        Ok(ProfessionCategory {
+            code: row.code,
+            display_name: row.display_name,
+            localized_names: parse_localized_names(row.localized_names)?,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[async_trait]
+impl ProfessionCatalogRepository for PostgresProfessionCatalogRepository {
+    #[instrument(name = "find_profession", skip(self))]
+    async fn find_profession(&self, code: &ProfessionType) -> Result<Option<Profession>> {
+        sqlx::query_as::<_, ProfessionRow>(
+            "SELECT code, display_name, localized_names, category_code, default_rate, is_active, created_at, updated_at
+             FROM professions WHERE code = $1",
+        )
+        .bind(code.as_str())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Profession::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "list_professions", skip(self))]
+    async fn list_professions(&self, include_inactive: bool) -> Result<Vec<Profession>> {
+        sqlx::query_as::<_, ProfessionRow>(
+            "SELECT code, display_name, localized_names, category_code, default_rate, is_active, created_at, updated_at
+             FROM professions
+             WHERE $1 OR is_active = true
+             ORDER BY category_code, code",
+        )
+        .bind(include_inactive)
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Profession::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "create_profession", skip(self, profession))]
+    async fn create_profession(&self, profession: &Profession) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO professions (code, display_name, localized_names, category_code, default_rate, is_active, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            profession.code.as_str(),
+            profession.display_name,
+            localized_names_json(&profession.localized_names)?,
+            profession.category_code,
+            profession.default_rate.value(),
+            profession.is_active,
+            profession.created_at,
+            profession.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("保存职业失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "update_profession", skip(self, profession))]
+    async fn update_profession(&self, profession: &Profession) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE professions
+            SET display_name = $2, localized_names = $3, category_code = $4, default_rate = $5, is_active = $6, updated_at = $7
+            WHERE code = $1
+            "#,
+            profession.code.as_str(),
+            profession.display_name,
+            localized_names_json(&profession.localized_names)?,
+            profession.category_code,
+            profession.default_rate.value(),
+            profession.is_active,
+            profession.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("更新职业失败: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("职业不存在"));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(name = "find_profession_category", skip(self))]
+    async fn find_category(&self, code: &str) -> Result<Option<ProfessionCategory>> {
+        sqlx::query_as::<_, ProfessionCategoryRow>(
+            "SELECT code, display_name, localized_names, created_at, updated_at
+             FROM profession_categories WHERE code = $1",
+        )
+        .bind(code)
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(ProfessionCategory::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "list_profession_categories", skip(self))]
+    async fn list_categories(&self) -> Result<Vec<ProfessionCategory>> {
+        sqlx::query_as::<_, ProfessionCategoryRow>(
+            "SELECT code, display_name, localized_names, created_at, updated_at
+             FROM profession_categories
+             ORDER BY code",
+        )
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(ProfessionCategory::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "create_profession_category", skip(self, category))]
+    async fn create_category(&self, category: &ProfessionCategory) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO profession_categories (code, display_name, localized_names, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5)
+            "#,
+            category.code,
+            category.display_name,
+            localized_names_json(&category.localized_names)?,
+            category.created_at,
+            category.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("保存职业分类失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "update_profession_category", skip(self, category))]
+    async fn update_category(&self, category: &ProfessionCategory) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE profession_categories
+            SET display_name = $2, localized_names = $3, updated_at = $4
+            WHERE code = $1
+            "#,
+            category.code,
+            category.display_name,
+            localized_names_json(&category.localized_names)?,
+            category.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("更新职业分类失败: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("职业分类不存在"));
+        }
+
+        Ok(())
+    }
+}
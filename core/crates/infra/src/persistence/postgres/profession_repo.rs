@@ -0,0 +1,355 @@
+//! ProfessionStandard Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    isu::ISURate,
+    member::MemberId,
+    profession::{
+        ProfessionStandardEntity, ProfessionStandardHistory, ProfessionStandardId,
+        ProfessionStandardRepository, ProfessionType,
+    },
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use sqlx::{Connection, FromRow, PgExecutor, PgPool};
+use std::convert::TryFrom;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::session::PgSession;
+
+/// PostgreSQL ProfessionStandard Repository
+pub struct PostgresProfessionStandardRepository {
+    session: PgSession,
+}
+
+impl PostgresProfessionStandardRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+}
+
+/// 职业标准行结构
+#[derive(Debug, Clone, FromRow)]
+struct ProfessionStandardRow {
+    id: Uuid,
+    profession_type: String,
+    isu_rate: Decimal,
+    description: String,
+    is_active: bool,
+    created_by: Uuid,
+    updated_by: Uuid,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// 变更历史行结构
+#[derive(Debug, Clone, FromRow)]
+struct ProfessionStandardHistoryRow {
+    id: Uuid,
+    standard_id: Uuid,
+    action: String,
+    old_rate: Option<Decimal>,
+    new_rate: Option<Decimal>,
+    reason: String,
+    changed_by: Uuid,
+    created_at: DateTime<Utc>,
+}
+
+/// Row -> Domain 转换
+impl TryFrom<ProfessionStandardRow> for ProfessionStandardEntity {
+    type Error = AppError;
+
+    fn try_from(row: ProfessionStandardRow) -> Result<Self> {
+        Ok(ProfessionStandardEntity {
+            id: ProfessionStandardId::from_uuid(row.id),
+            profession_type: row.profession_type.parse()?,
+            isu_rate: ISURate::new(row.isu_rate)
+                .map_err(|e| AppError::internal(format!("数据库中的费率无效: {}", e)))?,
+            description: row.description,
+            is_active: row.is_active,
+            created_by: MemberId::from_uuid(row.created_by),
+            updated_by: MemberId::from_uuid(row.updated_by),
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+impl TryFrom<ProfessionStandardHistoryRow> for ProfessionStandardHistory {
+    type Error = AppError;
+
+    fn try_from(row: ProfessionStandardHistoryRow) -> Result<Self> {
+        Ok(ProfessionStandardHistory {
+            id: row.id.to_string(),
+            standard_id: ProfessionStandardId::from_uuid(row.standard_id),
+            action: row.action.parse()?,
+            old_rate: row.old_rate.map(ISURate::new).transpose()?,
+            new_rate: row.new_rate.map(ISURate::new).transpose()?,
+            reason: row.reason,
+            changed_by: MemberId::from_uuid(row.changed_by),
+            created_at: row.created_at,
+        })
+    }
+}
+
+async fn insert_standard<'e>(
+    executor: impl PgExecutor<'e>,
+    standard: &ProfessionStandardEntity,
+) -> Result<()> {
+    sqlx::query!(
+        r#"
+        INSERT INTO profession_standards (id, profession_type, isu_rate, description, is_active, created_by, updated_by, created_at, updated_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9)
+        "#,
+        standard.id.value(),
+        standard.profession_type.as_str(),
+        standard.isu_rate.value(),
+        standard.description,
+        standard.is_active,
+        standard.created_by.value(),
+        standard.updated_by.value(),
+        standard.created_at,
+        standard.updated_at
+    )
+    .execute(executor)
+    .await
+    .map_err(|e| AppError::internal(format!("保存职业标准失败: {}", e)))?;
+
+    Ok(())
+}
+
+async fn update_standard<'e>(
+    executor: impl PgExecutor<'e>,
+    standard: &ProfessionStandardEntity,
+) -> Result<()> {
+    let result = sqlx::query!(
+        r#"
+        UPDATE profession_standards
+        SET isu_rate = $2, description = $3, is_active = $4, updated_by = $5, updated_at = $6
+        WHERE id = $1
+        "#,
+        standard.id.value(),
+        standard.isu_rate.value(),
+        standard.description,
+        standard.is_active,
+        standard.updated_by.value(),
+        standard.updated_at
+    )
+    .execute(executor)
+    .await
+    .map_err(|e| AppError::internal(format!("更新职业标准失败: {}", e)))?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("职业标准不存在"));
+    }
+
+    Ok(())
+}
+
+async fn insert_history<'e>(
+    executor: impl PgExecutor<'e>,
+    history: &ProfessionStandardHistory,
+) -> Result<()> {
+    let id = Uuid::parse_str(&history.id)
+        .map_err(|e| AppError::internal(format!("无效的历史记录ID: {}", e)))?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO profession_standard_history (id, standard_id, action, old_rate, new_rate, reason, changed_by, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+        "#,
+        id,
+        history.standard_id.value(),
+        history.action.to_string(),
+        history.old_rate.map(|rate| rate.value()),
+        history.new_rate.map(|rate| rate.value()),
+        history.reason,
+        history.changed_by.value(),
+        history.created_at
+    )
+    .execute(executor)
+    .await
+    .map_err(|e| AppError::internal(format!("保存变更历史失败: {}", e)))?;
+
+    Ok(())
+}
+
+#[async_trait]
+impl ProfessionStandardRepository for PostgresProfessionStandardRepository {
+    #[instrument(name = "save_profession_standard", skip(self, standard))]
+    async fn save(&self, standard: &ProfessionStandardEntity) -> Result<()> {
+        insert_standard(&mut *self.session.acquire().await?, standard).await
+    }
+
+    #[instrument(name = "find_profession_standard_by_id", skip(self))]
+    async fn find_by_id(&self, id: &ProfessionStandardId) -> Result<Option<ProfessionStandardEntity>> {
+        sqlx::query_as::<_, ProfessionStandardRow>(
+            "SELECT id, profession_type, isu_rate, description, is_active, created_by, updated_by, created_at, updated_at
+             FROM profession_standards WHERE id = $1",
+        )
+        .bind(id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(ProfessionStandardEntity::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_active_profession_standard", skip(self))]
+    async fn find_active_by_profession(
+        &self,
+        profession_type: &ProfessionType,
+    ) -> Result<Option<ProfessionStandardEntity>> {
+        sqlx::query_as::<_, ProfessionStandardRow>(
+            "SELECT id, profession_type, isu_rate, description, is_active, created_by, updated_by, created_at, updated_at
+             FROM profession_standards WHERE profession_type = $1 AND is_active = true",
+        )
+        .bind(profession_type.as_str())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(ProfessionStandardEntity::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_profession_standards_by_profession", skip(self))]
+    async fn find_all_by_profession(
+        &self,
+        profession_type: &ProfessionType,
+    ) -> Result<Vec<ProfessionStandardEntity>> {
+        sqlx::query_as::<_, ProfessionStandardRow>(
+            "SELECT id, profession_type, isu_rate, description, is_active, created_by, updated_by, created_at, updated_at
+             FROM profession_standards WHERE profession_type = $1
+             ORDER BY created_at DESC",
+        )
+        .bind(profession_type.as_str())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(ProfessionStandardEntity::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "find_profession_standards_by_manager", skip(self))]
+    async fn find_by_manager(&self, manager_id: &MemberId) -> Result<Vec<ProfessionStandardEntity>> {
+        // 与 Member::can_manage_profession 一致：管理员可管理全部，决策者仅限 managed_professions
+        sqlx::query_as::<_, ProfessionStandardRow>(
+            "SELECT ps.id, ps.profession_type, ps.isu_rate, ps.description, ps.is_active,
+                    ps.created_by, ps.updated_by, ps.created_at, ps.updated_at
+             FROM profession_standards ps
+             JOIN members m ON m.id = $1
+             WHERE m.role = 'admin'
+                OR (m.role = 'decider' AND m.managed_professions @> jsonb_build_array(ps.profession_type))
+             ORDER BY ps.profession_type",
+        )
+        .bind(manager_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(ProfessionStandardEntity::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "update_profession_standard", skip(self, standard))]
+    async fn update(&self, standard: &ProfessionStandardEntity) -> Result<()> {
+        update_standard(&mut *self.session.acquire().await?, standard).await
+    }
+
+    #[instrument(name = "delete_profession_standard", skip(self))]
+    async fn delete(&self, id: &ProfessionStandardId) -> Result<()> {
+        sqlx::query!("DELETE FROM profession_standards WHERE id = $1", id.value())
+            .execute(&mut *self.session.acquire().await?)
+            .await
+            .map_err(|e| AppError::internal(format!("删除失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "save_profession_standard_history", skip(self, history))]
+    async fn save_history(&self, history: &ProfessionStandardHistory) -> Result<()> {
+        insert_history(&mut *self.session.acquire().await?, history).await
+    }
+
+    #[instrument(name = "save_profession_standard_with_history", skip(self, standard, history))]
+    async fn save_with_history(
+        &self,
+        standard: &ProfessionStandardEntity,
+        history: &ProfessionStandardHistory,
+    ) -> Result<()> {
+        let mut conn = self.session.acquire().await?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| AppError::internal(format!("开启事务失败: {}", e)))?;
+
+        insert_standard(&mut *tx, standard).await?;
+        insert_history(&mut *tx, history).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::internal(format!("提交事务失败: {}", e)))
+    }
+
+    #[instrument(name = "update_profession_standard_with_history", skip(self, standard, history))]
+    async fn update_with_history(
+        &self,
+        standard: &ProfessionStandardEntity,
+        history: &ProfessionStandardHistory,
+    ) -> Result<()> {
+        let mut conn = self.session.acquire().await?;
+        let mut tx = conn
+            .begin()
+            .await
+            .map_err(|e| AppError::internal(format!("开启事务失败: {}", e)))?;
+
+        update_standard(&mut *tx, standard).await?;
+        insert_history(&mut *tx, history).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::internal(format!("提交事务失败: {}", e)))
+    }
+
+    #[instrument(name = "get_profession_standard_history", skip(self))]
+    async fn get_history(
+        &self,
+        standard_id: &ProfessionStandardId,
+    ) -> Result<Vec<ProfessionStandardHistory>> {
+        sqlx::query_as::<_, ProfessionStandardHistoryRow>(
+            "SELECT id, standard_id, action, old_rate, new_rate, reason, changed_by, created_at
+             FROM profession_standard_history WHERE standard_id = $1
+             ORDER BY created_at DESC",
+        )
+        .bind(standard_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(ProfessionStandardHistory::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "find_all_active_profession_standards", skip(self))]
+    async fn find_all_active(&self) -> Result<Vec<ProfessionStandardEntity>> {
+        sqlx::query_as::<_, ProfessionStandardRow>(
+            "SELECT id, profession_type, isu_rate, description, is_active, created_by, updated_by, created_at, updated_at
+             FROM profession_standards WHERE is_active = true
+             ORDER BY profession_type",
+        )
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(ProfessionStandardEntity::try_from)
+        .collect()
+    }
+}
@@ -0,0 +1,156 @@
+//! RefreshToken Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    auth::{RefreshToken, RefreshTokenFamilyId, RefreshTokenId, RefreshTokenRepository},
+    member::MemberId,
+};
+use shared::{AppError, Result};
+use sqlx::{FromRow, PgPool};
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::session::PgSession;
+
+/// PostgreSQL RefreshToken Repository
+pub struct PostgresRefreshTokenRepository {
+    session: PgSession,
+}
+
+impl PostgresRefreshTokenRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+}
+
+/// 数据库行结构
+#[derive(Debug, Clone, FromRow)]
+struct RefreshTokenRow {
+    id: Uuid,
+    member_id: Uuid,
+    family_id: Uuid,
+    token_hash: String,
+    expires_at: DateTime<Utc>,
+    used_at: Option<DateTime<Utc>>,
+    revoked_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+}
+
+/// Row -> Domain 转换
+impl From<RefreshTokenRow> for RefreshToken {
+    fn from(row: RefreshTokenRow) -> Self {
+        RefreshToken {
+            id: RefreshTokenId::from_uuid(row.id),
+            member_id: MemberId::from_uuid(row.member_id),
+            family_id: RefreshTokenFamilyId::from_uuid(row.family_id),
+            token_hash: row.token_hash,
+            expires_at: row.expires_at,
+            used_at: row.used_at,
+            revoked_at: row.revoked_at,
+            created_at: row.created_at,
+        }
+    }
+}
+
+#[async_trait]
+impl RefreshTokenRepository for PostgresRefreshTokenRepository {
+    #[instrument(name = "save_refresh_token", skip(self, token))]
+    async fn save(&self, token: &RefreshToken) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO refresh_tokens (id, member_id, family_id, token_hash, expires_at, used_at, revoked_at, created_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            token.id.value(),
+            token.member_id.value(),
+            token.family_id.value(),
+            token.token_hash,
+            token.expires_at,
+            token.used_at,
+            token.revoked_at,
+            token.created_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("保存刷新令牌失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "find_refresh_token_by_hash", skip(self, token_hash))]
+    async fn find_by_hash(&self, token_hash: &str) -> Result<Option<RefreshToken>> {
+        let row = sqlx::query_as::<_, RefreshTokenRow>(
+            "SELECT id, member_id, family_id, token_hash, expires_at, used_at, revoked_at, created_at
+             FROM refresh_tokens WHERE token_hash = $1",
+        )
+        .bind(token_hash)
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?;
+
+        Ok(row.map(RefreshToken::from))
+    }
+
+    #[instrument(name = "find_refresh_token_by_hash_for_update", skip(self, token_hash))]
+    async fn find_by_hash_for_update(&self, token_hash: &str) -> Result<Option<RefreshToken>> {
+        let row = sqlx::query_as::<_, RefreshTokenRow>(
+            "SELECT id, member_id, family_id, token_hash, expires_at, used_at, revoked_at, created_at
+             FROM refresh_tokens WHERE token_hash = $1
+             FOR UPDATE",
+        )
+        .bind(token_hash)
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?;
+
+        Ok(row.map(RefreshToken::from))
+    }
+
+    #[instrument(name = "mark_refresh_token_used", skip(self))]
+    async fn mark_used(&self, id: &RefreshTokenId) -> Result<bool> {
+        let result = sqlx::query!(
+            "UPDATE refresh_tokens SET used_at = NOW()
+             WHERE id = $1 AND used_at IS NULL AND revoked_at IS NULL",
+            id.value()
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("更新刷新令牌失败: {}", e)))?;
+
+        Ok(result.rows_affected() == 1)
+    }
+
+    #[instrument(name = "revoke_refresh_token_family", skip(self))]
+    async fn revoke_family(&self, family_id: &RefreshTokenFamilyId) -> Result<u64> {
+        let result = sqlx::query!(
+            "UPDATE refresh_tokens SET revoked_at = NOW()
+             WHERE family_id = $1 AND revoked_at IS NULL",
+            family_id.value()
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("吊销刷新令牌失败: {}", e)))?;
+
+        Ok(result.rows_affected())
+    }
+
+    #[instrument(name = "revoke_member_refresh_tokens", skip(self))]
+    async fn revoke_all_for_member(&self, member_id: &MemberId) -> Result<u64> {
+        let result = sqlx::query!(
+            "UPDATE refresh_tokens SET revoked_at = NOW()
+             WHERE member_id = $1 AND revoked_at IS NULL",
+            member_id.value()
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("吊销刷新令牌失败: {}", e)))?;
+
+        Ok(result.rows_affected())
+    }
+}
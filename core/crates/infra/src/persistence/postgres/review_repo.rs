@@ -0,0 +1,201 @@
+//! Review Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    member::MemberId,
+    review::{Rating, RatingSummary, Review, ReviewId, ReviewRepository},
+    service::ServiceId,
+    transaction::TransactionId,
+};
+use shared::{AppError, Result};
+use sqlx::{FromRow, PgPool};
+use std::convert::TryFrom;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::session::PgSession;
+
+/// 唯一约束冲突（unique_violation）
+const UNIQUE_VIOLATION: &str = "23505";
+
+/// 同一评价人对同一交易只能评价一次
+const REVIEW_UNIQUE_CONSTRAINT: &str = "uq_review_transaction_reviewer";
+
+/// PostgreSQL Review Repository
+pub struct PostgresReviewRepository {
+    session: PgSession,
+}
+
+impl PostgresReviewRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+}
+
+/// 评分汇总列 -> 领域对象（会员和服务仓储共用）
+pub(super) fn rating_summary(count: i32, total: i32) -> Result<RatingSummary> {
+    let column = |value: i32| {
+        u32::try_from(value)
+            .map_err(|_| AppError::internal(format!("数据库中的评分汇总无效: {}", value)))
+    };
+
+    Ok(RatingSummary {
+        count: column(count)?,
+        total: column(total)?,
+    })
+}
+
+/// 数据库行结构
+#[derive(Debug, Clone, FromRow)]
+struct ReviewRow {
+    id: Uuid,
+    transaction_id: Uuid,
+    reviewer_id: Uuid,
+    reviewee_id: Uuid,
+    service_id: Option<Uuid>,
+    rating: i16,
+    comment: Option<String>,
+    created_at: DateTime<Utc>,
+}
+
+/// Row -> Domain 转换
+impl TryFrom<ReviewRow> for Review {
+    type Error = AppError;
+
+    fn try_from(row: ReviewRow) -> Result<Self> {
+        let rating = u8::try_from(row.rating)
+            .map_err(|_| AppError::internal(format!("数据库中的评分无效: {}", row.rating)))
+            .and_then(|value| {
+                Rating::new(value)
+                    .map_err(|e| AppError::internal(format!("数据库中的评分无效: {}", e)))
+            })?;
+
+        Ok(Review {
+            id: ReviewId::from_uuid(row.id),
+            transaction_id: TransactionId::from_uuid(row.transaction_id),
+            reviewer_id: MemberId::from_uuid(row.reviewer_id),
+            reviewee_id: MemberId::from_uuid(row.reviewee_id),
+            service_id: row.service_id.map(ServiceId::from_uuid),
+            rating,
+            comment: row.comment,
+            created_at: row.created_at,
+        })
+    }
+}
+
+#[async_trait]
+impl ReviewRepository for PostgresReviewRepository {
+    #[instrument(name = "save_review", skip(self, review))]
+    async fn save(&self, review: &Review) -> Result<()> {
+        // 评价写入与评分汇总累加在同一条语句中完成，不依赖外层事务
+        sqlx::query!(
+            r#"
+            WITH inserted AS (
+                INSERT INTO reviews (id, transaction_id, reviewer_id, reviewee_id, service_id, rating, comment, created_at)
+                VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                RETURNING reviewee_id, service_id, rating
+            ),
+            member_rating AS (
+                UPDATE members
+                SET rating_count = members.rating_count + 1,
+                    rating_total = members.rating_total + inserted.rating
+                FROM inserted
+                WHERE members.id = inserted.reviewee_id
+            )
+            UPDATE services
+            SET rating_count = services.rating_count + 1,
+                rating_total = services.rating_total + inserted.rating
+            FROM inserted
+            WHERE services.id = inserted.service_id
+            "#,
+            review.id.value(),
+            review.transaction_id.value(),
+            review.reviewer_id.value(),
+            review.reviewee_id.value(),
+            review.service_id.map(|id| id.value()),
+            i16::from(review.rating.value()),
+            review.comment,
+            review.created_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| {
+            let is_duplicate = e.as_database_error().is_some_and(|db| {
+                db.code().is_some_and(|code| code == UNIQUE_VIOLATION)
+                    && db.constraint() == Some(REVIEW_UNIQUE_CONSTRAINT)
+            });
+            if is_duplicate {
+                AppError::conflict("您已评价过该交易")
+            } else {
+                AppError::internal(format!("保存评价失败: {}", e))
+            }
+        })?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "find_review_by_transaction_and_reviewer", skip(self))]
+    async fn find_by_transaction_and_reviewer(
+        &self,
+        transaction_id: &TransactionId,
+        reviewer_id: &MemberId,
+    ) -> Result<Option<Review>> {
+        sqlx::query_as::<_, ReviewRow>(
+            "SELECT id, transaction_id, reviewer_id, reviewee_id, service_id, rating, comment, created_at
+             FROM reviews WHERE transaction_id = $1 AND reviewer_id = $2",
+        )
+        .bind(transaction_id.value())
+        .bind(reviewer_id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Review::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_reviews_by_transaction", skip(self))]
+    async fn find_by_transaction_id(&self, transaction_id: &TransactionId) -> Result<Vec<Review>> {
+        sqlx::query_as::<_, ReviewRow>(
+            "SELECT id, transaction_id, reviewer_id, reviewee_id, service_id, rating, comment, created_at
+             FROM reviews WHERE transaction_id = $1
+             ORDER BY created_at ASC",
+        )
+        .bind(transaction_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Review::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "find_reviews_by_reviewee", skip(self))]
+    async fn find_by_reviewee(
+        &self,
+        reviewee_id: &MemberId,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<Review>> {
+        sqlx::query_as::<_, ReviewRow>(
+            "SELECT id, transaction_id, reviewer_id, reviewee_id, service_id, rating, comment, created_at
+             FROM reviews WHERE reviewee_id = $1
+             ORDER BY created_at DESC
+             LIMIT $2 OFFSET $3",
+        )
+        .bind(reviewee_id.value())
+        .bind(i64::from(limit.unwrap_or(20)))
+        .bind(i64::from(offset.unwrap_or(0)))
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Review::try_from)
+        .collect()
+    }
+}
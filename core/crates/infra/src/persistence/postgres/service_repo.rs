@@ -0,0 +1,289 @@
+//! Service Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    isu::{ISURate, ISU},
+    member::MemberId,
+    profession::ProfessionType,
+    service::{Service, ServiceId, ServiceRepository, ServiceSearchCriteria, ServiceSort},
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use sqlx::{FromRow, PgPool};
+use std::convert::TryFrom;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::review_repo::rating_summary;
+use super::session::PgSession;
+
+/// PostgreSQL Service Repository
+pub struct PostgresServiceRepository {
+    session: PgSession,
+}
+
+impl PostgresServiceRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+}
+
+/// 数据库行结构
+#[derive(Debug, Clone, FromRow)]
+struct ServiceRow {
+    id: Uuid,
+    provider_id: Uuid,
+    profession_type: String,
+    title: String,
+    description: String,
+    estimated_hours: Decimal,
+    isu_rate: Decimal,
+    total_isu: Decimal,
+    status: String,
+    rating_count: i32,
+    rating_total: i32,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Row -> Domain 转换
+impl TryFrom<ServiceRow> for Service {
+    type Error = AppError;
+
+    fn try_from(row: ServiceRow) -> Result<Self> {
+        Ok(Service {
+            id: ServiceId::from_uuid(row.id),
+            provider_id: MemberId::from_uuid(row.provider_id),
+            profession_type: row.profession_type.parse()?,
+            title: row.title,
+            description: row.description,
+            estimated_hours: row.estimated_hours,
+            isu_rate: ISURate::new(row.isu_rate)
+                .map_err(|e| AppError::internal(format!("数据库中的服务费率无效: {}", e)))?,
+            total_isu: ISU::new(row.total_isu)
+                .map_err(|e| AppError::internal(format!("数据库中的服务总价无效: {}", e)))?,
+            status: row.status.parse()?,
+            rating: rating_summary(row.rating_count, row.rating_total)?,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+/// 转义 LIKE 通配符，避免用户输入的 % 和 _ 被当作模式（会员仓储共用）
+pub(super) fn escape_like(keyword: &str) -> String {
+    keyword
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+#[async_trait]
+impl ServiceRepository for PostgresServiceRepository {
+    #[instrument(name = "save_service", skip(self, service))]
+    async fn save(&self, service: &Service) -> Result<()> {
+        // 用例层对新建和状态变更都调用 save，因此按 id 做 upsert
+        sqlx::query!(
+            r#"
+            INSERT INTO services (id, provider_id, profession_type, title, description, estimated_hours, isu_rate, total_isu, status, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
+            ON CONFLICT (id) DO UPDATE
+            SET profession_type = EXCLUDED.profession_type,
+                title = EXCLUDED.title,
+                description = EXCLUDED.description,
+                estimated_hours = EXCLUDED.estimated_hours,
+                isu_rate = EXCLUDED.isu_rate,
+                total_isu = EXCLUDED.total_isu,
+                status = EXCLUDED.status,
+                updated_at = EXCLUDED.updated_at
+            "#,
+            service.id.value(),
+            service.provider_id.value(),
+            service.profession_type.to_string(),
+            service.title,
+            service.description,
+            service.estimated_hours,
+            service.isu_rate.value(),
+            service.total_isu.value(),
+            service.status.to_string(),
+            service.created_at,
+            service.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("保存服务失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "find_service_by_id", skip(self))]
+    async fn find_by_id(&self, id: &ServiceId) -> Result<Option<Service>> {
+        sqlx::query_as::<_, ServiceRow>(
+            "SELECT id, provider_id, profession_type, title, description, estimated_hours, isu_rate, total_isu, status, rating_count, rating_total, created_at, updated_at
+             FROM services WHERE id = $1",
+        )
+        .bind(id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Service::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_services_by_provider", skip(self))]
+    async fn find_by_provider_id(&self, provider_id: &MemberId) -> Result<Vec<Service>> {
+        sqlx::query_as::<_, ServiceRow>(
+            "SELECT id, provider_id, profession_type, title, description, estimated_hours, isu_rate, total_isu, status, rating_count, rating_total, created_at, updated_at
+             FROM services WHERE provider_id = $1
+             ORDER BY created_at DESC",
+        )
+        .bind(provider_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Service::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "find_available_services_by_profession", skip(self))]
+    async fn find_available_by_profession(
+        &self,
+        profession_type: &ProfessionType,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<Service>> {
+        sqlx::query_as::<_, ServiceRow>(
+            "SELECT id, provider_id, profession_type, title, description, estimated_hours, isu_rate, total_isu, status, rating_count, rating_total, created_at, updated_at
+             FROM services WHERE status = 'available' AND profession_type = $1
+             ORDER BY created_at DESC
+             LIMIT $2 OFFSET $3",
+        )
+        .bind(profession_type.as_str())
+        .bind(i64::from(limit.unwrap_or(20)))
+        .bind(i64::from(offset.unwrap_or(0)))
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Service::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "find_available_services", skip(self))]
+    async fn find_available_services(
+        &self,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<Service>> {
+        sqlx::query_as::<_, ServiceRow>(
+            "SELECT id, provider_id, profession_type, title, description, estimated_hours, isu_rate, total_isu, status, rating_count, rating_total, created_at, updated_at
+             FROM services WHERE status = 'available'
+             ORDER BY created_at DESC
+             LIMIT $1 OFFSET $2",
+        )
+        .bind(i64::from(limit.unwrap_or(20)))
+        .bind(i64::from(offset.unwrap_or(0)))
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Service::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "search_services", skip(self))]
+    async fn search_services(
+        &self,
+        criteria: &ServiceSearchCriteria,
+        limit: Option<u32>,
+        offset: Option<u32>,
+    ) -> Result<Vec<Service>> {
+        let keyword = criteria.keyword.trim();
+        if keyword.is_empty()
+            && criteria.min_provider_reputation.is_none()
+            && criteria.sort == ServiceSort::Relevance
+        {
+            return match &criteria.profession_type {
+                Some(profession_type) => {
+                    self.find_available_by_profession(profession_type, limit, offset)
+                        .await
+                }
+                None => self.find_available_services(limit, offset).await,
+            };
+        }
+
+        // 全文检索按 ts_rank 排序；simple 词典不切分中文，因此再用 ILIKE 兜底子串匹配。
+        // 按信誉排序时信誉分优先，相关度次之
+        sqlx::query_as::<_, ServiceRow>(
+            r#"
+            SELECT s.id, s.provider_id, s.profession_type, s.title, s.description, s.estimated_hours, s.isu_rate, s.total_isu, s.status,
+                   s.rating_count, s.rating_total, s.created_at, s.updated_at
+            FROM services s
+            JOIN members m ON m.id = s.provider_id,
+                 websearch_to_tsquery('simple', $1) AS query
+            WHERE s.status = 'available'
+              AND ($2::VARCHAR IS NULL OR s.profession_type = $2)
+              AND ($1 = '' OR s.search_vector @@ query OR s.title ILIKE $3 OR s.description ILIKE $3)
+              AND ($4::NUMERIC IS NULL OR m.reputation_score >= $4)
+            ORDER BY CASE WHEN $5 = 'reputation' THEN m.reputation_score END DESC NULLS LAST,
+                     CASE WHEN $1 = '' THEN 0 ELSE ts_rank(s.search_vector, query) END DESC,
+                     (s.title ILIKE $3) DESC,
+                     s.created_at DESC
+            LIMIT $6 OFFSET $7
+            "#,
+        )
+        .bind(keyword)
+        .bind(criteria.profession_type.as_ref().map(ProfessionType::as_str))
+        .bind(format!("%{}%", escape_like(keyword)))
+        .bind(criteria.min_provider_reputation)
+        .bind(criteria.sort.to_string())
+        .bind(i64::from(limit.unwrap_or(20)))
+        .bind(i64::from(offset.unwrap_or(0)))
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("搜索服务失败: {}", e)))?
+        .into_iter()
+        .map(Service::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "delete_service", skip(self))]
+    async fn delete(&self, id: &ServiceId) -> Result<()> {
+        sqlx::query!("DELETE FROM services WHERE id = $1", id.value())
+            .execute(&mut *self.session.acquire().await?)
+            .await
+            .map_err(|e| AppError::internal(format!("删除失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "count_services_by_provider", skip(self))]
+    async fn count_by_provider(&self, provider_id: &MemberId) -> Result<u64> {
+        let result = sqlx::query!(
+            "SELECT COUNT(*) as count FROM services WHERE provider_id = $1",
+            provider_id.value()
+        )
+        .fetch_one(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("统计失败: {}", e)))?;
+
+        Ok(result.count.unwrap_or(0) as u64)
+    }
+
+    #[instrument(name = "count_available_services", skip(self))]
+    async fn count_available_services(&self) -> Result<u64> {
+        let result = sqlx::query!("SELECT COUNT(*) as count FROM services WHERE status = 'available'")
+            .fetch_one(&mut *self.session.acquire().await?)
+            .await
+            .map_err(|e| AppError::internal(format!("统计失败: {}", e)))?;
+
+        Ok(result.count.unwrap_or(0) as u64)
+    }
+}
@@ -0,0 +1,75 @@
+//! 数据库会话
+//!
+//! 仓储既可以直接运行在连接池上，也可以共享同一个事务（工作单元），
+//! 两种模式通过 `PgSession` 统一获取连接。
+
+use shared::{AppError, Result};
+use sqlx::pool::PoolConnection;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+use tokio::sync::{Mutex, OwnedMutexGuard};
+
+/// 多个仓储共享的事务，提交或回滚后变为 `None`
+pub type SharedTransaction = Arc<Mutex<Option<Transaction<'static, Postgres>>>>;
+
+/// 仓储使用的数据库会话
+#[derive(Clone)]
+pub enum PgSession {
+    /// 每次操作从连接池获取独立连接
+    Pool(PgPool),
+    /// 所有操作运行在同一个事务中
+    Transaction(SharedTransaction),
+}
+
+impl PgSession {
+    /// 获取当前会话的连接
+    pub async fn acquire(&self) -> Result<PgConnectionGuard> {
+        match self {
+            Self::Pool(pool) => pool
+                .acquire()
+                .await
+                .map(PgConnectionGuard::Pool)
+                .map_err(|e| AppError::internal(format!("获取数据库连接失败: {}", e))),
+            Self::Transaction(tx) => {
+                let guard = tx.clone().lock_owned().await;
+                if guard.is_none() {
+                    return Err(AppError::internal("工作单元已提交或回滚"));
+                }
+                Ok(PgConnectionGuard::Transaction(guard))
+            }
+        }
+    }
+}
+
+impl From<PgPool> for PgSession {
+    fn from(pool: PgPool) -> Self {
+        Self::Pool(pool)
+    }
+}
+
+/// 会话连接守卫，解引用为 `PgConnection`
+pub enum PgConnectionGuard {
+    Pool(PoolConnection<Postgres>),
+    Transaction(OwnedMutexGuard<Option<Transaction<'static, Postgres>>>),
+}
+
+impl Deref for PgConnectionGuard {
+    type Target = PgConnection;
+
+    fn deref(&self) -> &PgConnection {
+        match self {
+            Self::Pool(conn) => conn,
+            Self::Transaction(guard) => guard.as_deref().expect("acquire 时已检查事务存在"),
+        }
+    }
+}
+
+impl DerefMut for PgConnectionGuard {
+    fn deref_mut(&mut self) -> &mut PgConnection {
+        match self {
+            Self::Pool(conn) => conn,
+            Self::Transaction(guard) => guard.as_deref_mut().expect("acquire 时已检查事务存在"),
+        }
+    }
+}
@@ -0,0 +1,147 @@
+//! ToolRental Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, NaiveDate, Utc};
+use domain::{
+    isu::ISU,
+    member::MemberId,
+    tool::{RentalPeriod, ToolId, ToolRental, ToolRentalRepository},
+    transaction::TransactionId,
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use sqlx::{FromRow, PgPool};
+use std::convert::TryFrom;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::session::PgSession;
+
+/// PostgreSQL ToolRental Repository
+pub struct PostgresToolRentalRepository {
+    session: PgSession,
+}
+
+impl PostgresToolRentalRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+}
+
+/// 数据库行结构
+#[derive(Debug, Clone, FromRow)]
+struct ToolRentalRow {
+    transaction_id: Uuid,
+    tool_id: Uuid,
+    renter_id: Uuid,
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    daily_rate: Decimal,
+    rental_fee: Decimal,
+    deposit: Decimal,
+    deposit_status: String,
+    returned_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Row -> Domain 转换
+impl TryFrom<ToolRentalRow> for ToolRental {
+    type Error = AppError;
+
+    fn try_from(row: ToolRentalRow) -> Result<Self> {
+        let isu = |amount: Decimal| {
+            ISU::new(amount).map_err(|e| AppError::internal(format!("数据库中的租用金额无效: {}", e)))
+        };
+
+        Ok(ToolRental {
+            transaction_id: TransactionId::from_uuid(row.transaction_id),
+            tool_id: ToolId::from_uuid(row.tool_id),
+            renter_id: MemberId::from_uuid(row.renter_id),
+            period: RentalPeriod {
+                start_date: row.start_date,
+                end_date: row.end_date,
+            },
+            daily_rate: isu(row.daily_rate)?,
+            rental_fee: isu(row.rental_fee)?,
+            deposit: isu(row.deposit)?,
+            deposit_status: row.deposit_status.parse()?,
+            returned_at: row.returned_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[async_trait]
+impl ToolRentalRepository for PostgresToolRentalRepository {
+    #[instrument(name = "save_tool_rental", skip(self, rental))]
+    async fn save(&self, rental: &ToolRental) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO tool_rentals (transaction_id, tool_id, renter_id, start_date, end_date, daily_rate, rental_fee, deposit, deposit_status, returned_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
+            "#,
+            rental.transaction_id.value(),
+            rental.tool_id.value(),
+            rental.renter_id.value(),
+            rental.period.start_date,
+            rental.period.end_date,
+            rental.daily_rate.value(),
+            rental.rental_fee.value(),
+            rental.deposit.value(),
+            rental.deposit_status.to_string(),
+            rental.returned_at,
+            rental.created_at,
+            rental.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("保存租用记录失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "find_tool_rental_by_transaction", skip(self))]
+    async fn find_by_transaction_id(&self, transaction_id: &TransactionId) -> Result<Option<ToolRental>> {
+        sqlx::query_as::<_, ToolRentalRow>(
+            "SELECT transaction_id, tool_id, renter_id, start_date, end_date, daily_rate, rental_fee, deposit, deposit_status, returned_at, created_at, updated_at
+             FROM tool_rentals WHERE transaction_id = $1",
+        )
+        .bind(transaction_id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(ToolRental::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "update_tool_rental", skip(self, rental))]
+    async fn update(&self, rental: &ToolRental) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE tool_rentals
+            SET deposit_status = $2, returned_at = $3, updated_at = $4
+            WHERE transaction_id = $1
+            "#,
+            rental.transaction_id.value(),
+            rental.deposit_status.to_string(),
+            rental.returned_at,
+            rental.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("更新租用记录失败: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("租用记录不存在"));
+        }
+
+        Ok(())
+    }
+}
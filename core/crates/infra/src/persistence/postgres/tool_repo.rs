@@ -3,23 +3,32 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use domain::{
+    isu::ISU,
     member::MemberId,
     tool::{Currency, Money, Tool, ToolId, ToolRepository, ToolStatus},
 };
+use rust_decimal::Decimal;
 use shared::{AppError, Result};
 use sqlx::{FromRow, PgPool};
 use std::convert::TryFrom;
 use tracing::instrument;
 use uuid::Uuid;
 
+use super::session::PgSession;
+
 /// PostgreSQL Tool Repository
 pub struct PostgresToolRepository {
-    pool: PgPool,
+    session: PgSession,
 }
 
 impl PostgresToolRepository {
     pub fn new(pool: PgPool) -> Self {
-        Self { pool }
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
     }
 }
 
@@ -33,6 +42,8 @@ struct ToolRow {
     category: String,
     price_amount: i64,
     price_currency: String,
+    daily_rate: Option<Decimal>,
+    deposit: Decimal,
     status: String,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
@@ -63,6 +74,13 @@ impl TryFrom<ToolRow> for Tool {
             description: row.description,
             category: row.category,
             price,
+            daily_rate: row
+                .daily_rate
+                .map(ISU::new)
+                .transpose()
+                .map_err(|e| AppError::internal(format!("数据库中的工具租金无效: {}", e)))?,
+            deposit: ISU::new(row.deposit)
+                .map_err(|e| AppError::internal(format!("数据库中的工具押金无效: {}", e)))?,
             status,
             created_at: row.created_at,
             updated_at: row.updated_at,
@@ -81,8 +99,8 @@ impl ToolRepository for PostgresToolRepository {
 
         sqlx::query!(
             r#"
-            INSERT INTO tools (id, owner_id, name, description, category, price_amount, price_currency, status, created_at, updated_at)
-            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+            INSERT INTO tools (id, owner_id, name, description, category, price_amount, price_currency, daily_rate, deposit, status, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12)
             "#,
             tool.id.value(),
             tool.owner_id.value(),
@@ -91,11 +109,13 @@ impl ToolRepository for PostgresToolRepository {
             tool.category,
             tool.price.amount,
             currency_str,
+            tool.daily_rate.map(|rate| rate.value()),
+            tool.deposit.value(),
             tool.status.to_string(),
             tool.created_at,
             tool.updated_at
         )
-        .execute(&self.pool)
+        .execute(&mut *self.session.acquire().await?)
         .await
         .map_err(|e| AppError::internal(format!("保存工具失败: {}", e)))?;
 
@@ -105,11 +125,26 @@ impl ToolRepository for PostgresToolRepository {
     #[instrument(name = "find_tool_by_id", skip(self))]
     async fn find_by_id(&self, id: ToolId) -> Result<Option<Tool>> {
         sqlx::query_as::<_, ToolRow>(
-            "SELECT id, owner_id, name, description, category, price_amount, price_currency, status, created_at, updated_at 
-             FROM tools WHERE id = $1",
+            "SELECT id, owner_id, name, description, category, price_amount, price_currency, daily_rate, deposit, status, created_at, updated_at
+             FROM tools WHERE id = $1 AND deleted_at IS NULL",
+        )
+        .bind(id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Tool::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_tool_by_id_for_update", skip(self))]
+    async fn find_by_id_for_update(&self, id: ToolId) -> Result<Option<Tool>> {
+        sqlx::query_as::<_, ToolRow>(
+            "SELECT id, owner_id, name, description, category, price_amount, price_currency, daily_rate, deposit, status, created_at, updated_at
+             FROM tools WHERE id = $1 AND deleted_at IS NULL
+             FOR UPDATE",
         )
         .bind(id.value())
-        .fetch_optional(&self.pool)
+        .fetch_optional(&mut *self.session.acquire().await?)
         .await
         .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
         .map(Tool::try_from)
@@ -126,15 +161,15 @@ impl ToolRepository for PostgresToolRepository {
         let offset = (page - 1) * page_size;
 
         sqlx::query_as::<_, ToolRow>(
-            "SELECT id, owner_id, name, description, category, price_amount, price_currency, status, created_at, updated_at 
-             FROM tools WHERE owner_id = $1
+            "SELECT id, owner_id, name, description, category, price_amount, price_currency, daily_rate, deposit, status, created_at, updated_at
+             FROM tools WHERE owner_id = $1 AND deleted_at IS NULL
              ORDER BY created_at DESC
              LIMIT $2 OFFSET $3",
         )
         .bind(owner_id.value())
         .bind(page_size)
         .bind(offset)
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *self.session.acquire().await?)
         .await
         .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
         .into_iter()
@@ -147,14 +182,14 @@ impl ToolRepository for PostgresToolRepository {
         let offset = (page - 1) * page_size;
 
         sqlx::query_as::<_, ToolRow>(
-            "SELECT id, owner_id, name, description, category, price_amount, price_currency, status, created_at, updated_at 
-             FROM tools WHERE status = 'available'
+            "SELECT id, owner_id, name, description, category, price_amount, price_currency, daily_rate, deposit, status, created_at, updated_at
+             FROM tools WHERE status = 'available' AND deleted_at IS NULL
              ORDER BY created_at DESC
              LIMIT $1 OFFSET $2",
         )
         .bind(page_size)
         .bind(offset)
-        .fetch_all(&self.pool)
+        .fetch_all(&mut *self.session.acquire().await?)
         .await
         .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
         .into_iter()
@@ -173,7 +208,7 @@ impl ToolRepository for PostgresToolRepository {
             r#"
             UPDATE tools
             SET name = $2, description = $3, category = $4, price_amount = $5, 
-                price_currency = $6, status = $7, updated_at = $8
+                price_currency = $6, daily_rate = $7, deposit = $8, status = $9, updated_at = $10
             WHERE id = $1
             "#,
             tool.id.value(),
@@ -182,10 +217,12 @@ impl ToolRepository for PostgresToolRepository {
             tool.category,
             tool.price.amount,
             currency_str,
+            tool.daily_rate.map(|rate| rate.value()),
+            tool.deposit.value(),
             tool.status.to_string(),
             tool.updated_at
         )
-        .execute(&self.pool)
+        .execute(&mut *self.session.acquire().await?)
         .await
         .map_err(|e| AppError::internal(format!("更新工具失败: {}", e)))?;
 
@@ -194,18 +231,25 @@ impl ToolRepository for PostgresToolRepository {
 
     #[instrument(name = "delete_tool", skip(self))]
     async fn delete(&self, id: ToolId) -> Result<()> {
-        sqlx::query!("DELETE FROM tools WHERE id = $1", id.value())
-            .execute(&self.pool)
-            .await
-            .map_err(|e| AppError::internal(format!("删除失败: {}", e)))?;
+        let result = sqlx::query!(
+            "UPDATE tools SET deleted_at = NOW() WHERE id = $1 AND deleted_at IS NULL",
+            id.value()
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("删除失败: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("工具不存在"));
+        }
 
         Ok(())
     }
 
     #[instrument(name = "count_tools", skip(self))]
     async fn count(&self) -> Result<i64> {
-        let result = sqlx::query!("SELECT COUNT(*) as count FROM tools")
-            .fetch_one(&self.pool)
+        let result = sqlx::query!("SELECT COUNT(*) as count FROM tools WHERE deleted_at IS NULL")
+            .fetch_one(&mut *self.session.acquire().await?)
             .await
             .map_err(|e| AppError::internal(format!("统计失败: {}", e)))?;
 
@@ -0,0 +1,209 @@
+//! ToolReservation Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    member::MemberId,
+    tool::{ReservationId, ReservationWindow, ToolId, ToolReservation, ToolReservationRepository},
+    transaction::TransactionId,
+};
+use shared::{AppError, Result};
+use sqlx::{FromRow, PgPool};
+use std::convert::TryFrom;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::session::PgSession;
+
+/// 排他约束冲突（exclusion_violation）
+const EXCLUSION_VIOLATION: &str = "23P01";
+
+/// PostgreSQL ToolReservation Repository
+pub struct PostgresToolReservationRepository {
+    session: PgSession,
+}
+
+impl PostgresToolReservationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+}
+
+/// 数据库行结构（period 拆分为上下界读取）
+#[derive(Debug, Clone, FromRow)]
+struct ToolReservationRow {
+    id: Uuid,
+    tool_id: Uuid,
+    kind: String,
+    created_by: Uuid,
+    transaction_id: Option<Uuid>,
+    starts_at: DateTime<Utc>,
+    ends_at: DateTime<Utc>,
+    note: Option<String>,
+    released_at: Option<DateTime<Utc>>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+}
+
+/// Row -> Domain 转换
+impl TryFrom<ToolReservationRow> for ToolReservation {
+    type Error = AppError;
+
+    fn try_from(row: ToolReservationRow) -> Result<Self> {
+        Ok(ToolReservation {
+            id: ReservationId::from_uuid(row.id),
+            tool_id: ToolId::from_uuid(row.tool_id),
+            kind: row.kind.parse()?,
+            created_by: MemberId::from_uuid(row.created_by),
+            transaction_id: row.transaction_id.map(TransactionId::from_uuid),
+            window: ReservationWindow {
+                starts_at: row.starts_at,
+                ends_at: row.ends_at,
+            },
+            note: row.note,
+            released_at: row.released_at,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[async_trait]
+impl ToolReservationRepository for PostgresToolReservationRepository {
+    #[instrument(name = "save_tool_reservation", skip(self, reservation))]
+    async fn save(&self, reservation: &ToolReservation) -> Result<()> {
+        sqlx::query!(
+            r#"
+            INSERT INTO tool_reservations (id, tool_id, kind, created_by, transaction_id, period, note, released_at, created_at, updated_at)
+            VALUES ($1, $2, $3, $4, $5, tstzrange($6, $7, '[)'), $8, $9, $10, $11)
+            "#,
+            reservation.id.value(),
+            reservation.tool_id.value(),
+            reservation.kind.to_string(),
+            reservation.created_by.value(),
+            reservation.transaction_id.map(|id| id.value()),
+            reservation.window.starts_at,
+            reservation.window.ends_at,
+            reservation.note,
+            reservation.released_at,
+            reservation.created_at,
+            reservation.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| {
+            let is_overlap = e
+                .as_database_error()
+                .and_then(|db| db.code())
+                .is_some_and(|code| code == EXCLUSION_VIOLATION);
+            if is_overlap {
+                AppError::validation("该时间段已被预订或封锁")
+            } else {
+                AppError::internal(format!("保存预订失败: {}", e))
+            }
+        })?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "find_tool_reservation_by_id", skip(self))]
+    async fn find_by_id(&self, id: ReservationId) -> Result<Option<ToolReservation>> {
+        sqlx::query_as::<_, ToolReservationRow>(
+            "SELECT id, tool_id, kind, created_by, transaction_id, lower(period) AS starts_at, upper(period) AS ends_at,
+                    note, released_at, created_at, updated_at
+             FROM tool_reservations
+             WHERE id = $1",
+        )
+        .bind(id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(ToolReservation::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_tool_reservation_by_transaction", skip(self))]
+    async fn find_by_transaction_id(&self, transaction_id: &TransactionId) -> Result<Option<ToolReservation>> {
+        sqlx::query_as::<_, ToolReservationRow>(
+            "SELECT id, tool_id, kind, created_by, transaction_id, lower(period) AS starts_at, upper(period) AS ends_at,
+                    note, released_at, created_at, updated_at
+             FROM tool_reservations
+             WHERE transaction_id = $1",
+        )
+        .bind(transaction_id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(ToolReservation::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_active_overlapping_tool_reservations", skip(self))]
+    async fn find_active_overlapping(&self, tool_id: ToolId, window: &ReservationWindow) -> Result<Vec<ToolReservation>> {
+        sqlx::query_as::<_, ToolReservationRow>(
+            "SELECT id, tool_id, kind, created_by, transaction_id, lower(period) AS starts_at, upper(period) AS ends_at,
+                    note, released_at, created_at, updated_at
+             FROM tool_reservations
+             WHERE tool_id = $1
+               AND released_at IS NULL
+               AND period && tstzrange($2, $3, '[)')
+             ORDER BY lower(period)",
+        )
+        .bind(tool_id.value())
+        .bind(window.starts_at)
+        .bind(window.ends_at)
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(ToolReservation::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "find_active_tool_reservations", skip(self))]
+    async fn find_active_by_tool(&self, tool_id: ToolId) -> Result<Vec<ToolReservation>> {
+        sqlx::query_as::<_, ToolReservationRow>(
+            "SELECT id, tool_id, kind, created_by, transaction_id, lower(period) AS starts_at, upper(period) AS ends_at,
+                    note, released_at, created_at, updated_at
+             FROM tool_reservations
+             WHERE tool_id = $1 AND released_at IS NULL
+             ORDER BY lower(period)",
+        )
+        .bind(tool_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(ToolReservation::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "update_tool_reservation", skip(self, reservation))]
+    async fn update(&self, reservation: &ToolReservation) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE tool_reservations
+            SET note = $2, released_at = $3, updated_at = $4
+            WHERE id = $1
+            "#,
+            reservation.id.value(),
+            reservation.note,
+            reservation.released_at,
+            reservation.updated_at
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("更新预订失败: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("预订不存在"));
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,395 @@
+//! Transaction Repository PostgreSQL 实现
+
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use domain::{
+    isu::ISU,
+    member::MemberId,
+    service::ServiceId,
+    tool::ToolId,
+    transaction::{Transaction, TransactionId, TransactionItemType, TransactionRepository},
+};
+use rust_decimal::Decimal;
+use shared::{AppError, Result};
+use sqlx::{FromRow, PgPool};
+use std::convert::TryFrom;
+use tracing::instrument;
+use uuid::Uuid;
+
+use super::session::PgSession;
+
+/// PostgreSQL Transaction Repository
+pub struct PostgresTransactionRepository {
+    session: PgSession,
+}
+
+impl PostgresTransactionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self::with_session(PgSession::Pool(pool))
+    }
+
+    /// 在指定会话（如工作单元事务）上创建仓储
+    pub fn with_session(session: PgSession) -> Self {
+        Self { session }
+    }
+}
+
+/// 数据库行结构
+#[derive(Debug, Clone, FromRow)]
+struct TransactionRow {
+    id: Uuid,
+    buyer_id: Uuid,
+    seller_id: Uuid,
+    item_type: String,
+    item_id: Uuid,
+    isu_amount: Decimal,
+    escrowed_amount: Decimal,
+    status: String,
+    description: Option<String>,
+    created_at: DateTime<Utc>,
+    updated_at: DateTime<Utc>,
+    confirmed_at: Option<DateTime<Utc>>,
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+    cancelled_by: Option<Uuid>,
+}
+
+/// 交易项目 -> (item_type, item_id) 列
+fn item_columns(item: &TransactionItemType) -> (&'static str, Uuid) {
+    match item {
+        TransactionItemType::Service(service_id) => ("service", service_id.value()),
+        TransactionItemType::Tool(tool_id) => ("tool", tool_id.value()),
+    }
+}
+
+/// Row -> Domain 转换
+impl TryFrom<TransactionRow> for Transaction {
+    type Error = AppError;
+
+    fn try_from(row: TransactionRow) -> Result<Self> {
+        let item_type = match row.item_type.as_str() {
+            "service" => TransactionItemType::Service(ServiceId::from_uuid(row.item_id)),
+            "tool" => TransactionItemType::Tool(ToolId::from_uuid(row.item_id)),
+            other => {
+                return Err(AppError::internal(format!(
+                    "数据库中的交易项目类型无效: {}",
+                    other
+                )))
+            }
+        };
+
+        Ok(Transaction {
+            id: TransactionId::from_uuid(row.id),
+            buyer_id: MemberId::from_uuid(row.buyer_id),
+            seller_id: MemberId::from_uuid(row.seller_id),
+            item_type,
+            isu_amount: ISU::new(row.isu_amount)
+                .map_err(|e| AppError::internal(format!("数据库中的交易金额无效: {}", e)))?,
+            escrowed_amount: ISU::new(row.escrowed_amount)
+                .map_err(|e| AppError::internal(format!("数据库中的托管金额无效: {}", e)))?,
+            status: row.status.parse()?,
+            description: row.description,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+            confirmed_at: row.confirmed_at,
+            started_at: row.started_at,
+            completed_at: row.completed_at,
+            cancelled_by: row.cancelled_by.map(MemberId::from_uuid),
+        })
+    }
+}
+
+#[async_trait]
+impl TransactionRepository for PostgresTransactionRepository {
+    #[instrument(name = "save_transaction", skip(self, transaction))]
+    async fn save(&self, transaction: &Transaction) -> Result<()> {
+        let (item_type, item_id) = item_columns(&transaction.item_type);
+
+        sqlx::query!(
+            r#"
+            INSERT INTO transactions (id, buyer_id, seller_id, item_type, item_id, isu_amount, escrowed_amount, status, description, created_at, updated_at, confirmed_at, started_at, completed_at, cancelled_by)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+            "#,
+            transaction.id.value(),
+            transaction.buyer_id.value(),
+            transaction.seller_id.value(),
+            item_type,
+            item_id,
+            transaction.isu_amount.value(),
+            transaction.escrowed_amount.value(),
+            transaction.status.to_string(),
+            transaction.description,
+            transaction.created_at,
+            transaction.updated_at,
+            transaction.confirmed_at,
+            transaction.started_at,
+            transaction.completed_at,
+            transaction.cancelled_by.map(|id| id.value())
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("保存交易失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "find_transaction_by_id", skip(self))]
+    async fn find_by_id(&self, id: &TransactionId) -> Result<Option<Transaction>> {
+        sqlx::query_as::<_, TransactionRow>(
+            "SELECT id, buyer_id, seller_id, item_type, item_id, isu_amount, escrowed_amount, status, description, created_at, updated_at, confirmed_at, started_at, completed_at, cancelled_by
+             FROM transactions WHERE id = $1",
+        )
+        .bind(id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Transaction::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_transaction_by_id_for_update", skip(self))]
+    async fn find_by_id_for_update(&self, id: &TransactionId) -> Result<Option<Transaction>> {
+        sqlx::query_as::<_, TransactionRow>(
+            "SELECT id, buyer_id, seller_id, item_type, item_id, isu_amount, escrowed_amount, status, description, created_at, updated_at, confirmed_at, started_at, completed_at, cancelled_by
+             FROM transactions WHERE id = $1
+             FOR UPDATE",
+        )
+        .bind(id.value())
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Transaction::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "find_transactions_by_buyer", skip(self))]
+    async fn find_by_buyer_id(&self, buyer_id: &MemberId) -> Result<Vec<Transaction>> {
+        sqlx::query_as::<_, TransactionRow>(
+            "SELECT id, buyer_id, seller_id, item_type, item_id, isu_amount, escrowed_amount, status, description, created_at, updated_at, confirmed_at, started_at, completed_at, cancelled_by
+             FROM transactions WHERE buyer_id = $1
+             ORDER BY created_at DESC",
+        )
+        .bind(buyer_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Transaction::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "find_transactions_by_seller", skip(self))]
+    async fn find_by_seller_id(&self, seller_id: &MemberId) -> Result<Vec<Transaction>> {
+        sqlx::query_as::<_, TransactionRow>(
+            "SELECT id, buyer_id, seller_id, item_type, item_id, isu_amount, escrowed_amount, status, description, created_at, updated_at, confirmed_at, started_at, completed_at, cancelled_by
+             FROM transactions WHERE seller_id = $1
+             ORDER BY created_at DESC",
+        )
+        .bind(seller_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Transaction::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "find_transactions_by_participant", skip(self))]
+    async fn find_by_participant(&self, member_id: &MemberId) -> Result<Vec<Transaction>> {
+        sqlx::query_as::<_, TransactionRow>(
+            "SELECT id, buyer_id, seller_id, item_type, item_id, isu_amount, escrowed_amount, status, description, created_at, updated_at, confirmed_at, started_at, completed_at, cancelled_by
+             FROM transactions WHERE buyer_id = $1 OR seller_id = $1
+             ORDER BY created_at DESC",
+        )
+        .bind(member_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Transaction::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "find_pending_transactions_by_seller", skip(self))]
+    async fn find_pending_by_seller(&self, seller_id: &MemberId) -> Result<Vec<Transaction>> {
+        sqlx::query_as::<_, TransactionRow>(
+            "SELECT id, buyer_id, seller_id, item_type, item_id, isu_amount, escrowed_amount, status, description, created_at, updated_at, confirmed_at, started_at, completed_at, cancelled_by
+             FROM transactions WHERE seller_id = $1 AND status = 'pending'
+             ORDER BY created_at ASC",
+        )
+        .bind(seller_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Transaction::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "find_in_progress_transactions_by_participant", skip(self))]
+    async fn find_in_progress_by_participant(&self, member_id: &MemberId) -> Result<Vec<Transaction>> {
+        sqlx::query_as::<_, TransactionRow>(
+            "SELECT id, buyer_id, seller_id, item_type, item_id, isu_amount, escrowed_amount, status, description, created_at, updated_at, confirmed_at, started_at, completed_at, cancelled_by
+             FROM transactions
+             WHERE (buyer_id = $1 OR seller_id = $1) AND status = 'in_progress'
+             ORDER BY updated_at DESC",
+        )
+        .bind(member_id.value())
+        .fetch_all(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .into_iter()
+        .map(Transaction::try_from)
+        .collect()
+    }
+
+    #[instrument(name = "claim_pending_transaction", skip(self, exclude))]
+    async fn claim_pending_created_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        exclude: &[TransactionId],
+    ) -> Result<Option<Transaction>> {
+        let exclude: Vec<Uuid> = exclude.iter().map(|id| id.value()).collect();
+
+        sqlx::query_as::<_, TransactionRow>(
+            "SELECT id, buyer_id, seller_id, item_type, item_id, isu_amount, escrowed_amount, status, description, created_at, updated_at, confirmed_at, started_at, completed_at, cancelled_by
+             FROM transactions
+             WHERE status = 'pending' AND created_at < $1 AND NOT (id = ANY($2))
+             ORDER BY created_at ASC
+             LIMIT 1
+             FOR UPDATE SKIP LOCKED",
+        )
+        .bind(cutoff)
+        .bind(&exclude)
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Transaction::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "claim_in_progress_service_transaction", skip(self, exclude))]
+    async fn claim_in_progress_services_started_before(
+        &self,
+        cutoff: DateTime<Utc>,
+        exclude: &[TransactionId],
+    ) -> Result<Option<Transaction>> {
+        let exclude: Vec<Uuid> = exclude.iter().map(|id| id.value()).collect();
+
+        sqlx::query_as::<_, TransactionRow>(
+            "SELECT id, buyer_id, seller_id, item_type, item_id, isu_amount, escrowed_amount, status, description, created_at, updated_at, confirmed_at, started_at, completed_at, cancelled_by
+             FROM transactions
+             WHERE status = 'in_progress' AND item_type = 'service' AND started_at < $1
+               AND NOT (id = ANY($2))
+             ORDER BY started_at ASC
+             LIMIT 1
+             FOR UPDATE SKIP LOCKED",
+        )
+        .bind(cutoff)
+        .bind(&exclude)
+        .fetch_optional(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("查询失败: {}", e)))?
+        .map(Transaction::try_from)
+        .transpose()
+    }
+
+    #[instrument(name = "update_transaction", skip(self, transaction))]
+    async fn update(&self, transaction: &Transaction) -> Result<()> {
+        let result = sqlx::query!(
+            r#"
+            UPDATE transactions
+            SET status = $2, description = $3, isu_amount = $4, escrowed_amount = $5, updated_at = $6, confirmed_at = $7, started_at = $8, completed_at = $9, cancelled_by = $10
+            WHERE id = $1
+            "#,
+            transaction.id.value(),
+            transaction.status.to_string(),
+            transaction.description,
+            transaction.isu_amount.value(),
+            transaction.escrowed_amount.value(),
+            transaction.updated_at,
+            transaction.confirmed_at,
+            transaction.started_at,
+            transaction.completed_at,
+            transaction.cancelled_by.map(|id| id.value())
+        )
+        .execute(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("更新交易失败: {}", e)))?;
+
+        if result.rows_affected() == 0 {
+            return Err(AppError::not_found("交易不存在"));
+        }
+
+        Ok(())
+    }
+
+    #[instrument(name = "delete_transaction", skip(self))]
+    async fn delete(&self, id: &TransactionId) -> Result<()> {
+        sqlx::query!("DELETE FROM transactions WHERE id = $1", id.value())
+            .execute(&mut *self.session.acquire().await?)
+            .await
+            .map_err(|e| AppError::internal(format!("删除失败: {}", e)))?;
+
+        Ok(())
+    }
+
+    #[instrument(name = "count_transactions_by_participant", skip(self))]
+    async fn count_by_participant(&self, member_id: &MemberId) -> Result<u64> {
+        let result = sqlx::query!(
+            "SELECT COUNT(*) as count FROM transactions WHERE buyer_id = $1 OR seller_id = $1",
+            member_id.value()
+        )
+        .fetch_one(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("统计失败: {}", e)))?;
+
+        Ok(result.count.unwrap_or(0) as u64)
+    }
+
+    #[instrument(name = "count_cancellations_by_member", skip(self))]
+    async fn count_confirmed_cancelled_by(&self, member_id: &MemberId) -> Result<u64> {
+        let result = sqlx::query!(
+            "SELECT COUNT(*) as count FROM transactions
+             WHERE cancelled_by = $1 AND confirmed_at IS NOT NULL",
+            member_id.value()
+        )
+        .fetch_one(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("统计失败: {}", e)))?;
+
+        Ok(result.count.unwrap_or(0) as u64)
+    }
+
+    #[instrument(name = "count_open_transactions_by_item", skip(self))]
+    async fn count_open_by_item(&self, item: &TransactionItemType) -> Result<u64> {
+        let (item_type, item_id) = item_columns(item);
+
+        let result = sqlx::query!(
+            "SELECT COUNT(*) as count FROM transactions
+             WHERE item_type = $1 AND item_id = $2
+               AND status IN ('pending', 'confirmed', 'in_progress', 'disputed')",
+            item_type,
+            item_id
+        )
+        .fetch_one(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("统计失败: {}", e)))?;
+
+        Ok(result.count.unwrap_or(0) as u64)
+    }
+
+    #[instrument(name = "count_completed_transactions_by_participant", skip(self))]
+    async fn count_completed_by_participant(&self, member_id: &MemberId) -> Result<u64> {
+        let result = sqlx::query!(
+            "SELECT COUNT(*) as count FROM transactions
+             WHERE (buyer_id = $1 OR seller_id = $1) AND status = 'completed'",
+            member_id.value()
+        )
+        .fetch_one(&mut *self.session.acquire().await?)
+        .await
+        .map_err(|e| AppError::internal(format!("统计失败: {}", e)))?;
+
+        Ok(result.count.unwrap_or(0) as u64)
+    }
+}
@@ -0,0 +1,177 @@
+//! Unit of Work PostgreSQL 实现
+
+use async_trait::async_trait;
+use domain::{
+    audit::AuditEventRepository,
+    auth::RefreshTokenRepository,
+    dispute::DisputeRepository,
+    isu::ISUAccountRepository,
+    member::MemberRepository,
+    profession::{ProfessionCatalogRepository, ProfessionStandardRepository},
+    review::ReviewRepository,
+    service::ServiceRepository,
+    tool::{ToolRentalRepository, ToolRepository, ToolReservationRepository},
+    transaction::TransactionRepository,
+    unit_of_work::{UnitOfWork, UnitOfWorkFactory},
+};
+use shared::{AppError, Result};
+use sqlx::PgPool;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tracing::instrument;
+
+use super::session::{PgSession, SharedTransaction};
+use super::{
+    PostgresAuditEventRepository, PostgresDisputeRepository, PostgresISUAccountRepository, PostgresMemberRepository,
+    PostgresProfessionCatalogRepository, PostgresProfessionStandardRepository, PostgresRefreshTokenRepository, PostgresReviewRepository,
+    PostgresServiceRepository, PostgresToolRentalRepository, PostgresToolRepository,
+    PostgresToolReservationRepository, PostgresTransactionRepository,
+};
+
+/// PostgreSQL 工作单元工厂
+pub struct PostgresUnitOfWorkFactory {
+    pool: PgPool,
+}
+
+impl PostgresUnitOfWorkFactory {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+}
+
+#[async_trait]
+impl UnitOfWorkFactory for PostgresUnitOfWorkFactory {
+    #[instrument(name = "begin_unit_of_work", skip(self))]
+    async fn begin(&self) -> Result<Box<dyn UnitOfWork>> {
+        let tx = self
+            .pool
+            .begin()
+            .await
+            .map_err(|e| AppError::internal(format!("开启事务失败: {}", e)))?;
+
+        Ok(Box::new(PostgresUnitOfWork::new(Arc::new(Mutex::new(Some(tx))))))
+    }
+}
+
+/// PostgreSQL 工作单元，所有仓储共享同一个事务
+pub struct PostgresUnitOfWork {
+    tx: SharedTransaction,
+    members: PostgresMemberRepository,
+    isu_accounts: PostgresISUAccountRepository,
+    professions: PostgresProfessionStandardRepository,
+    profession_catalog: PostgresProfessionCatalogRepository,
+    services: PostgresServiceRepository,
+    tools: PostgresToolRepository,
+    tool_rentals: PostgresToolRentalRepository,
+    tool_reservations: PostgresToolReservationRepository,
+    transactions: PostgresTransactionRepository,
+    disputes: PostgresDisputeRepository,
+    audit_events: PostgresAuditEventRepository,
+    refresh_tokens: PostgresRefreshTokenRepository,
+    reviews: PostgresReviewRepository,
+}
+
+impl PostgresUnitOfWork {
+    fn new(tx: SharedTransaction) -> Self {
+        let session = PgSession::Transaction(tx.clone());
+        Self {
+            tx,
+            members: PostgresMemberRepository::with_session(session.clone()),
+            isu_accounts: PostgresISUAccountRepository::with_session(session.clone()),
+            professions: PostgresProfessionStandardRepository::with_session(session.clone()),
+            profession_catalog: PostgresProfessionCatalogRepository::with_session(session.clone()),
+            services: PostgresServiceRepository::with_session(session.clone()),
+            tools: PostgresToolRepository::with_session(session.clone()),
+            tool_rentals: PostgresToolRentalRepository::with_session(session.clone()),
+            tool_reservations: PostgresToolReservationRepository::with_session(session.clone()),
+            transactions: PostgresTransactionRepository::with_session(session.clone()),
+            disputes: PostgresDisputeRepository::with_session(session.clone()),
+            audit_events: PostgresAuditEventRepository::with_session(session.clone()),
+            refresh_tokens: PostgresRefreshTokenRepository::with_session(session.clone()),
+            reviews: PostgresReviewRepository::with_session(session),
+        }
+    }
+}
+
+#[async_trait]
+impl UnitOfWork for PostgresUnitOfWork {
+    fn members(&self) -> &dyn MemberRepository {
+        &self.members
+    }
+
+    fn isu_accounts(&self) -> &dyn ISUAccountRepository {
+        &self.isu_accounts
+    }
+
+    fn professions(&self) -> &dyn ProfessionStandardRepository {
+        &self.professions
+    }
+
+    fn profession_catalog(&self) -> &dyn ProfessionCatalogRepository {
+        &self.profession_catalog
+    }
+
+    fn services(&self) -> &dyn ServiceRepository {
+        &self.services
+    }
+
+    fn tools(&self) -> &dyn ToolRepository {
+        &self.tools
+    }
+
+    fn tool_rentals(&self) -> &dyn ToolRentalRepository {
+        &self.tool_rentals
+    }
+
+    fn tool_reservations(&self) -> &dyn ToolReservationRepository {
+        &self.tool_reservations
+    }
+
+    fn transactions(&self) -> &dyn TransactionRepository {
+        &self.transactions
+    }
+
+    fn disputes(&self) -> &dyn DisputeRepository {
+        &self.disputes
+    }
+
+    fn audit_events(&self) -> &dyn AuditEventRepository {
+        &self.audit_events
+    }
+
+    fn refresh_tokens(&self) -> &dyn RefreshTokenRepository {
+        &self.refresh_tokens
+    }
+
+    fn reviews(&self) -> &dyn ReviewRepository {
+        &self.reviews
+    }
+
+    #[instrument(name = "commit_unit_of_work", skip(self))]
+    async fn commit(self: Box<Self>) -> Result<()> {
+        let tx = self
+            .tx
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| AppError::internal("工作单元已提交或回滚"))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| AppError::internal(format!("提交事务失败: {}", e)))
+    }
+
+    #[instrument(name = "rollback_unit_of_work", skip(self))]
+    async fn rollback(self: Box<Self>) -> Result<()> {
+        let tx = self
+            .tx
+            .lock()
+            .await
+            .take()
+            .ok_or_else(|| AppError::internal("工作单元已提交或回滚"))?;
+
+        tx.rollback()
+            .await
+            .map_err(|e| AppError::internal(format!("回滚事务失败: {}", e)))
+    }
+}
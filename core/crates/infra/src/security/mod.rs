@@ -1,5 +1,7 @@
 //! 安全相关服务
 
 mod password_hasher;
+mod refresh_token;
 
 pub use password_hasher::{Argon2PasswordHasher, PasswordHasher};
+pub use refresh_token::{generate_refresh_token, hash_refresh_token};
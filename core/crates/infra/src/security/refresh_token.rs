@@ -0,0 +1,50 @@
+//! 刷新令牌生成与哈希
+
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use sha2::{Digest, Sha256};
+
+/// 刷新令牌的随机字节数
+const REFRESH_TOKEN_BYTES: usize = 32;
+
+/// 生成刷新令牌明文（32 字节随机数的十六进制表示）
+pub fn generate_refresh_token() -> String {
+    let mut bytes = [0u8; REFRESH_TOKEN_BYTES];
+    OsRng.fill_bytes(&mut bytes);
+    to_hex(&bytes)
+}
+
+/// 计算刷新令牌的存储哈希（SHA-256；令牌本身是高熵随机数，无需加盐慢哈希）
+pub fn hash_refresh_token(token: &str) -> String {
+    to_hex(&Sha256::digest(token.as_bytes()))
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn generated_tokens_are_random_hex() {
+        let token = generate_refresh_token();
+        assert_eq!(token.len(), REFRESH_TOKEN_BYTES * 2);
+        assert!(token.bytes().all(|b| b.is_ascii_hexdigit()));
+        assert_ne!(token, generate_refresh_token());
+    }
+
+    #[test]
+    fn hash_is_deterministic_and_hides_the_token() {
+        let token = generate_refresh_token();
+        let hash = hash_refresh_token(&token);
+
+        assert_eq!(hash, hash_refresh_token(&token));
+        assert_ne!(hash, token);
+        assert_ne!(hash, hash_refresh_token(&generate_refresh_token()));
+        assert_eq!(
+            hash_refresh_token("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+}
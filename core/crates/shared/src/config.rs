@@ -1,8 +1,9 @@
 //! 配置管理模块
 
 use config::{Config, ConfigError, Environment, File};
+use rust_decimal::Decimal;
 use serde::Deserialize;
-use std::env;
+use std::{env, net::IpAddr};
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct AppConfig {
@@ -11,12 +12,23 @@ pub struct AppConfig {
     pub jwt: JwtConfig,
     pub log: LogConfig,
     pub pagination: PaginationConfig,
+    pub isu: IsuConfig,
+    pub cancellation: CancellationConfig,
+    pub jobs: JobsConfig,
+    /// 运行环境（RUN_ENV，默认 local）
+    #[serde(skip)]
+    pub run_env: String,
 }
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct ServerConfig {
     pub host: String,
     pub port: u16,
+    /// 受信任的反向代理地址，只有来自这些地址的请求才采信 X-Forwarded-For
+    #[serde(default)]
+    pub trusted_proxies: Vec<IpAddr>,
+    /// 会话 Cookie 是否带 Secure 属性（只通过 HTTPS 发送）；本地纯 HTTP 开发时关闭
+    pub secure_cookies: bool,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -30,8 +42,26 @@ pub struct DatabaseConfig {
 
 #[derive(Debug, Clone, Deserialize)]
 pub struct JwtConfig {
-    pub secret: String,
+    /// 访问令牌有效期（秒）
     pub expires_in: i64,
+    /// 刷新令牌有效期（秒）
+    pub refresh_expires_in: i64,
+    /// 当前用于签发令牌的密钥 kid（必须在 keys 中配置且带私钥）
+    pub signing_kid: String,
+    /// 验证密钥；轮换期间新旧密钥同时配置，旧密钥保留到其签发的令牌全部过期
+    pub keys: Vec<JwtKeyConfig>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JwtKeyConfig {
+    pub kid: String,
+    /// 签名算法：RS256 或 EdDSA
+    pub algorithm: String,
+    /// 公钥 PEM 文件路径
+    pub public_key_path: String,
+    /// 私钥 PEM 文件路径（只有签名密钥需要）
+    #[serde(default)]
+    pub private_key_path: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -46,6 +76,38 @@ pub struct PaginationConfig {
     pub max_page_size: i64,
 }
 
+#[derive(Debug, Clone, Deserialize)]
+pub struct IsuConfig {
+    /// 新会员注册时从平台金库发放的初始余额
+    pub initial_balance: Decimal,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct CancellationConfig {
+    /// 卖家确认后买家可免费取消的宽限期（分钟）
+    pub grace_period_minutes: i64,
+    /// 买家取消时从冻结款项中收取的取消费比例
+    pub buyer_fee_rate: Decimal,
+    /// 临近开始时间取消时的取消费比例
+    pub late_fee_rate: Decimal,
+    /// 距约定开始时间不足该时长（小时）视为临近开始
+    pub late_window_hours: i64,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct JobsConfig {
+    /// 是否在 api 进程内运行交易后台任务
+    pub enabled: bool,
+    /// 任务运行间隔（秒）
+    pub interval_seconds: u64,
+    /// 待确认交易超过该时长（小时）未确认即过期
+    pub pending_ttl_hours: i64,
+    /// 进行中的服务交易开始后超过该时长（小时）未完成也未争议即自动完成
+    pub auto_complete_after_hours: i64,
+    /// 每个任务单次运行最多处理的交易数
+    pub batch_size: usize,
+}
+
 impl AppConfig {
     /// 加载配置
     pub fn load() -> Result<Self, ConfigError> {
@@ -58,6 +120,13 @@ impl AppConfig {
             .add_source(Environment::with_prefix("APP").separator("__"))
             .build()?;
 
-        config.try_deserialize()
+        let mut app_config: Self = config.try_deserialize()?;
+        app_config.run_env = run_env;
+        Ok(app_config)
+    }
+
+    /// 是否为生产环境
+    pub fn is_production(&self) -> bool {
+        self.run_env == "production"
     }
 }
@@ -27,8 +27,11 @@ pub enum AppError {
     #[error("未授权")]
     Unauthorized,
 
-    #[error("权限不足")]
-    Forbidden,
+    #[error("权限不足: {0}")]
+    Forbidden(String),
+
+    #[error("冲突: {0}")]
+    Conflict(String),
 }
 
 impl AppError {
@@ -45,8 +48,11 @@ impl AppError {
     }
 
     pub fn forbidden(msg: impl Into<String>) -> Self {
-        let _ = msg.into(); // 接受消息但使用默认的
-        Self::Forbidden
+        Self::Forbidden(msg.into())
+    }
+
+    pub fn conflict(msg: impl Into<String>) -> Self {
+        Self::Conflict(msg.into())
     }
 }
 
@@ -64,7 +70,8 @@ impl IntoResponse for AppError {
             AppError::Validation(msg) => (StatusCode::BAD_REQUEST, msg),
             AppError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
             AppError::Unauthorized => (StatusCode::UNAUTHORIZED, "未授权".to_string()),
-            AppError::Forbidden => (StatusCode::FORBIDDEN, "权限不足".to_string()),
+            AppError::Forbidden(msg) => (StatusCode::FORBIDDEN, msg),
+            AppError::Conflict(msg) => (StatusCode::CONFLICT, msg),
             AppError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
             AppError::Config(e) => (
                 StatusCode::INTERNAL_SERVER_ERROR,
@@ -0,0 +1,16 @@
+-- 为 services 表添加全文检索支持
+-- 使用 simple 词典（不做词干化，适配中英文混合内容），标题权重高于描述
+
+-- 1. 添加生成列 search_vector
+ALTER TABLE services ADD COLUMN IF NOT EXISTS search_vector TSVECTOR
+    GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', coalesce(title, '')), 'A') ||
+        setweight(to_tsvector('simple', coalesce(description, '')), 'B')
+    ) STORED;
+
+-- 2. 创建 GIN 索引
+CREATE INDEX IF NOT EXISTS idx_services_search_vector ON services USING GIN (search_vector);
+
+-- 3. 可用服务按职业分页查询的复合索引
+CREATE INDEX IF NOT EXISTS idx_services_status_profession_created
+    ON services(status, profession_type, created_at DESC);
@@ -0,0 +1,30 @@
+-- 平台金库账户
+-- 管理员调整等 ISU 流转需要一个对手方账户（isu_transactions 要求 from/to 均存在且不同），
+-- 因此创建一个系统会员及其 ISU 账户，以创世供应量作为初始余额
+
+-- 1. 系统会员（不可登录：状态为 inactive，密码哈希无效）
+INSERT INTO members (id, email, username, password_hash, status, role)
+VALUES (
+    '00000000-0000-0000-0000-000000000001',
+    'treasury@system.internal',
+    'system_treasury',
+    '!',
+    'inactive',
+    'regular'
+)
+ON CONFLICT (id) DO NOTHING;
+
+-- 2. 金库账户（ID 与 domain::isu::TREASURY_ACCOUNT_UUID 保持一致）
+INSERT INTO isu_accounts (id, owner_id, balance)
+VALUES (
+    '00000000-0000-0000-0000-000000000001',
+    '00000000-0000-0000-0000-000000000001',
+    1000000000.00
+)
+ON CONFLICT (id) DO NOTHING;
+
+-- 3. 流水分页按 (created_at, id) 游标倒序扫描
+CREATE INDEX IF NOT EXISTS idx_isu_transactions_from_created
+    ON isu_transactions(from_account_id, created_at DESC, id DESC);
+CREATE INDEX IF NOT EXISTS idx_isu_transactions_to_created
+    ON isu_transactions(to_account_id, created_at DESC, id DESC);
@@ -0,0 +1,20 @@
+-- ISU 流通量跟踪
+-- 记录平台金库的创世供应量，用于校验：
+--   1. 所有会员账户余额之和 = 金库净流出（流出 - 流入）
+--   2. 金库余额 + 会员账户余额之和 = 创世供应量
+
+CREATE TABLE IF NOT EXISTS isu_supply (
+    id BOOLEAN PRIMARY KEY DEFAULT TRUE,
+    genesis_supply DECIMAL(20, 2) NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_isu_supply_singleton CHECK (id),
+    CONSTRAINT chk_genesis_supply_non_negative CHECK (genesis_supply >= 0)
+);
+
+-- 与 20250104000000_isu_treasury.sql 中金库的初始余额一致
+INSERT INTO isu_supply (genesis_supply)
+VALUES (1000000000.00)
+ON CONFLICT (id) DO NOTHING;
+
+COMMENT ON TABLE isu_supply IS 'ISU供应量表 - 记录平台金库的创世供应量';
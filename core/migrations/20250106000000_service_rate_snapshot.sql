@@ -0,0 +1,14 @@
+-- 服务费率快照
+-- 服务发布时记录所用的职业费率，费率调整不会重新计价已发布的服务
+
+ALTER TABLE services ADD COLUMN IF NOT EXISTS isu_rate DECIMAL(10, 2);
+
+-- 已有服务按总价和工时反推费率
+UPDATE services
+SET isu_rate = ROUND(total_isu / estimated_hours, 2)
+WHERE isu_rate IS NULL;
+
+ALTER TABLE services ALTER COLUMN isu_rate SET NOT NULL;
+ALTER TABLE services ADD CONSTRAINT chk_service_isu_rate_positive CHECK (isu_rate > 0);
+
+COMMENT ON COLUMN services.isu_rate IS '发布时使用的ISU费率快照（ISU/小时）';
@@ -0,0 +1,99 @@
+-- 职业目录
+-- 职业及其分类改为数据维护：默认费率、显示名称（含多语言）和所属分类都存放在数据库中，
+-- 服务和职业标准通过外键引用职业编码
+
+-- ============================================
+-- 1. 职业分类表 (profession_categories)
+-- ============================================
+CREATE TABLE IF NOT EXISTS profession_categories (
+    code VARCHAR(50) PRIMARY KEY,
+    display_name VARCHAR(100) NOT NULL,
+    localized_names JSONB NOT NULL DEFAULT '{}'::jsonb,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_profession_category_code CHECK (code ~ '^[a-z][a-z0-9_]*$'),
+    CONSTRAINT chk_profession_category_localized_names CHECK (jsonb_typeof(localized_names) = 'object')
+);
+
+-- ============================================
+-- 2. 职业表 (professions)
+-- ============================================
+CREATE TABLE IF NOT EXISTS professions (
+    code VARCHAR(50) PRIMARY KEY,
+    display_name VARCHAR(100) NOT NULL,
+    localized_names JSONB NOT NULL DEFAULT '{}'::jsonb,
+    category_code VARCHAR(50) NOT NULL REFERENCES profession_categories(code),
+    default_rate DECIMAL(10, 2) NOT NULL,
+    is_active BOOLEAN NOT NULL DEFAULT true,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_profession_code CHECK (code ~ '^[a-z][a-z0-9_]*$'),
+    CONSTRAINT chk_profession_default_rate_positive CHECK (default_rate > 0),
+    CONSTRAINT chk_profession_localized_names CHECK (jsonb_typeof(localized_names) = 'object')
+);
+
+CREATE INDEX idx_professions_category_code ON professions(category_code);
+CREATE INDEX idx_professions_active ON professions(is_active);
+
+CREATE TRIGGER update_profession_categories_updated_at BEFORE UPDATE ON profession_categories
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+CREATE TRIGGER update_professions_updated_at BEFORE UPDATE ON professions
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+-- ============================================
+-- 3. 初始目录
+-- ============================================
+INSERT INTO profession_categories (code, display_name, localized_names) VALUES
+    ('household', '家政服务', '{"en": "Household"}'),
+    ('repair', '维修安装', '{"en": "Repair & Installation"}'),
+    ('education', '教育培训', '{"en": "Education"}'),
+    ('professional', '专业服务', '{"en": "Professional Services"}'),
+    ('other', '其他', '{"en": "Other"}')
+ON CONFLICT (code) DO NOTHING;
+
+-- 原硬编码的五种职业沿用原默认费率；初始化脚本中的职业沿用其标准费率
+INSERT INTO professions (code, display_name, localized_names, category_code, default_rate) VALUES
+    ('cleaning', '清洁服务', '{"en": "Cleaning"}', 'household', 1.00),
+    ('basic_repair', '基础维修', '{"en": "Basic Repair"}', 'repair', 1.50),
+    ('home_tutoring', '家庭教学', '{"en": "Home Tutoring"}', 'education', 2.00),
+    ('documentation', '文档/翻译', '{"en": "Documentation & Translation"}', 'professional', 1.80),
+    ('cooking', '烹饪服务', '{"en": "Cooking"}', 'household', 1.20),
+    ('plumber', '管道工', '{"en": "Plumber"}', 'repair', 50.00),
+    ('electrician', '电工', '{"en": "Electrician"}', 'repair', 60.00),
+    ('carpenter', '木工', '{"en": "Carpenter"}', 'repair', 55.00),
+    ('painter', '油漆工', '{"en": "Painter"}', 'repair', 45.00),
+    ('cleaner', '清洁工', '{"en": "Cleaner"}', 'household', 30.00),
+    ('gardener', '园丁', '{"en": "Gardener"}', 'household', 40.00),
+    ('mechanic', '机械师', '{"en": "Mechanic"}', 'repair', 65.00),
+    ('teacher', '教师', '{"en": "Teacher"}', 'education', 70.00),
+    ('programmer', '程序员', '{"en": "Programmer"}', 'professional', 100.00),
+    ('designer', '设计师', '{"en": "Designer"}', 'professional', 80.00)
+ON CONFLICT (code) DO NOTHING;
+
+-- 已有数据中出现但不在初始目录里的职业，归入“其他”分类，显示名称暂用编码
+INSERT INTO professions (code, display_name, category_code, default_rate)
+SELECT DISTINCT ON (code) code, code, 'other', rate
+FROM (
+    SELECT profession_type AS code, isu_rate AS rate FROM profession_standards
+    UNION ALL
+    SELECT profession_type AS code, isu_rate AS rate FROM services
+) AS existing
+ORDER BY code, rate DESC
+ON CONFLICT (code) DO NOTHING;
+
+-- ============================================
+-- 4. 引用职业目录
+-- ============================================
+ALTER TABLE services
+    ADD CONSTRAINT fk_services_profession FOREIGN KEY (profession_type) REFERENCES professions(code);
+
+ALTER TABLE profession_standards
+    ADD CONSTRAINT fk_profession_standards_profession FOREIGN KEY (profession_type) REFERENCES professions(code);
+
+COMMENT ON TABLE profession_categories IS '职业分类表 - 职业目录的上级分类';
+COMMENT ON TABLE professions IS '职业表 - 可在运行时维护的职业目录';
+COMMENT ON COLUMN professions.localized_names IS '多语言显示名称，如 {"en": "Cleaning"}';
+COMMENT ON COLUMN professions.default_rate IS '没有启用的职业标准时使用的默认ISU费率（ISU/小时）';
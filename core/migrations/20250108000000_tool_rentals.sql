@@ -0,0 +1,81 @@
+-- 工具租用
+-- 工具按日计租，可设置可退还押金；押金在租用开始时转入平台托管账户，归还后退还
+
+-- ============================================
+-- 1. 工具租用条款
+-- ============================================
+ALTER TABLE tools ADD COLUMN IF NOT EXISTS daily_rate DECIMAL(20, 2);
+ALTER TABLE tools ADD COLUMN IF NOT EXISTS deposit DECIMAL(20, 2) NOT NULL DEFAULT 0.00;
+
+ALTER TABLE tools ADD CONSTRAINT chk_tool_daily_rate_positive CHECK (daily_rate IS NULL OR daily_rate > 0);
+ALTER TABLE tools ADD CONSTRAINT chk_tool_deposit_non_negative CHECK (deposit >= 0);
+
+COMMENT ON COLUMN tools.daily_rate IS '每日租金（ISU），为空表示不可租用';
+COMMENT ON COLUMN tools.deposit IS '可退还押金（ISU），0 表示无需押金';
+
+-- ============================================
+-- 2. 平台托管账户（ID 与 domain::isu::ESCROW_ACCOUNT_UUID 保持一致）
+-- ============================================
+INSERT INTO members (id, email, username, password_hash, status, role)
+VALUES (
+    '00000000-0000-0000-0000-000000000002',
+    'escrow@system.internal',
+    'system_escrow',
+    '!',
+    'inactive',
+    'regular'
+)
+ON CONFLICT (id) DO NOTHING;
+
+INSERT INTO isu_accounts (id, owner_id, balance)
+VALUES (
+    '00000000-0000-0000-0000-000000000002',
+    '00000000-0000-0000-0000-000000000002',
+    0.00
+)
+ON CONFLICT (id) DO NOTHING;
+
+-- ============================================
+-- 3. 押金流水类型
+-- ============================================
+ALTER TABLE isu_transactions DROP CONSTRAINT IF EXISTS chk_transaction_type;
+ALTER TABLE isu_transactions ADD CONSTRAINT chk_transaction_type CHECK (
+    transaction_type IN (
+        'service_payment', 'tool_rental', 'initial_balance', 'admin_adjustment',
+        'deposit_hold', 'deposit_refund'
+    )
+);
+
+-- ============================================
+-- 4. 工具租用记录表 (tool_rentals)
+-- ============================================
+CREATE TABLE IF NOT EXISTS tool_rentals (
+    transaction_id UUID PRIMARY KEY REFERENCES transactions(id) ON DELETE CASCADE,
+    tool_id UUID NOT NULL REFERENCES tools(id) ON DELETE CASCADE,
+    renter_id UUID NOT NULL REFERENCES members(id) ON DELETE CASCADE,
+    start_date DATE NOT NULL,
+    end_date DATE NOT NULL,
+    daily_rate DECIMAL(20, 2) NOT NULL,
+    rental_fee DECIMAL(20, 2) NOT NULL,
+    deposit DECIMAL(20, 2) NOT NULL DEFAULT 0.00,
+    deposit_status VARCHAR(20) NOT NULL,
+    returned_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_rental_period CHECK (end_date >= start_date),
+    CONSTRAINT chk_rental_daily_rate_positive CHECK (daily_rate > 0),
+    CONSTRAINT chk_rental_fee_positive CHECK (rental_fee > 0),
+    CONSTRAINT chk_rental_deposit_non_negative CHECK (deposit >= 0),
+    CONSTRAINT chk_rental_deposit_status CHECK (
+        deposit_status IN ('not_required', 'pending', 'held', 'refunded')
+    )
+);
+
+CREATE INDEX idx_tool_rentals_tool_period ON tool_rentals(tool_id, start_date, end_date);
+CREATE INDEX idx_tool_rentals_renter_id ON tool_rentals(renter_id);
+
+CREATE TRIGGER update_tool_rentals_updated_at BEFORE UPDATE ON tool_rentals
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+COMMENT ON TABLE tool_rentals IS '工具租用记录表 - 工具类交易的租用期、租金和押金';
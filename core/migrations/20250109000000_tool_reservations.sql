@@ -0,0 +1,66 @@
+-- 工具预订（可用性日历）
+-- 所有者封锁日期和租用者的租用预订共用一张表，同一工具未释放的预订时间段由排他约束保证互不重叠
+
+CREATE EXTENSION IF NOT EXISTS btree_gist;
+
+-- ============================================
+-- 1. 工具预订表 (tool_reservations)
+-- ============================================
+CREATE TABLE IF NOT EXISTS tool_reservations (
+    id UUID PRIMARY KEY,
+    tool_id UUID NOT NULL REFERENCES tools(id) ON DELETE CASCADE,
+    kind VARCHAR(20) NOT NULL,
+    created_by UUID NOT NULL REFERENCES members(id) ON DELETE CASCADE,
+    transaction_id UUID UNIQUE REFERENCES transactions(id) ON DELETE CASCADE,
+    period TSTZRANGE NOT NULL,
+    note TEXT,
+    released_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_reservation_kind CHECK (kind IN ('block', 'rental')),
+    CONSTRAINT chk_reservation_transaction CHECK (
+        (kind = 'rental' AND transaction_id IS NOT NULL)
+        OR (kind = 'block' AND transaction_id IS NULL)
+    ),
+    CONSTRAINT chk_reservation_period CHECK (
+        NOT isempty(period) AND lower_inc(period) AND NOT upper_inc(period)
+        AND NOT lower_inf(period) AND NOT upper_inf(period)
+    ),
+    CONSTRAINT excl_tool_reservation_overlap EXCLUDE USING gist (
+        tool_id WITH =,
+        period WITH &&
+    ) WHERE (released_at IS NULL)
+);
+
+CREATE INDEX idx_tool_reservations_tool_id ON tool_reservations(tool_id);
+
+CREATE TRIGGER update_tool_reservations_updated_at BEFORE UPDATE ON tool_reservations
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+COMMENT ON TABLE tool_reservations IS '工具预订表 - 所有者封锁的日期和租用者的租用预订';
+COMMENT ON COLUMN tool_reservations.period IS '占用时间段，左闭右开';
+COMMENT ON COLUMN tool_reservations.released_at IS '释放时间（取消封锁、租用取消或归还），释放后不再占用时间';
+
+-- ============================================
+-- 2. 回填仍然有效的租用
+-- ============================================
+INSERT INTO tool_reservations (id, tool_id, kind, created_by, transaction_id, period, created_at, updated_at)
+SELECT
+    r.transaction_id,
+    r.tool_id,
+    'rental',
+    r.renter_id,
+    r.transaction_id,
+    tstzrange(r.start_date::timestamp AT TIME ZONE 'UTC', (r.end_date + 1)::timestamp AT TIME ZONE 'UTC', '[)'),
+    r.created_at,
+    r.updated_at
+FROM tool_rentals r
+JOIN transactions t ON t.id = r.transaction_id
+WHERE r.returned_at IS NULL
+  AND t.status IN ('pending', 'confirmed', 'in_progress', 'disputed')
+ON CONFLICT DO NOTHING;
+
+-- 租用记录的重叠检查改由预订表承担
+DROP INDEX IF EXISTS idx_tool_rentals_tool_period;
+CREATE INDEX IF NOT EXISTS idx_tool_rentals_tool_id ON tool_rentals(tool_id);
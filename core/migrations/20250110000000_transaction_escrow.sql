@@ -0,0 +1,63 @@
+-- 交易托管结算
+-- 卖家确认时买家的 ISU 转入平台托管账户并计入买家的托管中余额，交易完成后放款给卖家，
+-- 取消或争议裁决后退还买家；每一步都有独立的 ISU 流水
+
+-- ============================================
+-- 1. 账户托管中余额
+-- ============================================
+ALTER TABLE isu_accounts ADD COLUMN IF NOT EXISTS held_balance DECIMAL(20, 2) NOT NULL DEFAULT 0.00;
+ALTER TABLE isu_accounts ADD CONSTRAINT chk_held_balance_non_negative CHECK (held_balance >= 0);
+
+COMMENT ON COLUMN isu_accounts.balance IS '可用余额';
+COMMENT ON COLUMN isu_accounts.held_balance IS '托管中余额（已转入平台托管账户、尚未结算）';
+
+-- 此前只有工具押金会转入托管账户
+UPDATE isu_accounts a
+SET held_balance = h.held
+FROM (
+    SELECT r.renter_id, SUM(r.deposit) AS held
+    FROM tool_rentals r
+    WHERE r.deposit_status = 'held'
+    GROUP BY r.renter_id
+) h
+WHERE a.owner_id = h.renter_id;
+
+-- ============================================
+-- 2. 交易托管金额
+-- ============================================
+ALTER TABLE transactions ADD COLUMN IF NOT EXISTS escrowed_amount DECIMAL(20, 2) NOT NULL DEFAULT 0.00;
+ALTER TABLE transactions ADD CONSTRAINT chk_escrowed_amount_non_negative CHECK (escrowed_amount >= 0);
+
+COMMENT ON COLUMN transactions.escrowed_amount IS '托管中的交易款项';
+
+-- 进行中的交易在旧流程下已经付款，托管金额为 0，完成时不会重复放款。
+
+-- ============================================
+-- 3. 已确认但尚未开始的交易退回待确认（一次性数据迁移）
+-- ============================================
+-- 旧流程在开始交易时才扣款，这些交易没有冻结任何款项。若保持已确认，开始并完成后只会放出为 0 的托管金额，
+-- 卖家收不到款；因此退回待确认，由卖家重新确认以冻结款项。
+-- 待确认交易按创建时间过期（jobs.pending_ttl_hours），创建时间早于该期限的交易会在下一次过期任务中过期，
+-- 买家需重新下单。过期不涉及任何款项。
+DO $$
+DECLARE
+    reverted BIGINT;
+BEGIN
+    UPDATE transactions
+    SET status = 'pending'
+    WHERE status = 'confirmed' AND escrowed_amount = 0;
+
+    GET DIAGNOSTICS reverted = ROW_COUNT;
+    RAISE NOTICE '托管迁移: % 笔已确认但未冻结款项的交易退回待确认', reverted;
+END $$;
+
+-- ============================================
+-- 4. 托管流水类型
+-- ============================================
+ALTER TABLE isu_transactions DROP CONSTRAINT IF EXISTS chk_transaction_type;
+ALTER TABLE isu_transactions ADD CONSTRAINT chk_transaction_type CHECK (
+    transaction_type IN (
+        'service_payment', 'tool_rental', 'initial_balance', 'admin_adjustment',
+        'deposit_hold', 'deposit_refund', 'escrow_hold', 'escrow_refund'
+    )
+);
@@ -0,0 +1,62 @@
+-- 交易争议与仲裁
+-- 交易进入争议后创建争议记录，双方提交证据，由管理该服务职业的决策者裁决；
+-- 裁决结算托管款项（全额退还、拆分或放款给卖家），结算产生的ISU流水与裁决一并记录
+
+-- ============================================
+-- 1. 争议表 (disputes)
+-- ============================================
+CREATE TABLE IF NOT EXISTS disputes (
+    id UUID PRIMARY KEY,
+    transaction_id UUID NOT NULL UNIQUE REFERENCES transactions(id) ON DELETE CASCADE,
+    opened_by UUID NOT NULL REFERENCES members(id),
+    reason TEXT NOT NULL,
+    profession_type VARCHAR(50) REFERENCES professions(code),
+    decider_id UUID REFERENCES members(id),
+    status VARCHAR(20) NOT NULL DEFAULT 'open',
+    ruling_outcome VARCHAR(20),
+    buyer_refund DECIMAL(20, 2),
+    seller_payout DECIMAL(20, 2),
+    ruling_note TEXT,
+    decided_by UUID REFERENCES members(id),
+    decided_at TIMESTAMPTZ,
+    refund_isu_transaction_id UUID REFERENCES isu_transactions(id),
+    payout_isu_transaction_id UUID REFERENCES isu_transactions(id),
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_dispute_status CHECK (status IN ('open', 'resolved')),
+    CONSTRAINT chk_dispute_ruling_outcome CHECK (
+        ruling_outcome IS NULL OR ruling_outcome IN ('full_refund', 'split', 'release_to_seller')
+    ),
+    CONSTRAINT chk_dispute_ruling_complete CHECK (
+        (status = 'open' AND ruling_outcome IS NULL AND decided_by IS NULL AND decided_at IS NULL)
+        OR (status = 'resolved' AND ruling_outcome IS NOT NULL AND decided_by IS NOT NULL AND decided_at IS NOT NULL
+            AND buyer_refund >= 0 AND seller_payout >= 0)
+    )
+);
+
+CREATE INDEX idx_disputes_decider_status ON disputes(decider_id, status);
+CREATE INDEX idx_disputes_status ON disputes(status);
+
+CREATE TRIGGER update_disputes_updated_at BEFORE UPDATE ON disputes
+    FOR EACH ROW EXECUTE FUNCTION update_updated_at_column();
+
+COMMENT ON TABLE disputes IS '交易争议表 - 争议理由、分配的决策者和裁决结果';
+COMMENT ON COLUMN disputes.profession_type IS '服务所属职业，决定可裁决的决策者；工具租用为空，仅管理员可裁决';
+COMMENT ON COLUMN disputes.refund_isu_transaction_id IS '裁决退还买家的ISU流水';
+COMMENT ON COLUMN disputes.payout_isu_transaction_id IS '裁决放款给卖家的ISU流水';
+
+-- ============================================
+-- 2. 争议证据表 (dispute_evidence)
+-- ============================================
+CREATE TABLE IF NOT EXISTS dispute_evidence (
+    id UUID PRIMARY KEY,
+    dispute_id UUID NOT NULL REFERENCES disputes(id) ON DELETE CASCADE,
+    author_id UUID NOT NULL REFERENCES members(id),
+    message TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_dispute_evidence_dispute_id ON dispute_evidence(dispute_id, created_at);
+
+COMMENT ON TABLE dispute_evidence IS '争议证据表 - 交易双方及决策者的留言（只增不改）';
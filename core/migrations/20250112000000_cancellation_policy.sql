@@ -0,0 +1,24 @@
+-- 取消交易与退款
+-- 已确认（款项已冻结）的交易取消时，按取消方和取消时间收取取消费：取消费从托管转给卖家，其余退还买家
+
+-- ============================================
+-- 1. 交易确认时间（取消费宽限期从确认时开始计算）
+-- ============================================
+ALTER TABLE transactions ADD COLUMN IF NOT EXISTS confirmed_at TIMESTAMPTZ;
+
+COMMENT ON COLUMN transactions.confirmed_at IS '卖家确认（款项冻结）时间';
+
+-- 已确认的交易以最后更新时间近似确认时间；已开始或结束的交易不再适用取消费，无需回填
+UPDATE transactions SET confirmed_at = updated_at WHERE status = 'confirmed' AND confirmed_at IS NULL;
+
+-- ============================================
+-- 2. 取消退款流水类型
+-- ============================================
+ALTER TABLE isu_transactions DROP CONSTRAINT IF EXISTS chk_transaction_type;
+ALTER TABLE isu_transactions ADD CONSTRAINT chk_transaction_type CHECK (
+    transaction_type IN (
+        'service_payment', 'tool_rental', 'initial_balance', 'admin_adjustment',
+        'deposit_hold', 'deposit_refund', 'escrow_hold', 'escrow_refund',
+        'cancellation_refund', 'cancellation_fee'
+    )
+);
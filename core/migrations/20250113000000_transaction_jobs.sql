@@ -0,0 +1,28 @@
+-- 交易自动过期与自动完成
+-- 后台任务定期把超时未确认的交易标记为已过期，把开始后超过宽限期仍未完成的服务交易自动完成
+
+-- ============================================
+-- 1. 交易开始时间（自动完成的宽限期从开始时计算）
+-- ============================================
+ALTER TABLE transactions ADD COLUMN IF NOT EXISTS started_at TIMESTAMPTZ;
+
+COMMENT ON COLUMN transactions.started_at IS '交易开始（进入进行中）时间';
+
+-- 进行中的交易以最后更新时间近似开始时间
+UPDATE transactions SET started_at = updated_at WHERE status IN ('in_progress', 'disputed') AND started_at IS NULL;
+
+-- ============================================
+-- 2. 已过期状态
+-- ============================================
+ALTER TABLE transactions DROP CONSTRAINT IF EXISTS chk_transaction_status;
+ALTER TABLE transactions ADD CONSTRAINT chk_transaction_status CHECK (
+    status IN ('pending', 'confirmed', 'in_progress', 'completed', 'cancelled', 'disputed', 'expired')
+);
+
+-- ============================================
+-- 3. 后台任务扫描索引
+-- ============================================
+CREATE INDEX IF NOT EXISTS idx_transactions_pending_created
+    ON transactions(created_at) WHERE status = 'pending';
+CREATE INDEX IF NOT EXISTS idx_transactions_in_progress_started
+    ON transactions(started_at) WHERE status = 'in_progress';
@@ -0,0 +1,38 @@
+-- 交易评价
+-- 交易完成后买卖双方可互相评价一次（1-5 星及文字），会员和服务的评分汇总随评价写入同步累加
+
+-- ============================================
+-- 1. 评价表
+-- ============================================
+CREATE TABLE IF NOT EXISTS reviews (
+    id UUID PRIMARY KEY,
+    transaction_id UUID NOT NULL REFERENCES transactions(id),
+    reviewer_id UUID NOT NULL REFERENCES members(id),
+    reviewee_id UUID NOT NULL REFERENCES members(id),
+    -- 买家评价服务提供者时关联的服务，计入服务评分
+    service_id UUID REFERENCES services(id),
+    rating SMALLINT NOT NULL,
+    comment TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+
+    CONSTRAINT chk_review_rating CHECK (rating BETWEEN 1 AND 5),
+    CONSTRAINT chk_review_parties CHECK (reviewer_id != reviewee_id),
+    CONSTRAINT uq_review_transaction_reviewer UNIQUE (transaction_id, reviewer_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_reviews_reviewee ON reviews(reviewee_id, created_at DESC);
+
+COMMENT ON TABLE reviews IS '交易评价（每笔交易每方一条）';
+
+-- ============================================
+-- 2. 评分汇总
+-- ============================================
+ALTER TABLE members ADD COLUMN IF NOT EXISTS rating_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE members ADD COLUMN IF NOT EXISTS rating_total INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE services ADD COLUMN IF NOT EXISTS rating_count INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE services ADD COLUMN IF NOT EXISTS rating_total INTEGER NOT NULL DEFAULT 0;
+
+COMMENT ON COLUMN members.rating_count IS '收到的评价数';
+COMMENT ON COLUMN members.rating_total IS '收到的评价星级总和';
+COMMENT ON COLUMN services.rating_count IS '服务收到的评价数';
+COMMENT ON COLUMN services.rating_total IS '服务收到的评价星级总和';
@@ -0,0 +1,22 @@
+-- 会员信誉分
+-- 由已完成交易数、收到的评分、争议败诉和毁约取消计算，在交易事件（完成、取消、裁决、评价）后重新计算
+
+-- ============================================
+-- 1. 交易取消方（只统计会员自己取消的已确认交易）
+-- ============================================
+ALTER TABLE transactions ADD COLUMN IF NOT EXISTS cancelled_by UUID REFERENCES members(id);
+
+COMMENT ON COLUMN transactions.cancelled_by IS '取消交易的参与者；争议裁决全额退还的交易为空';
+
+-- ============================================
+-- 2. 信誉分（0-100，新会员 50 分）
+-- ============================================
+ALTER TABLE members ADD COLUMN IF NOT EXISTS reputation_score NUMERIC(5, 2) NOT NULL DEFAULT 50;
+
+ALTER TABLE members DROP CONSTRAINT IF EXISTS chk_reputation_score;
+ALTER TABLE members ADD CONSTRAINT chk_reputation_score CHECK (reputation_score BETWEEN 0 AND 100);
+
+COMMENT ON COLUMN members.reputation_score IS '信誉分，交易事件后重新计算；存量会员在下一次交易事件时更新';
+
+-- 按提供者信誉过滤、排序服务
+CREATE INDEX IF NOT EXISTS idx_members_reputation ON members(reputation_score DESC);
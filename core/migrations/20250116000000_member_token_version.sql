@@ -0,0 +1,6 @@
+-- 会员令牌版本
+-- JWT 中携带角色、状态和管理的职业；这些信息变化时递增版本号，旧令牌据此识别为过期授权
+
+ALTER TABLE members ADD COLUMN IF NOT EXISTS token_version INTEGER NOT NULL DEFAULT 0;
+
+COMMENT ON COLUMN members.token_version IS '授权信息（角色、状态、管理的职业）变化时递增，签发的令牌携带该版本';
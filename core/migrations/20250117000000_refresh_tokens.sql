@@ -0,0 +1,19 @@
+-- 刷新令牌
+-- 访问令牌短期有效，通过轮换的刷新令牌续期；只保存令牌的 SHA-256 哈希
+
+CREATE TABLE IF NOT EXISTS refresh_tokens (
+    id UUID PRIMARY KEY,
+    member_id UUID NOT NULL REFERENCES members(id) ON DELETE CASCADE,
+    family_id UUID NOT NULL,
+    token_hash TEXT NOT NULL UNIQUE,
+    expires_at TIMESTAMPTZ NOT NULL,
+    used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+COMMENT ON COLUMN refresh_tokens.family_id IS '同一次登录轮换出的令牌共享家族，检测到重用时整个家族吊销';
+COMMENT ON COLUMN refresh_tokens.used_at IS '轮换时间；已使用的令牌再次出现视为重用';
+
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_family ON refresh_tokens(family_id);
+CREATE INDEX IF NOT EXISTS idx_refresh_tokens_member_active ON refresh_tokens(member_id) WHERE revoked_at IS NULL;
@@ -0,0 +1,17 @@
+-- 工具软删除
+-- 租用记录和预订关联着托管的押金与租金，删除工具不能级联清除它们；
+-- 工具改为软删除，外键改为 RESTRICT 防止误删仍被引用的工具
+
+ALTER TABLE tools ADD COLUMN IF NOT EXISTS deleted_at TIMESTAMPTZ;
+
+COMMENT ON COLUMN tools.deleted_at IS '软删除时间，非空表示工具已删除，查询时不再返回';
+
+CREATE INDEX IF NOT EXISTS idx_tools_not_deleted ON tools(owner_id, created_at DESC) WHERE deleted_at IS NULL;
+
+ALTER TABLE tool_rentals DROP CONSTRAINT IF EXISTS tool_rentals_tool_id_fkey;
+ALTER TABLE tool_rentals
+    ADD CONSTRAINT tool_rentals_tool_id_fkey FOREIGN KEY (tool_id) REFERENCES tools(id) ON DELETE RESTRICT;
+
+ALTER TABLE tool_reservations DROP CONSTRAINT IF EXISTS tool_reservations_tool_id_fkey;
+ALTER TABLE tool_reservations
+    ADD CONSTRAINT tool_reservations_tool_id_fkey FOREIGN KEY (tool_id) REFERENCES tools(id) ON DELETE RESTRICT;
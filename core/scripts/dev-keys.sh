@@ -0,0 +1,20 @@
+#!/usr/bin/env bash
+# 生成本地开发用的 JWT 签名密钥（Ed25519），对应 config/development.toml、config/local.toml 中的 dev-ed25519
+# 用法：在 core/ 目录下运行 ./scripts/dev-keys.sh
+set -euo pipefail
+
+KEY_DIR="config/keys"
+KID="dev-ed25519"
+
+mkdir -p "$KEY_DIR"
+
+if [[ -f "$KEY_DIR/$KID.pem" ]]; then
+    echo "密钥已存在: $KEY_DIR/$KID.pem"
+    exit 0
+fi
+
+openssl genpkey -algorithm ed25519 -out "$KEY_DIR/$KID.pem"
+openssl pkey -in "$KEY_DIR/$KID.pem" -pubout -out "$KEY_DIR/$KID.pub.pem"
+chmod 600 "$KEY_DIR/$KID.pem"
+
+echo "已生成 $KEY_DIR/$KID.pem 和 $KEY_DIR/$KID.pub.pem"
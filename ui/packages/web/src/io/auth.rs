@@ -1,18 +1,154 @@
+use std::future::Future;
+
+use dioxus::fullstack::{SetCookie, SetHeader};
 use dioxus::prelude::*;
-use crate::types::{LoginRequest, LoginResponse, RegisterRequest, MemberDto};
+use crate::types::{LoginRequest, RegisterRequest, MemberDto};
+
+/// 浏览器端刷新令牌 Cookie 的作用路径，只随会话请求（续期、登出）发送
+#[cfg(feature = "server")]
+const SESSION_PATH: &str = "/api/session";
 
+/// 登录：访问令牌和刷新令牌都写入 HttpOnly Cookie，脚本无法读取
 #[server]
-pub async fn login(req: LoginRequest) -> Result<LoginResponse, ServerFnError> {
+pub async fn login(
+    req: LoginRequest,
+) -> Result<(SetHeader<SetCookie>, SetHeader<SetCookie>), ServerFnError> {
+    #[cfg(feature = "server")]
+    {
+        use crate::io::base;
+        use crate::types::LoginResponse;
+        use reqwest::Method;
+
+        let request = base::build_request(Method::POST, "/members/login").await.json(&req);
+        let (session, refresh_token, max_age) =
+            base::execute_session::<LoginResponse>(request).await?;
+        session_cookies(&session, &refresh_token, max_age)
+    }
+    #[cfg(not(feature = "server"))]
+    unreachable!()
+}
+
+/// 续期会话：用刷新令牌换取新的访问令牌，刷新令牌随之轮换
+#[post("/api/session/refresh")]
+pub async fn refresh_session() -> Result<(SetHeader<SetCookie>, SetHeader<SetCookie>), ServerFnError> {
+    #[cfg(feature = "server")]
+    {
+        use crate::io::base;
+        use crate::types::LoginResponse;
+        use reqwest::Method;
+
+        let refresh_token = base::request_cookie(base::REFRESH_TOKEN_COOKIE)
+            .await
+            .ok_or_else(|| ServerFnError::ServerError {
+                message: "会话已过期，请重新登录".to_string(),
+                code: 401,
+                details: None,
+            })?;
+
+        let request = base::build_request(Method::POST, "/members/refresh")
+            .await
+            .header(
+                reqwest::header::COOKIE,
+                format!("{}={}", base::REFRESH_TOKEN_COOKIE, refresh_token),
+            );
+        let (session, refresh_token, max_age) =
+            base::execute_session::<LoginResponse>(request).await?;
+        session_cookies(&session, &refresh_token, max_age)
+    }
+    #[cfg(not(feature = "server"))]
+    unreachable!()
+}
+
+/// 登出：吊销刷新令牌所在的令牌家族，并清除浏览器中的会话 Cookie
+#[post("/api/session/logout")]
+pub async fn logout() -> Result<(SetHeader<SetCookie>, SetHeader<SetCookie>), ServerFnError> {
     #[cfg(feature = "server")]
     {
         use crate::io::base;
         use reqwest::Method;
-        base::request_json(Method::POST, "/members/login", &req).await
+
+        if let Some(refresh_token) = base::request_cookie(base::REFRESH_TOKEN_COOKIE).await {
+            let request = base::build_request(Method::POST, "/members/logout")
+                .await
+                .header(
+                    reqwest::header::COOKIE,
+                    format!("{}={}", base::REFRESH_TOKEN_COOKIE, refresh_token),
+                );
+            base::execute_empty(request).await?;
+        }
+        expired_session_cookies()
     }
     #[cfg(not(feature = "server"))]
     unreachable!()
 }
 
+/// 调用需要登录的服务端函数；访问令牌过期（401）时续期会话后重试一次
+pub async fn with_session<T, F, Fut>(call: F) -> Result<T, ServerFnError>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = Result<T, ServerFnError>>,
+{
+    match call().await {
+        Err(ServerFnError::ServerError { code: 401, .. }) => {
+            refresh_session().await?;
+            call().await
+        }
+        result => result,
+    }
+}
+
+/// 会话 Cookie：访问令牌随所有页面请求发送，刷新令牌只随会话请求发送
+#[cfg(feature = "server")]
+fn session_cookies(
+    session: &crate::types::LoginResponse,
+    refresh_token: &str,
+    refresh_max_age: Option<i64>,
+) -> Result<(SetHeader<SetCookie>, SetHeader<SetCookie>), ServerFnError> {
+    use crate::io::base;
+
+    let access = format!(
+        "{}={}; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age={}",
+        base::ACCESS_TOKEN_COOKIE,
+        session.token,
+        session.expires_in
+    );
+    let mut refresh = format!(
+        "{}={}; Path={}; HttpOnly; Secure; SameSite=Strict",
+        base::REFRESH_TOKEN_COOKIE,
+        refresh_token,
+        SESSION_PATH
+    );
+    if let Some(max_age) = refresh_max_age {
+        refresh.push_str(&format!("; Max-Age={}", max_age));
+    }
+
+    Ok((set_cookie(access)?, set_cookie(refresh)?))
+}
+
+/// 立即过期的会话 Cookie（路径须与签发时一致，浏览器才会删除）
+#[cfg(feature = "server")]
+fn expired_session_cookies() -> Result<(SetHeader<SetCookie>, SetHeader<SetCookie>), ServerFnError> {
+    use crate::io::base;
+
+    let access = format!(
+        "{}=; Path=/; HttpOnly; Secure; SameSite=Lax; Max-Age=0",
+        base::ACCESS_TOKEN_COOKIE
+    );
+    let refresh = format!(
+        "{}=; Path={}; HttpOnly; Secure; SameSite=Strict; Max-Age=0",
+        base::REFRESH_TOKEN_COOKIE,
+        SESSION_PATH
+    );
+
+    Ok((set_cookie(access)?, set_cookie(refresh)?))
+}
+
+/// 组装 Set-Cookie 响应头
+#[cfg(feature = "server")]
+fn set_cookie(value: String) -> Result<SetHeader<SetCookie>, ServerFnError> {
+    SetHeader::new(value).map_err(|e| ServerFnError::new(format!("无效的 Cookie: {}", e)))
+}
+
 #[server]
 pub async fn register(req: RegisterRequest) -> Result<MemberDto, ServerFnError> {
     #[cfg(feature = "server")]
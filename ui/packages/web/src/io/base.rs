@@ -16,24 +16,35 @@ fn get_client() -> &'static Client {
 
 const API_BASE_URL: &str = "http://localhost:3000/api/v1";
 
+/// 浏览器中保存访问令牌的 Cookie
+pub const ACCESS_TOKEN_COOKIE: &str = "token";
+
+/// 刷新令牌 Cookie（API 下发和浏览器保存使用同一个名字）
+pub const REFRESH_TOKEN_COOKIE: &str = "refresh_token";
+
+/// 读取浏览器请求携带的 Cookie (使用 FullstackContext::extract)
+pub async fn request_cookie(name: &str) -> Option<String> {
+    let headers = FullstackContext::extract::<reqwest::header::HeaderMap, _>()
+        .await
+        .ok()?;
+    let cookie = headers.get("cookie")?.to_str().ok()?;
+
+    cookie.split(';').find_map(|part| {
+        part.trim()
+            .strip_prefix(name)
+            .and_then(|rest| rest.strip_prefix('='))
+            .map(str::to_string)
+    })
+}
+
 /// 核心逻辑：构建请求 + 注入 Auth
-async fn build_request(method: Method, path: &str) -> RequestBuilder {
+pub async fn build_request(method: Method, path: &str) -> RequestBuilder {
     let url = format!("{}{}", API_BASE_URL, path);
     let mut req = get_client().request(method, &url);
 
-    // 1. 尝试从 Cookie 提取 Token (使用 FullstackContext::extract)
-    // extract::<reqwest::header::HeaderMap>
-    #[cfg(feature = "server")]
-    if let Ok(headers) = FullstackContext::extract::<reqwest::header::HeaderMap, _>().await {
-        if let Some(cookie) = headers.get("cookie").and_then(|c| c.to_str().ok()) {
-            for part in cookie.split(';') {
-                let part = part.trim();
-                if let Some(token) = part.strip_prefix("token=") {
-                    req = req.header("Authorization", format!("Bearer {}", token));
-                    break;
-                }
-            }
-        }
+    // 1. 尝试从 Cookie 提取 Token
+    if let Some(token) = request_cookie(ACCESS_TOKEN_COOKIE).await {
+        req = req.header("Authorization", format!("Bearer {}", token));
     }
 
     // 2. 环境变量覆盖 (Dev Mode)
@@ -49,6 +60,50 @@ async fn execute<T>(req: RequestBuilder) -> Result<T, ServerFnError>
 where
     T: DeserializeOwned,
 {
+    let resp = send(req).await?;
+    unwrap_response(resp).await
+}
+
+/// 执行会话请求（登录、续期），同时取回 API 下发的刷新令牌 Cookie（值和有效期）
+pub async fn execute_session<T>(req: RequestBuilder) -> Result<(T, String, Option<i64>), ServerFnError>
+where
+    T: DeserializeOwned,
+{
+    let resp = send(req).await?;
+
+    let (refresh_token, max_age) = resp
+        .headers()
+        .get_all(reqwest::header::SET_COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find_map(parse_refresh_cookie)
+        .ok_or_else(|| ServerFnError::new("API 未下发刷新令牌"))?;
+
+    let data = unwrap_response(resp).await?;
+    Ok((data, refresh_token, max_age))
+}
+
+/// 执行不返回数据的请求（如登出），只关心是否成功
+pub async fn execute_empty(req: RequestBuilder) -> Result<(), ServerFnError> {
+    send(req).await.map(|_| ())
+}
+
+/// 解析 `refresh_token=<value>; ...; Max-Age=<seconds>`
+fn parse_refresh_cookie(cookie: &str) -> Option<(String, Option<i64>)> {
+    let mut parts = cookie.split(';').map(str::trim);
+    let value = parts
+        .next()?
+        .strip_prefix(REFRESH_TOKEN_COOKIE)?
+        .strip_prefix('=')?
+        .to_string();
+    let max_age = parts
+        .find_map(|attr| attr.strip_prefix("Max-Age="))
+        .and_then(|age| age.parse().ok());
+    Some((value, max_age))
+}
+
+/// 发送请求；非 2xx 转为错误，401 保留状态码供客户端续期会话
+async fn send(req: RequestBuilder) -> Result<reqwest::Response, ServerFnError> {
     let resp = req
         .send()
         .await
@@ -57,12 +112,20 @@ where
     let status = resp.status();
     if !status.is_success() {
         let body = resp.text().await.unwrap_or_default();
-        return Err(ServerFnError::new(format!(
-            "API Error {}: {}",
-            status, body
-        )));
+        return Err(ServerFnError::ServerError {
+            message: format!("API Error {}: {}", status, body),
+            code: status.as_u16(),
+            details: None,
+        });
     }
 
+    Ok(resp)
+}
+
+async fn unwrap_response<T>(resp: reqwest::Response) -> Result<T, ServerFnError>
+where
+    T: DeserializeOwned,
+{
     let api_res: ApiResponse<T> = resp
         .json()
         .await
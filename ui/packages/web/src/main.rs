@@ -5,7 +5,9 @@ mod components;
 mod types;
 mod io;
 
+use components::button::{Button, ButtonVariant};
 use components::separator::Separator;
+use io::auth::logout;
 use views::{Home, ToolList, ToolDetail, NotFound, Login, Signup};
 
 const FAVICON: Asset = asset!("/assets/favicon.ico");
@@ -49,6 +51,8 @@ fn App() -> Element {
 
 #[component]
 fn Layout() -> Element {
+    let nav = use_navigator();
+
     rsx! {
         div {
             class: "min-h-screen bg-gray-50",
@@ -80,6 +84,19 @@ fn Layout() -> Element {
                                 }
                             }
                         }
+                        div {
+                            class: "flex items-center",
+                            Button {
+                                variant: ButtonVariant::Ghost,
+                                onclick: move |_| async move {
+                                    // 登出失败（如 API 不可达）时保留会话，停留在当前页面
+                                    if logout().await.is_ok() {
+                                        nav.push(Route::Login {});
+                                    }
+                                },
+                                "Sign out"
+                            }
+                        }
                     }
                 }
             }
@@ -16,6 +16,8 @@ pub struct LoginRequest {
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct LoginResponse {
     pub token: String,
+    /// 访问令牌有效期（秒）
+    pub expires_in: i64,
     pub member: MemberDto,
 }
 
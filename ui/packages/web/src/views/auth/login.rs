@@ -21,16 +21,8 @@ pub fn Login() -> Element {
         };
         
         match login(req).await {
-            Ok(resp) => {
-                // Set Cookie on Client Side via JS eval
-                // This allows subsequent SSR requests to carry the token
-                let js = format!(
-                    "document.cookie = 'token={}; Path=/; SameSite=Lax; Max-Age=86400';", 
-                    resp.token
-                );
-                // Dioxus 0.7 document::eval
-                let _ = document::eval(&js);
-                
+            Ok(_) => {
+                // 会话 Cookie 由服务端以 HttpOnly 写入，后续请求自动携带
                 nav.push(Route::ToolList {});
             },
             Err(e) => {
@@ -1,11 +1,15 @@
 use dioxus::prelude::*;
+use crate::io::auth::with_session;
 use crate::io::get_tool;
 use crate::components::button::{Button, ButtonVariant};
 use crate::Route;
 
 #[component]
 pub fn ToolDetail(id: String) -> Element {
-    let tool_data = use_server_future(move || get_tool(id.clone()))?;
+    let tool_data = use_server_future(move || {
+        let id = id.clone();
+        with_session(move || get_tool(id.clone()))
+    })?;
 
     rsx! {
         div {
@@ -1,4 +1,5 @@
 use dioxus::prelude::*;
+use crate::io::auth::with_session;
 use crate::io::list_tools;
 use crate::components::accordion::{Accordion, AccordionItem, AccordionTrigger, AccordionContent};
 use crate::components::button::{Button, ButtonVariant};
@@ -6,7 +7,7 @@ use crate::Route;
 
 #[component]
 pub fn ToolList() -> Element {
-    let tools_data = use_server_future(move || list_tools(1, 20))?;
+    let tools_data = use_server_future(move || with_session(|| list_tools(1, 20)))?;
 
     rsx! {
         div {